@@ -0,0 +1,23 @@
+//! Select the limb width for the big-integer arithmetic.
+//!
+//! This is the single source of truth for the limb width: both the
+//! big-integer types and the pre-computed tables are gated on the
+//! `limb_width_64` cfg emitted here, so they can never disagree.
+//! See `src/bigint.rs` for the rationale behind the selection.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(limb_width_64)");
+
+    // We use 64-bit limbs on 64-bit architectures with efficient 64-bit
+    // multiplication, which excludes all SPARC targets. Both `sparc`
+    // and `sparc64` must be checked, since 64-bit SPARC targets use the
+    // `sparc64` architecture.
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let is_sparc = arch == "sparc" || arch == "sparc64";
+    if pointer_width == "64" && !is_sparc {
+        println!("cargo:rustc-cfg=limb_width_64");
+    }
+}
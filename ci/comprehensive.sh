@@ -20,3 +20,13 @@ fi
 cd etc/correctness
 cargo run $FEATURES --release --bin test-parse-golang
 cargo run $FEATURES --release --bin test-parse-unittests
+
+# Exhaustively test the narrow floats, which can have exact halfway
+# representations with few enough digits to hit the moderate path.
+cargo run $FEATURES --release --bin f16-test
+cargo run $FEATURES --release --bin bf16-test
+
+# Without std or alloc, the slow path uses fixed-size big integers,
+# and must still be correct.
+cargo run --no-default-features --release --bin test-parse-golang
+cargo run --no-default-features --release --bin test-parse-unittests
@@ -20,6 +20,16 @@ FEATURES=(
     "compact"
     "alloc"
     "compact,alloc"
+    "diagnostics"
+    "no_slow_path"
+    "compact,no_slow_path"
+    "bellerophon"
+    "simd"
+    "const_fn"
+    "wide_mantissa"
+    "widening_mul"
+    "radix"
+    "radix,no_slow_path"
 )
 
 check() {
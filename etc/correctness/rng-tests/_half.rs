@@ -0,0 +1,180 @@
+//! Software half-precision floats, to test parsing narrow floats.
+//!
+//! Both types only implement the required items of `Float`, so every
+//! algorithm uses the integer-only defaults.
+
+#![allow(dead_code, non_camel_case_types)]
+
+use minimal_lexical::Float;
+use std::ops;
+
+macro_rules! half_impl {
+    ($t:ident, $($name:ident : $type:ty = $value:expr ;)*) => {
+        /// Half-precision float, stored as the IEEE-754 bits.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct $t(pub u16);
+
+        impl ops::Neg for $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                $t(self.0 ^ 0x8000)
+            }
+        }
+
+        impl Float for $t {
+            $(const $name: $type = $value;)*
+
+            #[inline]
+            fn from_bits(u: u64) -> $t {
+                debug_assert!(u <= 0xffff);
+                $t(u as u16)
+            }
+
+            #[inline]
+            fn to_bits(self) -> u64 {
+                self.0 as u64
+            }
+        }
+    };
+}
+
+half_impl! {
+    f16,
+    MAX_DIGITS: usize = 23;
+    BIGINT_BITS: usize = 256;
+    SIGN_MASK: u64 = 0x8000;
+    EXPONENT_MASK: u64 = 0x7C00;
+    HIDDEN_BIT_MASK: u64 = 0x0400;
+    MANTISSA_MASK: u64 = 0x03FF;
+    MANTISSA_SIZE: i32 = 10;
+    EXPONENT_BIAS: i32 = 15 + 10;
+    DENORMAL_EXPONENT: i32 = 1 - 25;
+    MAX_EXPONENT: i32 = 0x1F - 25;
+    CARRY_MASK: u64 = 0x800;
+    MIN_EXPONENT_ROUND_TO_EVEN: i32 = -22;
+    MAX_EXPONENT_ROUND_TO_EVEN: i32 = 5;
+    MINIMUM_EXPONENT: i32 = -15;
+    SMALLEST_POWER_OF_TEN: i32 = -27;
+    LARGEST_POWER_OF_TEN: i32 = 4;
+    MIN_EXPONENT_FAST_PATH: i32 = -4;
+    MAX_EXPONENT_FAST_PATH: i32 = 4;
+    MAX_EXPONENT_DISGUISED_FAST_PATH: i32 = 7;
+}
+
+half_impl! {
+    bf16,
+    MAX_DIGITS: usize = 98;
+    BIGINT_BITS: usize = 640;
+    SIGN_MASK: u64 = 0x8000;
+    EXPONENT_MASK: u64 = 0x7F80;
+    HIDDEN_BIT_MASK: u64 = 0x0080;
+    MANTISSA_MASK: u64 = 0x007F;
+    MANTISSA_SIZE: i32 = 7;
+    EXPONENT_BIAS: i32 = 127 + 7;
+    DENORMAL_EXPONENT: i32 = 1 - 134;
+    MAX_EXPONENT: i32 = 0xFF - 134;
+    CARRY_MASK: u64 = 0x100;
+    MIN_EXPONENT_ROUND_TO_EVEN: i32 = -24;
+    MAX_EXPONENT_ROUND_TO_EVEN: i32 = 3;
+    MINIMUM_EXPONENT: i32 = -127;
+    SMALLEST_POWER_OF_TEN: i32 = -60;
+    LARGEST_POWER_OF_TEN: i32 = 38;
+    MIN_EXPONENT_FAST_PATH: i32 = -3;
+    MAX_EXPONENT_FAST_PATH: i32 = 3;
+    MAX_EXPONENT_DISGUISED_FAST_PATH: i32 = 5;
+}
+
+/// Format the exact value of `mantissa * 2^exp2` as decimal digits and an exponent.
+///
+/// The value is `digits * 10^exp10`, where the exponent is never positive.
+pub fn to_exact_decimal(mantissa: u64, exp2: i32) -> (String, i32) {
+    // Little-endian limbs of 9 decimal digits.
+    const BASE: u64 = 1_000_000_000;
+    let mut limbs = vec![mantissa % BASE, mantissa / BASE % BASE, mantissa / BASE / BASE];
+    let multiplier = if exp2 >= 0 {
+        2
+    } else {
+        5
+    };
+    for _ in 0..exp2.abs() {
+        let mut carry = 0;
+        for limb in limbs.iter_mut() {
+            let product = *limb * multiplier + carry;
+            *limb = product % BASE;
+            carry = product / BASE;
+        }
+        if carry != 0 {
+            limbs.push(carry);
+        }
+    }
+
+    let mut digits = String::new();
+    for limb in limbs.iter().rev() {
+        digits.push_str(&format!("{:09}", limb));
+    }
+    let digits = digits.trim_start_matches('0');
+    let digits = if digits.is_empty() {
+        "0"
+    } else {
+        digits
+    };
+    (digits.to_string(), std::cmp::min(exp2, 0))
+}
+
+/// Subtract 1 from the last decimal digit.
+fn decrement(digits: &str) -> String {
+    let mut bytes = digits.as_bytes().to_vec();
+    let mut index = bytes.len();
+    loop {
+        index -= 1;
+        if bytes[index] != b'0' {
+            bytes[index] -= 1;
+            break;
+        }
+        bytes[index] = b'9';
+    }
+    String::from_utf8(bytes).unwrap()
+}
+
+/// Parse the value `digits * 10^exp10`, with an optional sign.
+pub fn parse<F: Float>(is_positive: bool, digits: &str, exp10: i32) -> F {
+    let digits = digits.trim_start_matches('0');
+    let float: F = minimal_lexical::parse_float(digits.as_bytes().iter(), b"".iter(), exp10);
+    if is_positive {
+        float
+    } else {
+        -float
+    }
+}
+
+/// Check the exact value of a finite float, and its halfway point to the next float, round-trip.
+///
+/// The halfway point must round to even, and any digits just above or
+/// below the halfway point must round away from it, so any double rounding
+/// is detected.
+pub fn check_round_trip<F: Float>(float: F) {
+    let bits = float.to_bits();
+    let is_positive = bits & F::SIGN_MASK == 0;
+    let abs = bits & !F::SIGN_MASK;
+    assert!(abs < F::EXPONENT_MASK, "check_round_trip() non-finite float.");
+    let sign = bits & F::SIGN_MASK;
+
+    let (digits, exp10) = to_exact_decimal(float.mantissa(), float.exponent());
+    assert_eq!(parse::<F>(is_positive, &digits, exp10).to_bits(), bits, "{}e{}", digits, exp10);
+
+    let mantissa = 2 * float.mantissa() + 1;
+    let (halfway, exp10) = to_exact_decimal(mantissa, float.exponent() - 1);
+    let even = (abs + (abs & 1)) | sign;
+    let float = parse::<F>(is_positive, &halfway, exp10);
+    assert_eq!(float.to_bits(), even, "{}e{}", halfway, exp10);
+
+    let above = format!("{}0001", halfway);
+    let float = parse::<F>(is_positive, &above, exp10 - 4);
+    assert_eq!(float.to_bits(), (abs + 1) | sign, "{}", above);
+
+    let below = format!("{}9999", decrement(&halfway));
+    let float = parse::<F>(is_positive, &below, exp10 - 4);
+    assert_eq!(float.to_bits(), bits, "{}", below);
+}
@@ -0,0 +1,30 @@
+//! Exhaustively test every bf16 value.
+
+mod _common;
+mod _half;
+
+use self::_common::parse_float;
+use self::_half::{check_round_trip, bf16};
+use minimal_lexical::Float;
+
+pub fn main() {
+    let mut buffer = ryu::Buffer::new();
+    for i in 0..=0xFFFF_u16 {
+        let input = bf16(i);
+        if i as u64 & bf16::EXPONENT_MASK == bf16::EXPONENT_MASK {
+            continue;
+        }
+        if i % 10000 == 0 {
+            println!("Processed {} records.", i);
+        }
+
+        // The shortest representation of the wider float is within
+        // its rounding interval, so it must round to the same value.
+        let printed = buffer.format_finite(input.widen::<f32>());
+        let (output, rest) = parse_float::<bf16>(printed.as_bytes());
+        assert_eq!(output, input);
+        assert_eq!(rest, b"");
+
+        check_round_trip(input);
+    }
+}
@@ -0,0 +1,37 @@
+//! Exhaustively test every f32 value, printed with different numbers of digits.
+//!
+//! Unlike the shortest representation, the 9 and 17 digit representations
+//! are often far from the shortest decimal in the rounding interval, and
+//! near the halfway point to an adjacent float, so they require the
+//! moderate or slow path algorithms. Every representation has at least
+//! the 9 significant digits required to round-trip an f32.
+
+mod _common;
+
+use self::_common::parse_float;
+use minimal_lexical::Float;
+
+/// Parse the printed float, and check it is the input float.
+fn check(input: f32, printed: &str) {
+    let (output, rest) = parse_float::<f32>(printed.as_bytes());
+    assert_eq!(output.to_bits(), input.to_bits(), "{}", printed);
+    assert_eq!(rest, b"", "{}", printed);
+}
+
+pub fn main() {
+    let mut buffer = ryu::Buffer::new();
+    for i in 0..f32::EXPONENT_MASK {
+        let input = f32::from_bits(i as u32);
+        if i % 10000000 == 0 {
+            println!("Processed {} records.", i);
+        }
+
+        check(input, buffer.format_finite(input));
+        check(input, &format!("{:.8e}", input));
+        check(input, &format!("{:.16e}", input));
+        // The sign is applied after parsing, so only check it once.
+        if i % 1000 == 0 {
+            check(-input, &format!("-{:.16e}", input));
+        }
+    }
+}
@@ -170,10 +170,14 @@ where
     };
     let (exponent, bytes) = match bytes.first() {
         Some(&b'e') | Some(&b'E') => {
-            // Extract and parse the exponent.
-            let (is_positive, bytes) = parse_sign(&bytes[1..]);
-            let (exponent, bytes) = consume_digits(bytes);
-            (parse_exponent(exponent, is_positive), bytes)
+            // Extract and parse the exponent. Only consume the exponent
+            // symbol and sign if they're followed by exponent digits.
+            let (is_positive, rest) = parse_sign(&bytes[1..]);
+            let (exponent, rest) = consume_digits(rest);
+            match exponent.is_empty() {
+                true => (0, bytes),
+                false => (parse_exponent(exponent, is_positive), rest),
+            }
         },
         _ => (0, bytes),
     };
@@ -210,4 +214,7 @@ pub fn main() {
     check_parse_float("1.0e7", 1.0e7f64, "");
     check_parse_float("12345.67", 12345.67, "");
     check_parse_float("12345.67 narnia", 12345.67, " narnia");
+    check_parse_float("1.5e", 1.5, "e");
+    check_parse_float("1.5e+", 1.5, "e+");
+    check_parse_float("1.5eq", 1.5, "eq");
 }
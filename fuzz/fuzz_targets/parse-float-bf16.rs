@@ -0,0 +1,18 @@
+#![no_main]
+
+#[path = "../../etc/correctness/rng-tests/_half.rs"]
+mod half;
+
+#[macro_use]
+extern crate libfuzzer_sys;
+
+use minimal_lexical::Float;
+
+fuzz_target!(|data: &[u8]| {
+    if data.len() >= 2 {
+        let float = half::bf16(u16::from_le_bytes([data[0], data[1]]));
+        if float.to_bits() & half::bf16::EXPONENT_MASK != half::bf16::EXPONENT_MASK {
+            half::check_round_trip(float);
+        }
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+#[macro_use]
+extern crate libfuzzer_sys;
+
+// Feed the raw, unvalidated bytes as the digits, which must never panic.
+fuzz_target!(|data: &[u8]| {
+    if data.len() >= 4 {
+        let exponent = i32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+        let bytes = &data[4..];
+        let (integer, fraction) = match bytes.iter().position(|&c| c == b'.') {
+            Some(index) => (&bytes[..index], &bytes[index + 1..]),
+            None => (bytes, &bytes[..0]),
+        };
+        let _ = minimal_lexical::parse_float_total::<f32>(integer, fraction, exponent);
+        let _ = minimal_lexical::parse_float_total::<f64>(integer, fraction, exponent);
+        let _ = minimal_lexical::parse_float_total::<f64>(bytes, bytes, exponent);
+    }
+});
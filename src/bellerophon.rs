@@ -13,8 +13,12 @@
 //! This implementation is loosely based off the Golang implementation,
 //! found [here](https://github.com/golang/go/blob/b10849fbb97a2244c086991b4623ae9f32c212d0/src/strconv/extfloat.go).
 //! This code is therefore subject to a 3-clause BSD license.
+//!
+//! This replaces the Eisel-Lemire algorithm with the `compact` or
+//! `bellerophon` features. Both are always correct when combined with
+//! the slow path, so this can be used to cross-validate the two.
 
-#![cfg(feature = "compact")]
+#![cfg(any(feature = "compact", feature = "bellerophon"))]
 #![doc(hidden)]
 
 use crate::extended_float::ExtendedFloat;
@@ -41,6 +45,59 @@ pub fn bellerophon<F: Float>(num: &Number) -> ExtendedFloat {
         mant: 0,
         exp: 0,
     };
+
+    // Literal 0 or infinity.
+    let (mut fp, errors) = scale::<F>(num);
+    if fp.mant == 0 {
+        return fp;
+    }
+
+    // Too many errors accumulated, return an error.
+    if !error_is_accurate::<F>(errors, &fp) {
+        // Bias the exponent so we know it's invalid.
+        fp.exp += F::INVALID_FP;
+        return fp;
+    }
+
+    // Check if we have a literal 0 or overflow here.
+    // If we have an exponent of -63, we can still have a valid shift,
+    // giving a case where we have too many errors and need to round-up.
+    if -fp.exp + 1 == 65 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
+        return fp_zero;
+    }
+
+    round::<F, _>(&mut fp, |f, s| {
+        round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+            is_above || (is_odd && is_halfway)
+        });
+    });
+    fp
+}
+
+/// Calculate the extended-precision float without rounding.
+///
+/// This returns a literal 0 or infinity if the float cannot be represented,
+/// otherwise, the normalized float with an invalid, biased exponent,
+/// so the slow path algorithms are always used.
+pub fn bellerophon_error<F: Float>(num: &Number) -> ExtendedFloat {
+    let (mut fp, _) = scale::<F>(num);
+    if fp.mant != 0 {
+        fp.exp += F::INVALID_FP;
+    }
+    fp
+}
+
+/// Scale the significant digits by the decimal exponent.
+///
+/// Returns the normalized extended-precision float, with a biased exponent,
+/// and the number of errors accumulated during the calculation. If the
+/// float is a literal 0 or infinity, the mantissa will be 0.
+fn scale<F: Float>(num: &Number) -> (ExtendedFloat, u64) {
+    let fp_zero = ExtendedFloat {
+        mant: 0,
+        exp: 0,
+    };
     let fp_inf = ExtendedFloat {
         mant: 0,
         exp: F::INFINITE_POWER,
@@ -50,33 +107,38 @@ pub fn bellerophon<F: Float>(num: &Number) -> ExtendedFloat {
     // This allows us to avoid narrow casts causing numeric overflow,
     // and is a quick check for any radix.
     if num.mantissa == 0 || num.exponent <= -0x1000 {
-        return fp_zero;
+        return (fp_zero, 0);
     } else if num.exponent >= 0x1000 {
-        return fp_inf;
+        return (fp_inf, 0);
     }
 
     // Calculate our indexes for our extended-precision multiplication.
     // This narrowing cast is safe, since exponent must be in a valid range.
-    let exponent = num.exponent as i32 + BASE10_POWERS.bias;
+    let exponent = num.exponent + BASE10_POWERS.bias;
     let small_index = exponent % BASE10_POWERS.step;
     let large_index = exponent / BASE10_POWERS.step;
 
     if exponent < 0 {
         // Guaranteed underflow (assign 0).
-        return fp_zero;
+        return (fp_zero, 0);
     }
     if large_index as usize >= BASE10_POWERS.large.len() {
         // Overflow (assign infinity)
-        return fp_inf;
+        return (fp_inf, 0);
     }
 
     // Within the valid exponent range, multiply by the large and small
     // exponents and return the resulting value.
 
     // Track errors to as a factor of unit in last-precision.
-    let mut errors: u32 = 0;
+    // The truncated digits are less than 1 unit of the mantissa, so
+    // the relative error is less than `1 / mantissa`, or, when normalized,
+    // `2^64 / mantissa` units. This is at most 19 units for the 19
+    // significant digits parsed, but any other mantissa is also valid,
+    // and a small mantissa always requires the slow path.
+    let mut errors: u64 = 0;
     if num.many_digits {
-        errors += error_halfscale();
+        errors = (u64::max_value() / num.mantissa).saturating_add(1);
     }
 
     // Multiply by the small power.
@@ -89,55 +151,35 @@ pub fn bellerophon<F: Float>(num: &Number) -> ExtendedFloat {
     match fp.mant.overflowing_mul(BASE10_POWERS.get_small_int(small_index as usize)) {
         // Overflow, multiplication unsuccessful, go slow path.
         (_, true) => {
-            normalize(&mut fp);
-            fp = mul(&fp, &BASE10_POWERS.get_small(small_index as usize));
-            errors += error_halfscale();
+            fp.normalize();
+            fp.imul(&BASE10_POWERS.get_small(small_index as usize));
+            errors = errors.saturating_add(error_halfscale());
         },
         // No overflow, multiplication successful.
         (mant, false) => {
             fp.mant = mant;
-            normalize(&mut fp);
+            fp.normalize();
         },
     }
 
     // Multiply by the large power.
-    fp = mul(&fp, &BASE10_POWERS.get_large(large_index as usize));
+    fp.imul(&BASE10_POWERS.get_large(large_index as usize));
     if errors > 0 {
-        errors += 1;
+        errors = errors.saturating_add(1);
     }
-    errors += error_halfscale();
+    errors = errors.saturating_add(error_halfscale());
 
     // Normalize the floating point (and the errors).
-    let shift = normalize(&mut fp);
-    errors <<= shift;
+    let shift = fp.normalize();
+    errors = errors.saturating_mul(1 << shift);
     fp.exp += F::EXPONENT_BIAS;
 
     // Check for literal overflow, even with halfway cases.
     if -fp.exp + 1 > 65 {
-        return fp_zero;
+        return (fp_zero, 0);
     }
 
-    // Too many errors accumulated, return an error.
-    if !error_is_accurate::<F>(errors, &fp) {
-        // Bias the exponent so we know it's invalid.
-        fp.exp += F::INVALID_FP;
-        return fp;
-    }
-
-    // Check if we have a literal 0 or overflow here.
-    // If we have an exponent of -63, we can still have a valid shift,
-    // giving a case where we have too many errors and need to round-up.
-    if -fp.exp + 1 == 65 {
-        // Have more than 64 bits below the minimum exponent, must be 0.
-        return fp_zero;
-    }
-
-    round::<F, _>(&mut fp, |f, s| {
-        round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
-            is_above || (is_odd && is_halfway)
-        });
-    });
-    fp
+    (fp, errors)
 }
 
 // ERRORS
@@ -161,18 +203,18 @@ pub fn bellerophon<F: Float>(num: &Number) -> ExtendedFloat {
 
 /// Get the full error scale.
 #[inline(always)]
-const fn error_scale() -> u32 {
+const fn error_scale() -> u64 {
     8
 }
 
 /// Get the half error scale.
 #[inline(always)]
-const fn error_halfscale() -> u32 {
+const fn error_halfscale() -> u64 {
     error_scale() / 2
 }
 
 /// Determine if the number of errors is tolerable for float precision.
-fn error_is_accurate<F: Float>(errors: u32, fp: &ExtendedFloat) -> bool {
+fn error_is_accurate<F: Float>(errors: u64, fp: &ExtendedFloat) -> bool {
     // Check we can't have a literal 0 denormal float.
     debug_assert!(fp.exp >= -64);
 
@@ -238,11 +280,11 @@ fn error_is_accurate<F: Float>(errors: u32, fp: &ExtendedFloat) -> bool {
     //
     // # Conclusion
     //
-    // Since errors will always be small, and since we want to detect
+    // Since errors are usually small, and since we want to detect
     // if the representation is accurate, we need to use an **unsigned**
-    // type for comparisons.
+    // type for comparisons. Errors of at least half the truncated bits
+    // would wrap, but also always straddle a halfway point.
     let maskbits = extrabits as u64;
-    let errors = errors as u64;
 
     // Round-to-nearest, need to use the halfway point.
     if extrabits > 64 {
@@ -258,6 +300,9 @@ fn error_is_accurate<F: Float>(errors: u32, fp: &ExtendedFloat) -> bool {
         // Round-to-nearest, need to check if we're close to halfway.
         // IE, b10100 | 100000, where `|` signifies the truncation point.
         let halfway = lower_n_halfway(maskbits);
+        if errors >= halfway {
+            return false;
+        }
         let cmp1 = halfway.wrapping_sub(errors) < extra;
         let cmp2 = extra < halfway.wrapping_add(errors);
 
@@ -268,84 +313,32 @@ fn error_is_accurate<F: Float>(errors: u32, fp: &ExtendedFloat) -> bool {
     }
 }
 
-// MATH
-// ----
-
-/// Normalize float-point number.
-///
-/// Shift the mantissa so the number of leading zeros is 0, or the value
-/// itself is 0.
-///
-/// Get the number of bytes shifted.
-pub fn normalize(fp: &mut ExtendedFloat) -> i32 {
-    // Note:
-    // Using the ctlz intrinsic via leading_zeros is way faster (~10x)
-    // than shifting 1-bit at a time, via while loop, and also way
-    // faster (~2x) than an unrolled loop that checks at 32, 16, 4,
-    // 2, and 1 bit.
-    //
-    // Using a modulus of pow2 (which will get optimized to a bitwise
-    // and with 0x3F or faster) is slightly slower than an if/then,
-    // however, removing the if/then will likely optimize more branched
-    // code as it removes conditional logic.
-
-    // Calculate the number of leading zeros, and then zero-out
-    // any overflowing bits, to avoid shl overflow when self.mant == 0.
-    if fp.mant != 0 {
-        let shift = fp.mant.leading_zeros() as i32;
-        fp.mant <<= shift;
-        fp.exp -= shift;
-        shift
-    } else {
-        0
-    }
-}
-
-/// Multiply two normalized extended-precision floats, as if by `a*b`.
-///
-/// The precision is maximal when the numbers are normalized, however,
-/// decent precision will occur as long as both values have high bits
-/// set. The result is not normalized.
-///
-/// Algorithm:
-///     1. Non-signed multiplication of mantissas (requires 2x as many bits as input).
-///     2. Normalization of the result (not done here).
-///     3. Addition of exponents.
-pub fn mul(x: &ExtendedFloat, y: &ExtendedFloat) -> ExtendedFloat {
-    // Logic check, values must be decently normalized prior to multiplication.
-    debug_assert!(x.mant >> 32 != 0);
-    debug_assert!(y.mant >> 32 != 0);
-
-    // Extract high-and-low masks.
-    // Mask is u32::MAX for older Rustc versions.
-    const LOMASK: u64 = 0xffff_ffff;
-    let x1 = x.mant >> 32;
-    let x0 = x.mant & LOMASK;
-    let y1 = y.mant >> 32;
-    let y0 = y.mant & LOMASK;
-
-    // Get our products
-    let x1_y0 = x1 * y0;
-    let x0_y1 = x0 * y1;
-    let x0_y0 = x0 * y0;
-    let x1_y1 = x1 * y1;
-
-    let mut tmp = (x1_y0 & LOMASK) + (x0_y1 & LOMASK) + (x0_y0 >> 32);
-    // round up
-    tmp += 1 << (32 - 1);
-
-    ExtendedFloat {
-        mant: x1_y1 + (x1_y0 >> 32) + (x0_y1 >> 32) + (tmp >> 32),
-        exp: x.exp + y.exp + 64,
-    }
-}
-
 // POWERS
 // ------
 
 /// Precalculated powers of base N for the Bellerophon algorithm.
+///
+/// With the `compact` feature, the normalized small powers are calculated
+/// from the small integer powers, which are exact as 64-bit integers.
+/// The following sizes were measured for a binary parsing `f32` and `f64`,
+/// with `opt-level = "s"` and LTO on x86_64, along with the time to parse
+/// 1M random floats with up to 17 digits.
+///
+/// ```text
+/// default                 .rodata: 34032  .text: 242752   35 ms
+/// compact, stored         .rodata: 23544  .text: 242784   51 ms
+/// compact, calculated     .rodata: 23464  .text: 242800   51 ms
+/// ```
+///
+/// Calculating the small integer powers as well saves another 80 bytes
+/// of `.rodata`, but adds as much to `.text` and is ~30% slower, and
+/// doubling the step between large powers saves 256 bytes in total, but
+/// is ~45% slower, since more floats require the slow path algorithms.
+/// Neither is worth the tradeoff, so only the large and small integer
+/// powers are stored.
 pub struct BellerophonPowers {
     // Pre-calculated small powers.
+    #[cfg(not(feature = "compact"))]
     pub small: &'static [u64],
     // Pre-calculated large powers.
     pub large: &'static [u64],
@@ -365,7 +358,13 @@ pub struct BellerophonPowers {
 impl BellerophonPowers {
     #[inline]
     pub fn get_small(&self, index: usize) -> ExtendedFloat {
+        #[cfg(not(feature = "compact"))]
         let mant = self.small[index];
+        #[cfg(feature = "compact")]
+        let mant = {
+            let int = self.small_int[index];
+            int << int.leading_zeros()
+        };
         let exp = (1 - 64) + ((self.log2 * index as i64) >> self.log2_shift);
         ExtendedFloat {
             mant,
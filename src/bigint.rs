@@ -11,13 +11,18 @@ use crate::num::{int_pow_fast_path, FastPathRadix};
 use crate::stackvec::StackVec;
 #[cfg(not(feature = "compact"))]
 use crate::table::{LARGE_POW5, LARGE_POW5_STEP};
+#[cfg(feature = "diagnostics")]
+use core::fmt;
 use core::{cmp, ops, ptr};
 
 /// Number of bits in a Bigint.
 ///
 /// This needs to be at least the number of bits required to store
 /// a Bigint, which is `log2(radix**digits)`.
-/// ≅ 3600 for base-10, rounded-up.
+/// ≅ 3600 for base-10, rounded-up. For decimal floats, the limbs
+/// required are bounded by [`slow_path_limb_count`].
+///
+/// [`slow_path_limb_count`]: ../slow/fn.slow_path_limb_count.html
 pub const BIGINT_BITS: usize = 4000;
 
 /// The number of limbs for the bigint.
@@ -66,6 +71,16 @@ impl Bigint {
         }
     }
 
+    /// Construct a bigint representing 0, with space for at least `bits` bits.
+    ///
+    /// This only pre-allocates the storage with the `alloc` feature.
+    #[inline(always)]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            data: VecType::with_capacity(bits_to_limbs(bits)),
+        }
+    }
+
     /// Construct a bigint from an integer.
     #[inline(always)]
     pub fn from_u64(value: u64) -> Self {
@@ -74,24 +89,108 @@ impl Bigint {
         }
     }
 
+    /// Construct a bigint from an integer, with space for at least `bits` bits.
+    ///
+    /// This only pre-allocates the storage with the `alloc` feature.
+    #[inline(always)]
+    pub fn from_u64_with_capacity(value: u64, bits: usize) -> Self {
+        Self {
+            data: from_u64_with_capacity(value, bits_to_limbs(bits)),
+        }
+    }
+
+    /// Reserve space for at least `bits` bits in total.
+    ///
+    /// This only grows the storage with the `alloc` feature.
+    #[inline(always)]
+    pub fn reserve(&mut self, bits: usize) {
+        let additional = bits_to_limbs(bits).saturating_sub(self.data.len());
+        // Cannot fail, since the limbs are at most `BIGINT_LIMBS`.
+        let _ = self.data.try_reserve(additional);
+    }
+
+    /// Get the high 64 bits from the big-integer, and if bits were truncated.
     #[inline(always)]
     pub fn hi64(&self) -> (u64, bool) {
         self.data.hi64()
     }
 
     /// Multiply and assign as if by exponentiation by a power.
+    ///
+    /// The base must be 2, 5, or 10, or with the `radix` feature,
+    /// any radix from 2 to 36.
     #[inline]
     pub fn pow(&mut self, base: u32, exp: u32) -> Option<()> {
+        #[cfg(not(feature = "radix"))]
         debug_assert!(base == 2 || base == 5 || base == 10);
-        if base % 5 == 0 {
-            pow(&mut self.data, exp)?;
+        #[cfg(feature = "radix")]
+        debug_assert!((2..=36).contains(&base));
+
+        // Multiply by the odd factor, and shift by the power of 2.
+        let shift = base.trailing_zeros();
+        match base >> shift {
+            1 => (),
+            5 => pow(&mut self.data, exp)?,
+            #[cfg(feature = "radix")]
+            odd => pow_odd(&mut self.data, odd, exp)?,
+            #[cfg(not(feature = "radix"))]
+            _ => unreachable!(),
         }
-        if base % 2 == 0 {
-            shl(&mut self.data, exp as usize)?;
+        if shift != 0 {
+            shl(&mut self.data, (shift * exp) as usize)?;
         }
         Some(())
     }
 
+    /// Multiply and assign by `5^exp`.
+    ///
+    /// The result must have at most `BIGINT_BITS` bits. Otherwise, this
+    /// returns `None` and the value is unspecified, or with the `alloc`
+    /// feature, it may panic.
+    #[inline]
+    pub fn mul_pow5(&mut self, exp: u32) -> Option<()> {
+        pow(&mut self.data, exp)
+    }
+
+    /// Multiply and assign by `10^exp`.
+    ///
+    /// This is useful to scale significant digits by a positive decimal
+    /// exponent. The result must have at most `BIGINT_BITS` bits. Otherwise,
+    /// this returns `None` and the value is unspecified, or with the `alloc`
+    /// feature, it may panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minimal_lexical::Bigint;
+    ///
+    /// // Scale the significant digits of `1.2345e30` to an integer.
+    /// let mut x = Bigint::from_u64(12345);
+    /// x.mul_pow10(26).unwrap();
+    ///
+    /// // This is identical to scaling by `5^26`, and then `2^26`.
+    /// let mut y = Bigint::from_u64(12345);
+    /// y.mul_pow5(26).unwrap();
+    /// y.shl(26).unwrap();
+    /// assert!(x == y);
+    /// assert_eq!(x.bit_length(), 100);
+    /// ```
+    #[inline]
+    pub fn mul_pow10(&mut self, exp: u32) -> Option<()> {
+        self.mul_pow5(exp)?;
+        self.shl(exp as usize)
+    }
+
+    /// Shift-left and assign by `n` bits, multiplying by `2^n`.
+    ///
+    /// The result must have at most `BIGINT_BITS` bits. Otherwise, this
+    /// returns `None` and the value is unspecified, or with the `alloc`
+    /// feature, it may panic.
+    #[inline]
+    pub fn shl(&mut self, n: usize) -> Option<()> {
+        shl(&mut self.data, n)
+    }
+
     /// Calculate the bit-length of the big-integer.
     #[inline]
     pub fn bit_length(&self) -> u32 {
@@ -99,13 +198,49 @@ impl Bigint {
     }
 }
 
+/// Render the exact decimal value of the big integer.
+///
+/// This is useful to inspect the values compared by the slow path.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::Bigint;
+///
+/// let mut x = Bigint::from_u64(1);
+/// x.pow(10, 30).unwrap();
+/// assert_eq!(x.to_string(), "1000000000000000000000000000000");
+/// ```
+#[cfg(feature = "diagnostics")]
+impl fmt::Display for Bigint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut data = self.data.clone();
+        fmt_decimal(&mut data, f)
+    }
+}
+
+/// Write the decimal digits, from the most significant, 9 digits at a time.
+///
+/// This divides the value in-place, so it is 0 when done.
+#[cfg(feature = "diagnostics")]
+fn fmt_decimal(x: &mut VecType, f: &mut fmt::Formatter) -> fmt::Result {
+    let rem = small_div(x, 1_000_000_000);
+    if x.is_empty() {
+        write!(f, "{}", rem)
+    } else {
+        fmt_decimal(x, f)?;
+        write!(f, "{:09}", rem)
+    }
+}
+
 impl ops::MulAssign<&Bigint> for Bigint {
     fn mul_assign(&mut self, rhs: &Bigint) {
         self.data *= &rhs.data;
     }
 }
 
-/// REVERSE VIEW
+// REVERSE VIEW
+// ------------
 
 /// Reverse, immutable view of a sequence.
 pub struct ReverseView<'a, T: 'a> {
@@ -124,7 +259,7 @@ impl<'a, T> ops::Index<usize> for ReverseView<'a, T> {
 
 /// Create a reverse view of the vector for indexing.
 #[inline]
-pub fn rview(x: &[Limb]) -> ReverseView<Limb> {
+pub fn rview(x: &[Limb]) -> ReverseView<'_, Limb> {
     ReverseView {
         inner: x,
     }
@@ -182,11 +317,26 @@ pub fn is_normalized(x: &[Limb]) -> bool {
 // FROM
 // ----
 
+/// Get the number of limbs required to store `bits` bits.
+///
+/// This is clamped to `BIGINT_LIMBS`, since `BIGINT_BITS` may not be a
+/// multiple of the limb size.
+#[inline(always)]
+pub fn bits_to_limbs(bits: usize) -> usize {
+    cmp::min((bits + LIMB_BITS - 1) / LIMB_BITS, BIGINT_LIMBS)
+}
+
 /// Create StackVec from u64 value.
 #[inline(always)]
-#[allow(clippy::branches_sharing_code)]
 pub fn from_u64(x: u64) -> VecType {
-    let mut vec = VecType::new();
+    from_u64_with_capacity(x, BIGINT_LIMBS)
+}
+
+/// Create StackVec from u64 value, with space for at least `capacity` limbs.
+#[inline(always)]
+#[allow(clippy::branches_sharing_code)]
+pub fn from_u64_with_capacity(x: u64, capacity: usize) -> VecType {
+    let mut vec = VecType::with_capacity(cmp::max(capacity, 2));
     debug_assert!(vec.capacity() >= 2);
     if LIMB_BITS == 32 {
         vec.try_push(x as Limb).unwrap();
@@ -401,6 +551,32 @@ pub fn pow(x: &mut VecType, mut exp: u32) -> Option<()> {
     Some(())
 }
 
+/// MulAssign by a power of a small, odd base.
+///
+/// Unlike [`pow`], this has no pre-computed powers, so it multiplies by
+/// the largest power of the base that fits in a limb at a time.
+///
+/// [`pow`]: fn.pow.html
+#[cfg(feature = "radix")]
+pub fn pow_odd(x: &mut VecType, base: u32, mut exp: u32) -> Option<()> {
+    debug_assert!(base % 2 == 1);
+    let base = base as Limb;
+    let mut max_native = base;
+    let mut step = 1;
+    while let Some(power) = max_native.checked_mul(base) {
+        max_native = power;
+        step += 1;
+    }
+    while exp >= step {
+        small_mul(x, max_native)?;
+        exp -= step;
+    }
+    if exp != 0 {
+        small_mul(x, base.pow(exp))?;
+    }
+    Some(())
+}
+
 // SCALAR
 // ------
 
@@ -422,6 +598,23 @@ pub fn scalar_mul(x: Limb, y: Limb, carry: Limb) -> (Limb, Limb) {
     (z as Limb, (z >> LIMB_BITS) as Limb)
 }
 
+/// Subtract two small integers and return the resulting value and if overflow happens.
+#[inline(always)]
+pub fn scalar_sub(x: Limb, y: Limb) -> (Limb, bool) {
+    x.overflowing_sub(y)
+}
+
+/// Divide a two-limb integer by a small integer.
+///
+/// The high limb, or `rem`, must be less than `y`, so the quotient
+/// fits in a single limb. Returns the (quotient, remainder).
+#[inline(always)]
+pub fn scalar_div(x: Limb, y: Limb, rem: Limb) -> (Limb, Limb) {
+    debug_assert!(rem < y);
+    let z: Wide = ((rem as Wide) << LIMB_BITS) | (x as Wide);
+    ((z / y as Wide) as Limb, (z % y as Wide) as Limb)
+}
+
 // SMALL
 // -----
 
@@ -465,6 +658,22 @@ pub fn small_mul(x: &mut VecType, y: Limb) -> Option<()> {
     Some(())
 }
 
+/// Divide bigint by small integer, returning the remainder.
+///
+/// The divisor must be non-zero.
+#[inline]
+pub fn small_div(x: &mut VecType, y: Limb) -> Limb {
+    debug_assert!(y != 0);
+    let mut rem = 0;
+    for xi in x.iter_mut().rev() {
+        let result = scalar_div(*xi, y, rem);
+        *xi = result.0;
+        rem = result.1;
+    }
+    x.normalize();
+    rem
+}
+
 // LARGE
 // -----
 
@@ -512,6 +721,33 @@ pub fn large_add(x: &mut VecType, y: &[Limb]) -> Option<()> {
     large_add_from(x, y, 0)
 }
 
+/// Subtract bigint from bigint, in-place.
+///
+/// `x` must be greater than or equal to `y`.
+pub fn large_sub(x: &mut VecType, y: &[Limb]) {
+    debug_assert!(compare(x, y) != cmp::Ordering::Less);
+
+    // Iteratively subtract elements from y from x, with borrows.
+    let mut borrow = false;
+    for (index, xi) in x.iter_mut().enumerate() {
+        if index >= y.len() && !borrow {
+            break;
+        }
+        let yi = y.get(index).map_or(0, |&yi| yi);
+        let result = scalar_sub(*xi, yi);
+        *xi = result.0;
+        let mut tmp = result.1;
+        if borrow {
+            let result = scalar_sub(*xi, 1);
+            *xi = result.0;
+            tmp |= result.1;
+        }
+        borrow = tmp;
+    }
+    debug_assert!(!borrow);
+    x.normalize();
+}
+
 /// Grade-school multiplication algorithm.
 ///
 /// Slow, naive algorithm, using limb-bit bases and just shifting left for
@@ -556,7 +792,7 @@ pub fn large_add(x: &mut VecType, y: &[Limb]) -> Option<()> {
 /// ```
 ///
 /// We get the following results:
-
+///
 /// ```text
 /// mul/small:long          time:   [220.23 ns 221.47 ns 222.81 ns]
 /// Found 4 outliers among 100 measurements (4.00%)
@@ -575,18 +811,33 @@ pub fn large_add(x: &mut VecType, y: &[Limb]) -> Option<()> {
 ///
 /// In short, Karatsuba multiplication is never worthwhile for out use-case.
 pub fn long_mul(x: &[Limb], y: &[Limb]) -> Option<VecType> {
+    long_mul_with_capacity(x, y, x.len() + y.len())
+}
+
+/// Multiply bigint by bigint, reserving space for at least `capacity` limbs.
+///
+/// The product has at most `x.len() + y.len()` limbs, so it never
+/// needs to reallocate, and any extra capacity is kept for later
+/// operations on the product.
+#[inline]
+fn long_mul_with_capacity(x: &[Limb], y: &[Limb], capacity: usize) -> Option<VecType> {
     // Using the immutable value, multiply by all the scalars in y, using
     // the algorithm defined above. Use a single buffer to avoid
     // frequent reallocations. Handle the first case to avoid a redundant
     // addition, since we know y.len() >= 1.
-    let mut z = VecType::try_from(x)?;
+    let capacity = cmp::min(cmp::max(capacity, x.len() + y.len()), BIGINT_LIMBS);
+    let mut z = VecType::with_capacity(capacity);
+    z.try_extend(x)?;
     if !y.is_empty() {
         let y0 = y[0];
         small_mul(&mut z, y0)?;
 
+        // Each partial product has at most 1 more limb than `x`.
+        let mut zi = VecType::with_capacity(cmp::min(x.len() + 1, BIGINT_LIMBS));
         for (index, &yi) in y.iter().enumerate().skip(1) {
             if yi != 0 {
-                let mut zi = VecType::try_from(x)?;
+                zi.try_resize(0, 0)?;
+                zi.try_extend(x)?;
                 small_mul(&mut zi, yi)?;
                 large_add_from(&mut z, &zi, index)?;
             }
@@ -606,11 +857,52 @@ pub fn large_mul(x: &mut VecType, y: &[Limb]) -> Option<()> {
         // SAFETY: safe since `y.len() == 1`.
         small_mul(x, y[0])?;
     } else {
-        *x = long_mul(y, x)?;
+        // Keep the capacity, so the product does not need to reallocate.
+        *x = long_mul_with_capacity(y, x, x.capacity())?;
     }
     Some(())
 }
 
+/// Divide bigint by bigint, returning the (quotient, remainder).
+///
+/// This uses binary long division, shifting a single bit of the dividend
+/// into the remainder at a time. Both values must be normalized, and the
+/// divisor must be non-zero.
+pub fn large_div(x: &[Limb], y: &[Limb]) -> Option<(VecType, VecType)> {
+    debug_assert!(is_normalized(x) && is_normalized(y));
+    debug_assert!(!y.is_empty());
+
+    if compare(x, y) == cmp::Ordering::Less {
+        return Some((VecType::new(), VecType::try_from(x)?));
+    } else if y.len() == 1 {
+        let mut quotient = VecType::try_from(x)?;
+        let rem = small_div(&mut quotient, y[0]);
+        return Some((quotient, from_u64(rem as u64)));
+    }
+
+    let mut quotient = VecType::new();
+    quotient.try_resize(x.len(), 0)?;
+    let mut rem = VecType::new();
+    for bit in (0..bit_length(x) as usize).rev() {
+        // Shift in the next bit of the dividend.
+        if !rem.is_empty() {
+            shl_bits(&mut rem, 1)?;
+        }
+        if x[bit / LIMB_BITS] & (1 << (bit % LIMB_BITS)) != 0 {
+            match rem.is_empty() {
+                true => rem.try_push(1)?,
+                false => rem[0] |= 1,
+            }
+        }
+        if compare(&rem, y) != cmp::Ordering::Less {
+            large_sub(&mut rem, y);
+            quotient[bit / LIMB_BITS] |= 1 << (bit % LIMB_BITS);
+        }
+    }
+    quotient.normalize();
+    Some((quotient, rem))
+}
+
 // SHIFT
 // -----
 
@@ -648,11 +940,11 @@ pub fn shl_bits(x: &mut VecType, n: usize) -> Option<()> {
 #[inline]
 pub fn shl_limbs(x: &mut VecType, n: usize) -> Option<()> {
     debug_assert!(n != 0);
-    if n + x.len() > x.capacity() {
+    if x.try_reserve(n).is_none() {
         None
     } else if !x.is_empty() {
         let len = n + x.len();
-        // SAFE: since x is not empty, and `x.len() + n <= x.capacity()`.
+        // SAFE: since x is not empty, and we've reserved `n` additional limbs.
         unsafe {
             // Move the elements.
             let src = x.as_ptr();
@@ -772,17 +1064,19 @@ pub fn bit_length(x: &[Limb]) -> u32 {
 //
 //  All 32-bit architectures inherently do not have support. That means
 //  we can essentially look for 64-bit architectures that are not SPARC.
+//  The `limb_width_64` cfg is set by the build script, so the limb types
+//  and the pre-computed tables are always selected consistently.
 
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(limb_width_64)]
 pub type Limb = u64;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(limb_width_64)]
 pub type Wide = u128;
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(limb_width_64)]
 pub const LIMB_BITS: usize = 64;
 
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(limb_width_64))]
 pub type Limb = u32;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(limb_width_64))]
 pub type Wide = u64;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(limb_width_64))]
 pub const LIMB_BITS: usize = 32;
@@ -0,0 +1,126 @@
+//! Exact, arbitrary-precision decimal representation of a float.
+//!
+//! This is the decimal value that the algorithms round to a float,
+//! normalized so the representation of each value is unique. It is
+//! only required to inspect or compare parsed values, and parsing
+//! floats never uses it.
+
+#![cfg(all(feature = "decimal", any(feature = "std", feature = "alloc")))]
+#![doc(hidden)]
+
+use crate::num::Float;
+use crate::parse::parse_float_exp64;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Exact decimal value, as significant digits and a decimal exponent.
+///
+/// The value is `digits * 10^exponent`, where the digits have no
+/// leading or trailing zeros. Zero has no digits and an exponent of 0.
+/// Since each value has a single representation, two decimals are
+/// equal if and only if they have the same value.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::Decimal;
+///
+/// let decimal = Decimal::from_parts(b"0012".iter(), b"500".iter(), -1);
+/// assert_eq!(decimal.digits(), b"125");
+/// assert_eq!(decimal.exponent(), -2);
+/// assert_eq!(decimal.to_string(), "125e-2");
+/// assert_eq!(decimal.to_float::<f64>(), 1.25);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// The significant digits, as ASCII bytes.
+    digits: Vec<u8>,
+    /// The power of 10 the digits are multiplied by.
+    exponent: i64,
+}
+
+impl Decimal {
+    /// Create a decimal from the integer and fraction digits, and the exponent.
+    ///
+    /// The digits may have leading and trailing zeros, and may be empty,
+    /// however, they must all be valid digits, in the range [`b'0', b'9'].
+    ///
+    /// * `integer`     - Forward iterator over integer digits.
+    /// * `fraction`    - Forward iterator over fraction digits.
+    /// * `exponent`    - Parsed, 32-bit exponent.
+    pub fn from_parts<'a, Iter1, Iter2>(integer: Iter1, fraction: Iter2, exponent: i32) -> Self
+    where
+        Iter1: Iterator<Item = &'a u8>,
+        Iter2: Iterator<Item = &'a u8>,
+    {
+        let mut digits: Vec<u8> = integer.skip_while(|&&c| c == b'0').cloned().collect();
+        let mut exponent = exponent as i64;
+        for &c in fraction {
+            // Skip leading zeros in the fraction, if there are no integer digits.
+            if !digits.is_empty() || c != b'0' {
+                digits.push(c);
+            }
+            exponent -= 1;
+        }
+        debug_assert!(digits.iter().all(u8::is_ascii_digit));
+
+        let zeros = digits.iter().rev().take_while(|&&c| c == b'0').count();
+        digits.truncate(digits.len() - zeros);
+        exponent += zeros as i64;
+        if digits.is_empty() {
+            exponent = 0;
+        }
+        Self {
+            digits,
+            exponent,
+        }
+    }
+
+    /// Get the significant digits, as ASCII bytes.
+    #[inline]
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// Get the power of 10 the digits are multiplied by.
+    #[inline]
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Get if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Round the decimal to the nearest float.
+    ///
+    /// This uses the same algorithms as [`parse_float`], so the float is
+    /// correctly rounded, unless the `no_slow_path` feature is enabled.
+    /// Any exponent outside an `i32` is a literal 0 or infinity.
+    ///
+    /// [`parse_float`]: ../parse/fn.parse_float.html
+    #[inline]
+    pub fn to_float<F: Float>(&self) -> F {
+        parse_float_exp64(self.digits.iter(), [].iter(), self.exponent)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        for &c in self.digits.iter() {
+            write!(f, "{}", c as char)?;
+        }
+        if self.exponent != 0 {
+            write!(f, "e{}", self.exponent)?;
+        }
+        Ok(())
+    }
+}
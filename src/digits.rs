@@ -0,0 +1,232 @@
+//! Fast digit parsing for contiguous slices of digits.
+//!
+//! This parses 8 digits at a time using SWAR (SIMD within a register),
+//! which is portable to all targets. With the `simd` feature, this
+//! parses 16 digits at a time using SSE2 on x86 and x86_64, or NEON on
+//! aarch64, if the target feature is enabled at compile time.
+//!
+//! All algorithms validate that every byte is a digit, otherwise, the
+//! caller must parse a digit at a time.
+
+#![doc(hidden)]
+
+/// Read 8 bytes as a little-endian integer.
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+/// Determine if 8 bytes, read as a little-endian integer, are all digits.
+#[inline]
+pub fn is_8digits(v: u64) -> bool {
+    let a = v.wrapping_add(0x4646_4646_4646_4646);
+    let b = v.wrapping_sub(0x3030_3030_3030_3030);
+    (a | b) & 0x8080_8080_8080_8080 == 0
+}
+
+/// Parse 8 digits, read as a little-endian integer.
+///
+/// All 8 bytes must be digits.
+#[inline]
+pub fn parse_8digits(mut v: u64) -> u64 {
+    const MASK: u64 = 0x0000_00FF_0000_00FF;
+    const MUL1: u64 = 0x000F_4240_0000_0064;
+    const MUL2: u64 = 0x0000_2710_0000_0001;
+    v -= 0x3030_3030_3030_3030;
+    // Combine adjacent digits into 2-digit values: cannot overflow.
+    v = (v * 10) + (v >> 8);
+    let v1 = (v & MASK).wrapping_mul(MUL1);
+    let v2 = ((v >> 16) & MASK).wrapping_mul(MUL2);
+    ((v1.wrapping_add(v2) >> 32) as u32) as u64
+}
+
+/// Try to parse 8 digits from the start of the slice.
+///
+/// The slice must have at least 8 bytes.
+#[inline]
+pub fn try_parse_8digits(bytes: &[u8]) -> Option<u64> {
+    let v = read_u64(bytes);
+    match is_8digits(v) {
+        true => Some(parse_8digits(v)),
+        false => None,
+    }
+}
+
+/// Try to parse 16 digits from the start of the slice using SWAR.
+///
+/// The slice must have at least 16 bytes.
+#[inline]
+pub fn try_parse_16digits_swar(bytes: &[u8]) -> Option<u64> {
+    let hi = try_parse_8digits(bytes)?;
+    let lo = try_parse_8digits(&bytes[8..])?;
+    Some(hi * 100_000_000 + lo)
+}
+
+/// Try to parse 16 digits from the start of the slice.
+///
+/// The slice must have at least 16 bytes. This uses the vectorized
+/// algorithms if they're available, otherwise, SWAR.
+#[inline]
+pub fn try_parse_16digits(bytes: &[u8]) -> Option<u64> {
+    assert!(bytes.len() >= 16);
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    // SAFETY: safe, since we have at least 16 bytes and SSE2 is enabled.
+    return unsafe { sse2::try_parse_16digits(bytes) };
+
+    #[cfg(all(
+        feature = "simd",
+        target_arch = "aarch64",
+        target_feature = "neon",
+        target_endian = "little"
+    ))]
+    // SAFETY: safe, since we have at least 16 bytes and NEON is enabled.
+    return unsafe { neon::try_parse_16digits(bytes) };
+
+    #[cfg(not(any(
+        all(
+            feature = "simd",
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(
+            feature = "simd",
+            target_arch = "aarch64",
+            target_feature = "neon",
+            target_endian = "little"
+        )
+    )))]
+    return try_parse_16digits_swar(bytes);
+}
+
+/// Add the digits to the mantissa, as if by `mantissa * 10^N + digits`.
+///
+/// The digits are parsed 16 or 8 at a time, and the remaining digits
+/// are parsed one at a time. The caller must ensure the result cannot
+/// overflow, that is, at most 19 total significant digits.
+#[inline]
+pub fn parse_digits(mut mantissa: u64, mut bytes: &[u8]) -> u64 {
+    while bytes.len() >= 16 {
+        match try_parse_16digits(bytes) {
+            Some(value) => mantissa = mantissa * 10_000_000_000_000_000 + value,
+            None => break,
+        }
+        bytes = &bytes[16..];
+    }
+    while bytes.len() >= 8 {
+        match try_parse_8digits(bytes) {
+            Some(value) => mantissa = mantissa * 100_000_000 + value,
+            None => break,
+        }
+        bytes = &bytes[8..];
+    }
+    for &c in bytes {
+        let digit = c - b'0';
+        mantissa = mantissa * 10 + digit as u64;
+    }
+    mantissa
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod sse2 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Try to parse 16 digits from the start of the slice, using SSE2.
+    ///
+    /// # Safety
+    ///
+    /// The slice must have at least 16 bytes, and SSE2 must be enabled.
+    #[inline]
+    pub unsafe fn try_parse_16digits(bytes: &[u8]) -> Option<u64> {
+        debug_assert!(bytes.len() >= 16);
+
+        // SAFETY: safe, since we have at least 16 bytes and SSE2 is enabled.
+        unsafe {
+            let chunk = _mm_loadu_si128(bytes.as_ptr() as *const __m128i);
+            let digits = _mm_sub_epi8(chunk, _mm_set1_epi8(b'0' as i8));
+
+            // Validate all digits: the unsigned maximum must be 9.
+            let nine = _mm_set1_epi8(9);
+            let is_valid = _mm_cmpeq_epi8(_mm_max_epu8(digits, nine), nine);
+            if _mm_movemask_epi8(is_valid) != 0xFFFF {
+                return None;
+            }
+
+            // Combine into 2-digit values, as 16-bit integers.
+            let lo = _mm_and_si128(digits, _mm_set1_epi16(0xFF));
+            let hi = _mm_srli_epi16(digits, 8);
+            let pairs = _mm_add_epi16(_mm_mullo_epi16(lo, _mm_set1_epi16(10)), hi);
+            // Combine into 4-digit values, as 32-bit integers.
+            let quads = _mm_madd_epi16(pairs, _mm_set1_epi32(100 | (1 << 16)));
+            // Combine into 8-digit values, as 32-bit integers.
+            let quads = _mm_packs_epi32(quads, quads);
+            let octets = _mm_madd_epi16(quads, _mm_set1_epi32(10000 | (1 << 16)));
+
+            let hi = _mm_cvtsi128_si32(octets) as u32 as u64;
+            let lo = _mm_cvtsi128_si32(_mm_srli_si128(octets, 4)) as u32 as u64;
+            Some(hi * 100_000_000 + lo)
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "simd",
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    /// Try to parse 16 digits from the start of the slice, using NEON.
+    ///
+    /// # Safety
+    ///
+    /// The slice must have at least 16 bytes, and NEON must be enabled.
+    #[inline]
+    pub unsafe fn try_parse_16digits(bytes: &[u8]) -> Option<u64> {
+        debug_assert!(bytes.len() >= 16);
+
+        // SAFETY: safe, since we have at least 16 bytes and NEON is enabled.
+        unsafe {
+            let chunk = vld1q_u8(bytes.as_ptr());
+            let digits = vsubq_u8(chunk, vdupq_n_u8(b'0'));
+
+            // Validate all digits: the unsigned maximum must be 9.
+            if vmaxvq_u8(digits) > 9 {
+                return None;
+            }
+
+            // Combine into 2-digit values, as 16-bit integers.
+            let digits = vreinterpretq_u16_u8(digits);
+            let lo = vandq_u16(digits, vdupq_n_u16(0xFF));
+            let hi = vshrq_n_u16(digits, 8);
+            let pairs = vmlaq_n_u16(hi, lo, 10);
+            // Combine into 4-digit values, as 32-bit integers.
+            let pairs = vreinterpretq_u32_u16(pairs);
+            let lo = vandq_u32(pairs, vdupq_n_u32(0xFFFF));
+            let hi = vshrq_n_u32(pairs, 16);
+            let quads = vreinterpretq_u64_u32(vmlaq_n_u32(hi, lo, 100));
+
+            // Combine into 8-digit values.
+            let hi = vgetq_lane_u64(quads, 0);
+            let lo = vgetq_lane_u64(quads, 1);
+            let hi = (hi & 0xFFFF_FFFF) * 10000 + (hi >> 32);
+            let lo = (lo & 0xFFFF_FFFF) * 10000 + (lo >> 32);
+            Some(hi * 100_000_000 + lo)
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Const-evaluable parsing of exactly representable float literals.
+//!
+//! This only handles literals which are exactly representable as the
+//! float, without rounding, so it never needs the moderate or slow path
+//! algorithms. It only uses integer arithmetic, so it can be evaluated
+//! at compile time, and requires Rust 1.83 or later.
+
+#![cfg(feature = "const_fn")]
+#![doc(hidden)]
+
+use crate::num::Float;
+
+/// Parse the sign, significant digits and decimal exponent of a literal.
+///
+/// The literal is an optional sign, the integer digits, an optional `.`
+/// followed by the fraction digits, and an optional exponent, introduced
+/// by `e` or `E`, followed by an optional sign and the exponent digits.
+/// Returns `None` if the literal is invalid, or if the significant
+/// digits overflow a `u64`. Trailing zeros are moved into the exponent,
+/// so they are not significant digits.
+const fn parse_literal(bytes: &[u8]) -> Option<(bool, u64, i32)> {
+    let mut index = 0;
+    let is_negative = match !bytes.is_empty() && (bytes[0] == b'+' || bytes[0] == b'-') {
+        true => {
+            index += 1;
+            bytes[0] == b'-'
+        },
+        false => false,
+    };
+
+    // Parse the integer and fraction digits. Trailing zeros are deferred,
+    // and only added to the mantissa if followed by a non-zero digit,
+    // so they never cause an overflow.
+    let mut mantissa: u64 = 0;
+    let mut exponent: i32 = 0;
+    let mut zeros: i32 = 0;
+    let mut digits = 0;
+    let mut is_fraction = false;
+    while index < bytes.len() {
+        let c = bytes[index];
+        if c == b'.' && !is_fraction {
+            is_fraction = true;
+        } else if c.is_ascii_digit() {
+            if c != b'0' {
+                let mut count = 0;
+                while count <= zeros {
+                    mantissa = match mantissa.checked_mul(10) {
+                        Some(value) => value,
+                        None => return None,
+                    };
+                    count += 1;
+                }
+                mantissa = match mantissa.checked_add((c - b'0') as u64) {
+                    Some(value) => value,
+                    None => return None,
+                };
+                zeros = 0;
+            } else if mantissa != 0 {
+                zeros = zeros.saturating_add(1);
+            }
+            if is_fraction {
+                exponent = exponent.saturating_sub(1);
+            }
+            digits += 1;
+        } else {
+            break;
+        }
+        index += 1;
+    }
+    if digits == 0 {
+        return None;
+    }
+    exponent = exponent.saturating_add(zeros);
+
+    // Parse the exponent, saturating on overflow.
+    if index < bytes.len() && (bytes[index] == b'e' || bytes[index] == b'E') {
+        index += 1;
+        let mut is_exponent_positive = true;
+        if index < bytes.len() && (bytes[index] == b'+' || bytes[index] == b'-') {
+            is_exponent_positive = bytes[index] == b'+';
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+        let mut value: i32 = 0;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            value = value.saturating_mul(10).saturating_add((bytes[index] - b'0') as i32);
+            index += 1;
+        }
+        exponent = match is_exponent_positive {
+            true => exponent.saturating_add(value),
+            false => exponent.saturating_sub(value),
+        };
+    }
+
+    match index == bytes.len() {
+        true => Some((is_negative, mantissa, exponent)),
+        false => None,
+    }
+}
+
+/// Get the bits of the float exactly representing `mantissa * 10^exponent`.
+///
+/// The value is decomposed into an odd mantissa and a power of 2, which
+/// is exact only if the powers of 5 in the exponent can be removed from,
+/// or added to, the mantissa without overflow. Returns `None` if the
+/// value is not exactly representable.
+///
+/// * `mantissa_size`   - Number of explicit mantissa bits in the float.
+/// * `exponent_bias`   - Exponent bias, relative to an integral mantissa.
+/// * `max_exponent`    - Maximum, biased exponent for a finite float.
+const fn exact_bits(
+    mut mantissa: u64,
+    exponent: i32,
+    mantissa_size: i32,
+    exponent_bias: i32,
+    max_exponent: i32,
+) -> Option<u64> {
+    if mantissa == 0 {
+        return Some(0);
+    }
+
+    // Remove the trailing zero bits first, so the odd mantissa only
+    // overflows when adding powers of 5 if it has too many bits.
+    let zeros = mantissa.trailing_zeros() as i32;
+    mantissa >>= zeros;
+    let mut exp2 = exponent.saturating_add(zeros);
+
+    // Remove the powers of 5, since `10^N = 5^N * 2^N`.
+    let mut count = exponent;
+    while count > 0 {
+        mantissa = match mantissa.checked_mul(5) {
+            Some(value) => value,
+            None => return None,
+        };
+        count -= 1;
+    }
+    while count < 0 {
+        if mantissa % 5 != 0 {
+            return None;
+        }
+        mantissa /= 5;
+        count += 1;
+    }
+
+    // Check the odd mantissa fits.
+    let bits = 64 - mantissa.leading_zeros() as i32;
+    if bits > mantissa_size + 1 {
+        return None;
+    }
+
+    // Shift the mantissa to the hidden bit, and get the biased exponent.
+    let shift = mantissa_size + 1 - bits;
+    mantissa <<= shift;
+    exp2 -= shift;
+    // Denormal floats need far more than 19 significant digits to be
+    // exact, so they are never exactly representable here.
+    let biased = exp2 + exponent_bias;
+    if biased <= 0 || biased > max_exponent {
+        return None;
+    }
+
+    let mantissa_mask = (1 << mantissa_size) - 1;
+    Some(((biased as u64) << mantissa_size) | (mantissa & mantissa_mask))
+}
+
+/// Parse a float literal to an `f64`, if it is exactly representable.
+///
+/// The literal is an optional sign, the integer digits, an optional `.`
+/// followed by the fraction digits, and an optional exponent, introduced
+/// by `e` or `E`, followed by an optional sign and the exponent digits.
+/// Returns `None` if the literal is invalid, has more significant digits
+/// than fit in a `u64`, or would require rounding.
+///
+/// * `literal`     - Complete float literal.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_float_exact;
+///
+/// const X: f64 = match parse_float_exact("1.5") {
+///     Some(value) => value,
+///     None => panic!("inexact literal"),
+/// };
+/// assert_eq!(X, 1.5);
+/// assert_eq!(parse_float_exact("0.1"), None);
+/// ```
+pub const fn parse_float_exact(literal: &str) -> Option<f64> {
+    let (is_negative, mantissa, exponent) = match parse_literal(literal.as_bytes()) {
+        Some(parts) => parts,
+        None => return None,
+    };
+    let max_exponent = (f64::EXPONENT_MASK >> f64::MANTISSA_SIZE) as i32 - 1;
+    match exact_bits(mantissa, exponent, f64::MANTISSA_SIZE, f64::EXPONENT_BIAS, max_exponent) {
+        Some(bits) => Some(f64::from_bits(bits | ((is_negative as u64) << 63))),
+        None => None,
+    }
+}
+
+/// Parse a float literal to an `f32`, if it is exactly representable.
+///
+/// This is identical to [`parse_float_exact`], except for `f32`.
+///
+/// * `literal`     - Complete float literal.
+///
+/// [`parse_float_exact`]: fn.parse_float_exact.html
+pub const fn parse_float_exact_f32(literal: &str) -> Option<f32> {
+    let (is_negative, mantissa, exponent) = match parse_literal(literal.as_bytes()) {
+        Some(parts) => parts,
+        None => return None,
+    };
+    let max_exponent = (f32::EXPONENT_MASK >> f32::MANTISSA_SIZE) as i32 - 1;
+    match exact_bits(mantissa, exponent, f32::MANTISSA_SIZE, f32::EXPONENT_BIAS, max_exponent) {
+        Some(bits) => Some(f32::from_bits(bits as u32 | ((is_negative as u32) << 31))),
+        None => None,
+    }
+}
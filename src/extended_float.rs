@@ -1,13 +1,32 @@
-// FLOAT TYPE
-
-#![doc(hidden)]
+//! Extended-precision float type.
+//!
+//! A minimal, extended-precision float with a 64-bit mantissa, used
+//! by the moderate and slow path algorithms. This is also useful as a
+//! building block for manual, extended-precision scaling of floats.
 
 use crate::num::Float;
+use crate::rounding::{round, round_down, round_nearest_tie_even, round_up};
+#[cfg(feature = "widening_mul")]
+use crate::wide::mul_u64;
+use core::cmp;
 
 /// Extended precision floating-point type.
 ///
-/// Private implementation, exposed only for testing purposes.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The value is exactly `mant * 2^exp`. A float is normalized when the
+/// most significant bit of the mantissa is set, which maximizes the
+/// precision of each operation. Unlike native floats, the value has no
+/// sign, and there are no special values for infinity or NaN.
+///
+/// The moderate path algorithms store a biased exponent, however, all
+/// public methods expect an unbiased exponent, such as those returned
+/// by [`from_float`].
+///
+/// Comparisons use the value, so floats with different mantissas and
+/// exponents are equal if they represent the same value, such as
+/// `1 * 2^1` and `2 * 2^0`.
+///
+/// [`from_float`]: #method.from_float
+#[derive(Clone, Copy, Debug)]
 pub struct ExtendedFloat {
     /// Mantissa for the extended-precision float.
     pub mant: u64,
@@ -15,6 +34,406 @@ pub struct ExtendedFloat {
     pub exp: i32,
 }
 
+impl ExtendedFloat {
+    /// Create a normalized, extended-precision float from a native float.
+    ///
+    /// The most significant bit of the mantissa is set, and the exponent
+    /// is unbiased, so the value is exactly `mant * 2^exp`. A literal 0
+    /// has a mantissa and exponent of 0. The float must be finite, and
+    /// the sign is ignored.
+    #[inline]
+    pub fn from_float<F: Float>(float: F) -> Self {
+        let mant = float.mantissa();
+        if mant == 0 {
+            return ExtendedFloat {
+                mant: 0,
+                exp: 0,
+            };
+        }
+        let shift = mant.leading_zeros() as i32;
+        ExtendedFloat {
+            mant: mant << shift,
+            exp: float.exponent() - shift,
+        }
+    }
+
+    /// Normalize the float, shifting the mantissa so the most significant
+    /// bit is set, unless the value is 0.
+    ///
+    /// Returns the number of bits shifted.
+    #[inline]
+    pub fn normalize(&mut self) -> i32 {
+        // Note:
+        // Using the ctlz intrinsic via leading_zeros is way faster (~10x)
+        // than shifting 1-bit at a time, via while loop, and also way
+        // faster (~2x) than an unrolled loop that checks at 32, 16, 4,
+        // 2, and 1 bit.
+        //
+        // Calculate the number of leading zeros, and then zero-out
+        // any overflowing bits, to avoid shl overflow when self.mant == 0.
+        if self.mant != 0 {
+            let shift = self.mant.leading_zeros() as i32;
+            self.shl(shift);
+            shift
+        } else {
+            0
+        }
+    }
+
+    /// Multiply two normalized extended-precision floats, as if by `a*b`.
+    ///
+    /// The precision is maximal when the numbers are normalized, however,
+    /// decent precision will occur as long as both values have high bits
+    /// set. The truncated product is rounded to nearest, with ties rounded
+    /// up, and the result is not normalized.
+    ///
+    /// Algorithm:
+    ///     1. Non-signed multiplication of mantissas (requires 2x as many bits as input).
+    ///     2. Normalization of the result (not done here).
+    ///     3. Addition of exponents.
+    #[inline]
+    pub fn mul(&self, y: &ExtendedFloat) -> ExtendedFloat {
+        // Logic check, values must be decently normalized prior to multiplication.
+        debug_assert!(self.mant >> 32 != 0);
+        debug_assert!(y.mant >> 32 != 0);
+
+        ExtendedFloat {
+            mant: mul_round(self.mant, y.mant),
+            exp: self.exp + y.exp + 64,
+        }
+    }
+
+    /// Multiply two extended-precision floats, as if by `a*b`, normalizing them first.
+    ///
+    /// Unlike [`mul`], the floats may have any mantissa, including 0, so
+    /// this has the maximal precision for any input. The result has the
+    /// same rounding as [`mul`], and is not normalized.
+    ///
+    /// [`mul`]: #method.mul
+    #[inline]
+    pub fn mul_unnormalized(&self, y: &ExtendedFloat) -> ExtendedFloat {
+        if self.mant == 0 || y.mant == 0 {
+            return ExtendedFloat {
+                mant: 0,
+                exp: 0,
+            };
+        }
+
+        let mut x = *self;
+        let mut y = *y;
+        x.normalize();
+        y.normalize();
+        x.mul(&y)
+    }
+
+    /// Multiply the float by another in-place, as if by `a *= b`.
+    ///
+    /// This has the same semantics as [`mul`].
+    ///
+    /// [`mul`]: #method.mul
+    #[inline]
+    pub fn imul(&mut self, y: &ExtendedFloat) {
+        *self = self.mul(y);
+    }
+
+    /// Add two normalized extended-precision floats, as if by `a+b`.
+    ///
+    /// The float with the smaller exponent is shifted into place as if
+    /// by [`shr`], and any carry out of the mantissa is shifted back in,
+    /// rounding to nearest, with ties rounded to even. The sum of two
+    /// normalized floats is normalized.
+    ///
+    /// [`shr`]: #method.shr
+    #[inline]
+    pub fn add(&self, y: &ExtendedFloat) -> ExtendedFloat {
+        if self.mant == 0 {
+            return *y;
+        } else if y.mant == 0 {
+            return *self;
+        }
+
+        let (mut x, mut y) = match self.exp >= y.exp {
+            true => (*self, *y),
+            false => (*y, *self),
+        };
+        y.shr(x.exp - y.exp);
+
+        let (mant, carry) = x.mant.overflowing_add(y.mant);
+        x.mant = mant;
+        if carry {
+            // Shift the carry bit into the mantissa, and round the truncated
+            // bit: it is only ever halfway, so round-up if we're odd.
+            let is_round_up = mant & 3 == 3;
+            x.mant = (mant >> 1) | (1 << 63);
+            x.exp += 1;
+            if is_round_up {
+                match x.mant.checked_add(1) {
+                    Some(mant) => x.mant = mant,
+                    None => {
+                        x.mant = 1 << 63;
+                        x.exp += 1;
+                    },
+                }
+            }
+        }
+        x
+    }
+
+    /// Add two extended-precision floats, as if by `a+b`, truncating the result.
+    ///
+    /// The floats may have any mantissa, and are normalized first. The
+    /// float with the smaller exponent is aligned in a 128-bit mantissa,
+    /// so no bits are lost unless the exponents differ by more than 64.
+    /// The normalized sum is truncated to 64 bits, and returned with a
+    /// sticky bit, set if any set bits were truncated, so the exact sum is
+    /// in `[mant, mant + 1) * 2^exp`, and is exactly `mant * 2^exp` only
+    /// if the sticky bit is not set.
+    #[inline]
+    pub fn add_truncated(&self, y: &ExtendedFloat) -> (ExtendedFloat, bool) {
+        let (x, y) = match (normalized(*self), normalized(*y)) {
+            (x, y) if y.mant == 0 || (x.mant != 0 && x.exp >= y.exp) => (x, y),
+            (x, y) => (y, x),
+        };
+        let (xw, yw, is_truncated) = match align(&x, &y) {
+            Some(aligned) => aligned,
+            None => return (x, false),
+        };
+
+        let (sum, carry) = xw.overflowing_add(yw);
+        if carry {
+            // Shift the carry bit into the mantissa.
+            let fp = ExtendedFloat {
+                mant: (1 << 63) | (sum >> 65) as u64,
+                exp: x.exp + 1,
+            };
+            (fp, is_truncated || sum & ((1 << 65) - 1) != 0)
+        } else {
+            let fp = ExtendedFloat {
+                mant: (sum >> 64) as u64,
+                exp: x.exp,
+            };
+            (fp, is_truncated || sum as u64 != 0)
+        }
+    }
+
+    /// Subtract two extended-precision floats, as if by `a-b`, truncating the result.
+    ///
+    /// This has the same semantics as [`add_truncated`], and the float
+    /// must not be smaller than the subtrahend, since the value has no
+    /// sign. The difference is normalized, including when the leading
+    /// bits cancel, and an exact difference of 0 has a mantissa and
+    /// exponent of 0.
+    ///
+    /// [`add_truncated`]: #method.add_truncated
+    #[inline]
+    pub fn sub_truncated(&self, y: &ExtendedFloat) -> (ExtendedFloat, bool) {
+        debug_assert!(self >= y, "sub_truncated() negative difference.");
+        let x = normalized(*self);
+        let (xw, yw, is_truncated) = match align(&x, &normalized(*y)) {
+            Some(aligned) => aligned,
+            None => return (x, false),
+        };
+
+        // If the subtrahend was truncated, the exact difference is
+        // slightly smaller, so borrow from the truncated difference.
+        let diff = xw - yw - is_truncated as u128;
+        if diff == 0 {
+            let zero = ExtendedFloat {
+                mant: 0,
+                exp: 0,
+            };
+            return (zero, false);
+        }
+        let shift = diff.leading_zeros();
+        let diff = diff << shift;
+        let fp = ExtendedFloat {
+            mant: (diff >> 64) as u64,
+            exp: x.exp - shift as i32,
+        };
+        (fp, is_truncated || diff as u64 != 0)
+    }
+
+    /// Shift the mantissa left, as if by `a << n`, without changing the value.
+    ///
+    /// The exponent is decreased by the shift, so the value is exact.
+    /// The shift must be in the range `[0, 64)`, and no set bits may be
+    /// shifted out of the mantissa, that is, the shift must be at most
+    /// the number of leading zeros. This is the inverse of [`shr`]: a
+    /// left shift followed by a right shift of the same amount is the
+    /// identity, since no bits are truncated.
+    ///
+    /// [`shr`]: #method.shr
+    #[inline]
+    pub fn shl(&mut self, shift: i32) {
+        debug_assert!((0..64).contains(&shift), "shl() overflow in shift.");
+        debug_assert!(self.mant.leading_zeros() as i32 >= shift, "shl() truncated bits.");
+        self.mant <<= shift;
+        self.exp -= shift;
+    }
+
+    /// Shift the mantissa right, as if by `a >> n`, keeping the same value.
+    ///
+    /// The exponent is increased by the shift, and the truncated bits are
+    /// rounded to nearest, with ties rounded to even, the same as when
+    /// rounding to a machine float, so the value is only exact if no set
+    /// bits are truncated. The shift must be non-negative, and shifting by
+    /// more than 64 bits always rounds to 0.
+    #[inline]
+    pub fn shr(&mut self, shift: i32) {
+        debug_assert!(shift >= 0, "shr() underflow in shift.");
+        if shift == 0 {
+            return;
+        } else if shift > 64 {
+            self.mant = 0;
+            self.exp += shift;
+            return;
+        }
+        round_nearest_tie_even(self, shift, |is_odd, is_halfway, is_above| {
+            is_above || (is_odd && is_halfway)
+        });
+    }
+
+    /// Round the extended-precision float to the nearest machine float.
+    ///
+    /// The float must be normalized with an unbiased exponent, as
+    /// returned by [`from_float`], and ties are rounded to even.
+    ///
+    /// [`from_float`]: #method.from_float
+    #[inline]
+    pub fn into_float<F: Float>(self) -> F {
+        self.into_rounded_float::<F, _>(|fp, shift| {
+            round_nearest_tie_even(fp, shift, |is_odd, is_halfway, is_above| {
+                is_above || (is_odd && is_halfway)
+            });
+        })
+    }
+
+    /// Round the extended-precision float towards zero to a machine float.
+    ///
+    /// The float must be normalized with an unbiased exponent, as
+    /// returned by [`from_float`]. Values too large to be represented
+    /// round to the largest finite float.
+    ///
+    /// [`from_float`]: #method.from_float
+    #[inline]
+    pub fn into_downward_float<F: Float>(self) -> F {
+        let float = self.into_rounded_float::<F, _>(round_down);
+        match float.to_bits() == F::EXPONENT_MASK {
+            true => F::from_bits(F::EXPONENT_MASK - 1),
+            false => float,
+        }
+    }
+
+    /// Round the extended-precision float towards infinity to a machine float.
+    ///
+    /// The float must be normalized with an unbiased exponent, as
+    /// returned by [`from_float`]. Values too large to be represented
+    /// round to infinity.
+    ///
+    /// [`from_float`]: #method.from_float
+    #[inline]
+    pub fn into_upward_float<F: Float>(self) -> F {
+        self.into_rounded_float::<F, _>(round_up)
+    }
+
+    /// Get the exponent of the most significant bit and the normalized mantissa.
+    ///
+    /// This orders floats by value, regardless of normalization,
+    /// and a literal 0 is smaller than any other value.
+    #[inline]
+    fn ordering_key(&self) -> (i64, u64) {
+        if self.mant == 0 {
+            return (i64::min_value(), 0);
+        }
+        let shift = self.mant.leading_zeros();
+        (self.exp as i64 + 63 - shift as i64, self.mant << shift)
+    }
+
+    /// Round the extended-precision float to a machine float using a callback.
+    #[inline]
+    fn into_rounded_float<F, Cb>(self, cb: Cb) -> F
+    where
+        F: Float,
+        Cb: Fn(&mut ExtendedFloat, i32),
+    {
+        if self.mant == 0 {
+            return F::from_bits(0);
+        }
+
+        let mut fp = self;
+        fp.exp += F::EXPONENT_BIAS;
+        if -fp.exp + 1 >= 65 {
+            // Have more than 64 bits below the minimum exponent, so the
+            // value is less than half the smallest denormal. Use a value
+            // with the same rounding behavior that can be shifted into place.
+            fp = ExtendedFloat {
+                mant: 1,
+                exp: -63,
+            };
+        }
+        round::<F, _>(&mut fp, cb);
+        extended_to_float::<F>(fp)
+    }
+}
+
+impl PartialEq for ExtendedFloat {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ordering_key() == other.ordering_key()
+    }
+}
+
+impl Eq for ExtendedFloat {
+}
+
+impl PartialOrd for ExtendedFloat {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExtendedFloat {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.ordering_key().cmp(&other.ordering_key())
+    }
+}
+
+/// Normalize a float, where a literal 0 has a mantissa and exponent of 0.
+#[inline]
+fn normalized(mut x: ExtendedFloat) -> ExtendedFloat {
+    if x.mant == 0 {
+        x.exp = 0;
+    }
+    x.normalize();
+    x
+}
+
+/// Align two normalized floats as 128-bit mantissas, with the exponent of the larger float.
+///
+/// Returns the aligned mantissas, and if any set bits of the smaller
+/// float were truncated, or `None` if the smaller float is 0. The larger
+/// float's mantissa is in the high 64 bits, so the smaller float is
+/// exact unless the exponents differ by more than 64.
+#[inline]
+fn align(x: &ExtendedFloat, y: &ExtendedFloat) -> Option<(u128, u128, bool)> {
+    if y.mant == 0 {
+        return None;
+    }
+    debug_assert!(x.exp >= y.exp, "align() smaller float first.");
+    let shift = x.exp as i64 - y.exp as i64;
+    let (yw, is_truncated) = if shift <= 64 {
+        (((y.mant as u128) << 64) >> shift, false)
+    } else if shift < 128 {
+        let shift = shift - 64;
+        ((y.mant >> shift) as u128, y.mant & ((1 << shift) - 1) != 0)
+    } else {
+        (0, true)
+    };
+    Some(((x.mant as u128) << 64, yw, is_truncated))
+}
+
 /// Converts an `ExtendedFloat` to the closest machine float type.
 #[inline(always)]
 pub fn extended_to_float<F: Float>(x: ExtendedFloat) -> F {
@@ -22,3 +441,36 @@ pub fn extended_to_float<F: Float>(x: ExtendedFloat) -> F {
     word |= (x.exp as u64) << F::MANTISSA_SIZE;
     F::from_bits(word)
 }
+
+/// Get the high 64 bits of the 128-bit product, rounded to nearest, with ties up.
+#[inline(always)]
+#[cfg(feature = "widening_mul")]
+fn mul_round(x: u64, y: u64) -> u64 {
+    // Cannot overflow: the high bits are at most `2^64 - 2`.
+    let (lo, hi) = mul_u64(x, y);
+    hi + (lo >> 63)
+}
+
+/// Get the high 64 bits of the 128-bit product, rounded to nearest, with ties up.
+#[inline(always)]
+#[cfg(not(feature = "widening_mul"))]
+fn mul_round(x: u64, y: u64) -> u64 {
+    // Extract high-and-low masks.
+    // Mask is u32::MAX for older Rustc versions.
+    const LOMASK: u64 = 0xffff_ffff;
+    let x1 = x >> 32;
+    let x0 = x & LOMASK;
+    let y1 = y >> 32;
+    let y0 = y & LOMASK;
+
+    // Get our products
+    let x1_y0 = x1 * y0;
+    let x0_y1 = x0 * y1;
+    let x0_y0 = x0 * y0;
+    let x1_y1 = x1 * y1;
+
+    let mut tmp = (x1_y0 & LOMASK) + (x0_y1 & LOMASK) + (x0_y0 >> 32);
+    // round up
+    tmp += 1 << (32 - 1);
+    x1_y1 + (x1_y0 >> 32) + (x0_y1 >> 32) + (tmp >> 32)
+}
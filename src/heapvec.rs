@@ -11,7 +11,6 @@ use core::{cmp, ops};
 use std::vec::Vec;
 
 /// Simple heap vector implementation.
-#[derive(Clone)]
 pub struct HeapVec {
     /// The heap-allocated buffer for the elements.
     data: Vec<bigint::Limb>,
@@ -22,8 +21,15 @@ impl HeapVec {
     /// Construct an empty vector.
     #[inline]
     pub fn new() -> Self {
+        Self::with_capacity(bigint::BIGINT_LIMBS)
+    }
+
+    /// Construct an empty vector, with space for at least `capacity` items.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        debug_assert!(capacity <= bigint::BIGINT_LIMBS);
         Self {
-            data: Vec::with_capacity(bigint::BIGINT_LIMBS),
+            data: Vec::with_capacity(capacity),
         }
     }
 
@@ -75,6 +81,20 @@ impl HeapVec {
         Some(())
     }
 
+    /// Reserve space for `additional` more items.
+    ///
+    /// Returns `None` if the vector would have more than the maximum
+    /// number of items for a big integer.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Option<()> {
+        if self.len() + additional <= bigint::BIGINT_LIMBS {
+            self.data.reserve(additional);
+            Some(())
+        } else {
+            None
+        }
+    }
+
     /// Remove an item from the end of the vector and return it, or None if empty.
     #[inline]
     pub fn pop(&mut self) -> Option<bigint::Limb> {
@@ -141,6 +161,16 @@ impl HeapVec {
     }
 }
 
+impl Clone for HeapVec {
+    #[inline]
+    fn clone(&self) -> Self {
+        // Cloning a `Vec` only reserves the length, not the full capacity.
+        let mut vec = Self::with_capacity(self.capacity());
+        vec.data.extend_from_slice(&self.data);
+        vec
+    }
+}
+
 impl PartialEq for HeapVec {
     #[inline]
     #[allow(clippy::op_ref)]
@@ -156,7 +186,7 @@ impl Eq for HeapVec {
 impl cmp::PartialOrd for HeapVec {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(bigint::compare(self, other))
+        Some(self.cmp(other))
     }
 }
 
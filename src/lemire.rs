@@ -2,14 +2,28 @@
 //!
 //! This is adapted from [fast-float-rust](https://github.com/aldanor/fast-float-rust),
 //! a port of [fast_float](https://github.com/fastfloat/fast_float) to Rust.
+//!
+//! Only 32-bit and 64-bit floats are supported. The algorithm, the
+//! extended-precision float and the [`Float`] trait all assume the
+//! mantissa fits in 64 bits, so 128-bit floats would require wider
+//! representations throughout, a 192-bit power-of-5 table, and a
+//! larger slow path, which are not currently implemented.
+//!
+//! The power-of-5 table and the `log2(10)` approximation are provided
+//! by the [`Float`] trait, so a float type may provide its own table
+//! without changing the algorithm.
+//!
+//! [`Float`]: ../num/trait.Float.html
 
-#![cfg(not(feature = "compact"))]
+#![cfg(not(any(feature = "compact", feature = "bellerophon")))]
 #![doc(hidden)]
 
 use crate::extended_float::ExtendedFloat;
 use crate::num::Float;
 use crate::number::Number;
-use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+use crate::wide::mul_u64;
+#[cfg(feature = "wide_mantissa")]
+use crate::wide::mul_wide;
 
 /// Ensure truncation of digits doesn't affect our computation, by doing 2 passes.
 #[inline]
@@ -19,7 +33,7 @@ pub fn lemire<F: Float>(num: &Number) -> ExtendedFloat {
     // redundantly using the Eisel-Lemire algorithm if it was unable to
     // correctly round on the first pass.
     let mut fp = compute_float::<F>(num.exponent, num.mantissa);
-    if num.many_digits && fp.exp >= 0 && fp != compute_float::<F>(num.exponent, num.mantissa + 1) {
+    if num.many_digits && fp.exp >= 0 && !is_upper_rounded_same::<F>(num, fp) {
         // Need to re-calculate, since the previous values are rounded
         // when the slow path algorithm expects a normalized extended float.
         fp = compute_error::<F>(num.exponent, num.mantissa);
@@ -27,6 +41,86 @@ pub fn lemire<F: Float>(num: &Number) -> ExtendedFloat {
     fp
 }
 
+/// Check if the float from `mantissa + 1` is the same, so truncated digits cannot affect it.
+#[inline]
+fn is_upper_rounded_same<F: Float>(num: &Number, fp: ExtendedFloat) -> bool {
+    // Truncated decimal digits are at most `10^19 - 1`, but any other
+    // mantissa must not overflow, so conservatively use the slow path.
+    // `ExtendedFloat` equality compares the values, so compare the
+    // mantissa and exponent, which are the bits of the rounded float.
+    match num.mantissa.checked_add(1) {
+        Some(mantissa) => {
+            let upper = compute_float::<F>(num.exponent, mantissa);
+            fp.mant == upper.mant && fp.exp == upper.exp
+        },
+        None => false,
+    }
+}
+
+/// Reason the Eisel-Lemire algorithm did or did not round the float.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LemireStatus {
+    /// The float was correctly rounded.
+    Accurate,
+    /// The float is a literal 0 or infinity, since it is out of range.
+    OutOfRange,
+    /// The 128-bit product of the power of 5 was ambiguous, and the
+    /// truncated bits of the power of 5 could round past halfway.
+    Halfway192,
+    /// The float is a denormal which could be exactly halfway.
+    HalfwayDenormal,
+    /// The significant digits were truncated, and `mantissa + 1` rounds
+    /// to a different float.
+    TruncatedDigits,
+}
+
+/// Ensure truncation of digits doesn't affect our computation, and report why it failed.
+///
+/// This is identical to [`lemire`], but also reports whether the float
+/// was correctly rounded, and if not, why the slow path algorithms are
+/// required, to characterize inputs that fall back.
+///
+/// [`lemire`]: fn.lemire.html
+#[cfg(feature = "diagnostics")]
+pub fn lemire_diagnostic<F: Float>(num: &Number) -> (ExtendedFloat, LemireStatus) {
+    let (fp, status) = compute_float_diagnostic::<F>(num.exponent, num.mantissa);
+    if num.many_digits && fp.exp >= 0 && !is_upper_rounded_same::<F>(num, fp) {
+        let fp = compute_error::<F>(num.exponent, num.mantissa);
+        return (fp, LemireStatus::TruncatedDigits);
+    }
+    (fp, status)
+}
+
+/// Calculate the extended-precision float without rounding.
+///
+/// This returns a literal 0 or infinity if the float cannot be represented,
+/// otherwise, the normalized float with an invalid, biased exponent,
+/// so the slow path algorithms are always used.
+pub fn lemire_error<F: Float>(num: &Number) -> ExtendedFloat {
+    let fp_zero = ExtendedFloat {
+        mant: 0,
+        exp: 0,
+    };
+    let fp_inf = ExtendedFloat {
+        mant: 0,
+        exp: F::INFINITE_POWER,
+    };
+
+    // Short-circuit if the value can only be a literal 0 or infinity.
+    if num.mantissa == 0 || num.exponent < F::SMALLEST_POWER_OF_TEN {
+        return fp_zero;
+    } else if num.exponent > F::LARGEST_POWER_OF_TEN {
+        return fp_inf;
+    }
+
+    // Check for literal underflow, even with halfway cases.
+    let fp = compute_error::<F>(num.exponent, num.mantissa);
+    if -(fp.exp - F::INVALID_FP) + 1 > 65 {
+        return fp_zero;
+    }
+    fp
+}
+
 /// Compute a float using an extended-precision representation.
 ///
 /// Fast conversion of a the significant digits and decimal exponent
@@ -45,7 +139,29 @@ pub fn lemire<F: Float>(num: &Number) -> ExtendedFloat {
 /// at a Gigabyte per Second" in section 5, "Fast Algorithm", and
 /// section 6, "Exact Numbers And Ties", available online:
 /// <https://arxiv.org/abs/2101.11408.pdf>.
-pub fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
+#[inline]
+pub fn compute_float<F: Float>(q: i32, w: u64) -> ExtendedFloat {
+    compute_float_impl::<F>(q, w).0
+}
+
+/// Compute a float using an extended-precision representation, and report why it failed.
+///
+/// This is identical to [`compute_float`], but also reports whether the
+/// float was correctly rounded, or which check required the slow path
+/// algorithms.
+///
+/// [`compute_float`]: fn.compute_float.html
+#[cfg(feature = "diagnostics")]
+#[inline]
+pub fn compute_float_diagnostic<F: Float>(q: i32, w: u64) -> (ExtendedFloat, LemireStatus) {
+    compute_float_impl::<F>(q, w)
+}
+
+/// Implementation of [`compute_float`], which also reports the status.
+///
+/// [`compute_float`]: fn.compute_float.html
+#[inline(always)]
+fn compute_float_impl<F: Float>(q: i32, mut w: u64) -> (ExtendedFloat, LemireStatus) {
     let fp_zero = ExtendedFloat {
         mant: 0,
         exp: 0,
@@ -57,14 +173,14 @@ pub fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
 
     // Short-circuit if the value can only be a literal 0 or infinity.
     if w == 0 || q < F::SMALLEST_POWER_OF_TEN {
-        return fp_zero;
+        return (fp_zero, LemireStatus::OutOfRange);
     } else if q > F::LARGEST_POWER_OF_TEN {
-        return fp_inf;
+        return (fp_inf, LemireStatus::OutOfRange);
     }
     // Normalize our significant digits, so the most-significant bit is set.
     let lz = w.leading_zeros() as i32;
     w <<= lz;
-    let (lo, hi) = compute_product_approx(q, w, F::MANTISSA_SIZE as usize + 3);
+    let (lo, hi) = compute_product_approx::<F>(q, w, F::MANTISSA_SIZE as usize + 3);
     if lo == 0xFFFF_FFFF_FFFF_FFFF {
         // If we have failed to approximate w x 5^-q with our 128-bit value.
         // Since the addition of 1 could lead to an overflow which could then
@@ -81,28 +197,35 @@ pub fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
         // <https://arxiv.org/pdf/2101.11408.pdf#section.9.1>. For detailed
         // explanations of rounding for positive exponents, see
         // <https://arxiv.org/pdf/2101.11408.pdf#section.8>.
-        let inside_safe_exponent = (q >= -27) && (q <= 55);
+        let inside_safe_exponent = (-27..=55).contains(&q);
         if !inside_safe_exponent {
-            return compute_error_scaled::<F>(q, hi, lz);
+            return (compute_error_scaled::<F>(q, hi, lz), LemireStatus::Halfway192);
         }
     }
     let upperbit = (hi >> 63) as i32;
     let mut mantissa = hi >> (upperbit + 64 - F::MANTISSA_SIZE - 3);
-    let mut power2 = power(q) + upperbit - lz - F::MINIMUM_EXPONENT;
+    let mut power2 = power::<F>(q) + upperbit - lz - F::MINIMUM_EXPONENT;
     if power2 <= 0 {
         if -power2 + 1 >= 64 {
             // Have more than 64 bits below the minimum exponent, must be 0.
-            return fp_zero;
+            return (fp_zero, LemireStatus::OutOfRange);
+        }
+        // Have a subnormal value. Halfway denormals are only exact if
+        // `5^-q` divides the significant digits, so `q >= -27`, which only
+        // occurs for narrow floats. These may need to round down, so
+        // fall back to the slow path.
+        if q >= -27 {
+            return (compute_error_scaled::<F>(q, hi, lz), LemireStatus::HalfwayDenormal);
         }
-        // Have a subnormal value.
         mantissa >>= -power2 + 1;
         mantissa += mantissa & 1;
         mantissa >>= 1;
         power2 = (mantissa >= (1_u64 << F::MANTISSA_SIZE)) as i32;
-        return ExtendedFloat {
+        let fp = ExtendedFloat {
             mant: mantissa,
             exp: power2,
         };
+        return (fp, LemireStatus::Accurate);
     }
     // Need to handle rounding ties. Normally, we need to round up,
     // but if we fall right in between and and we have an even basis, we
@@ -138,12 +261,13 @@ pub fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
     mantissa &= !(1_u64 << F::MANTISSA_SIZE);
     if power2 >= F::INFINITE_POWER {
         // Exponent is above largest normal value, must be infinite.
-        return fp_inf;
+        return (fp_inf, LemireStatus::OutOfRange);
     }
-    ExtendedFloat {
+    let fp = ExtendedFloat {
         mant: mantissa,
         exp: power2,
-    }
+    };
+    (fp, LemireStatus::Accurate)
 }
 
 /// Fallback algorithm to calculate the non-rounded representation.
@@ -153,7 +277,7 @@ pub fn compute_float<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
 pub fn compute_error<F: Float>(q: i32, mut w: u64) -> ExtendedFloat {
     let lz = w.leading_zeros() as i32;
     w <<= lz;
-    let hi = compute_product_approx(q, w, F::MANTISSA_SIZE as usize + 3).1;
+    let hi = compute_product_approx::<F>(q, w, F::MANTISSA_SIZE as usize + 3).1;
     compute_error_scaled::<F>(q, hi, lz)
 }
 
@@ -163,7 +287,7 @@ pub fn compute_error_scaled<F: Float>(q: i32, mut w: u64, lz: i32) -> ExtendedFl
     // Want to normalize the float, but this is faster than ctlz on most architectures.
     let hilz = (w >> 63) as i32 ^ 1;
     w <<= hilz;
-    let power2 = power(q as i32) + F::EXPONENT_BIAS - hilz - lz - 62;
+    let power2 = power::<F>(q) + F::EXPONENT_BIAS - hilz - lz - 62;
 
     ExtendedFloat {
         mant: w,
@@ -173,25 +297,19 @@ pub fn compute_error_scaled<F: Float>(q: i32, mut w: u64, lz: i32) -> ExtendedFl
 
 /// Calculate a base 2 exponent from a decimal exponent.
 /// This uses a pre-computed integer approximation for
-/// log2(10), where 217706 / 2^16 is accurate for the
-/// entire range of non-finite decimal exponents.
-#[inline]
-fn power(q: i32) -> i32 {
-    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
-}
-
+/// log2(10), `F::LEMIRE_MAGIC / 2^16`, which is accurate
+/// for the entire range of non-finite decimal exponents.
 #[inline]
-fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
-    let r = (a as u128) * (b as u128);
-    (r as u64, (r >> 64) as u64)
+fn power<F: Float>(q: i32) -> i32 {
+    (q.wrapping_mul(F::LEMIRE_MAGIC) >> 16) + 63
 }
 
 // This will compute or rather approximate w * 5**q and return a pair of 64-bit words
 // approximating the result, with the "high" part corresponding to the most significant
 // bits and the low part corresponding to the least significant bits.
-fn compute_product_approx(q: i32, w: u64, precision: usize) -> (u64, u64) {
-    debug_assert!(q >= SMALLEST_POWER_OF_FIVE);
-    debug_assert!(q <= LARGEST_POWER_OF_FIVE);
+fn compute_product_approx<F: Float>(q: i32, w: u64, precision: usize) -> (u64, u64) {
+    debug_assert!(q >= F::SMALLEST_POWER_OF_FIVE);
+    debug_assert!(q <= F::LARGEST_POWER_OF_FIVE);
     debug_assert!(precision <= 64);
 
     let mask = if precision < 64 {
@@ -203,19 +321,19 @@ fn compute_product_approx(q: i32, w: u64, precision: usize) -> (u64, u64) {
     // 5^q < 2^64, then the multiplication always provides an exact value.
     // That means whenever we need to round ties to even, we always have
     // an exact value.
-    let index = (q - SMALLEST_POWER_OF_FIVE) as usize;
-    let (lo5, hi5) = POWER_OF_FIVE_128[index];
+    let index = (q - F::SMALLEST_POWER_OF_FIVE) as usize;
+    let (lo5, hi5) = F::powers()[index];
     // Only need one multiplication as long as there is 1 zero but
     // in the explicit mantissa bits, +1 for the hidden bit, +1 to
     // determine the rounding direction, +1 for if the computed
     // product has a leading zero.
-    let (mut first_lo, mut first_hi) = full_multiplication(w, lo5);
+    let (mut first_lo, mut first_hi) = mul_u64(w, lo5);
     if first_hi & mask == mask {
         // Need to do a second multiplication to get better precision
         // for the lower product. This will always be exact
         // where q is < 55, since 5^55 < 2^128. If this wraps,
         // then we need to need to round up the hi product.
-        let (_, second_hi) = full_multiplication(w, hi5);
+        let (_, second_hi) = mul_u64(w, hi5);
         first_lo = first_lo.wrapping_add(second_hi);
         if second_hi > first_lo {
             first_hi += 1;
@@ -223,3 +341,145 @@ fn compute_product_approx(q: i32, w: u64, precision: usize) -> (u64, u64) {
     }
     (first_lo, first_hi)
 }
+
+/// Parse up to 38 significant digits, for the wide mantissa algorithm.
+///
+/// Returns the significant digits and the exponent scaled to them, or
+/// `None` if the digits would be truncated, that is, if any non-zero digit
+/// follows the first 38 significant digits. The number must have been
+/// parsed from the same digits and have many digits, so the first 19
+/// significant digits are already scaled to `num.exponent`.
+#[cfg(feature = "wide_mantissa")]
+fn parse_wide_mantissa<'a, Iter1, Iter2>(
+    num: &Number,
+    integer: Iter1,
+    fraction: Iter2,
+) -> Option<(u128, i32)>
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    debug_assert!(num.many_digits);
+
+    // 10^38 - 1 < 2^128, so 38 digits can never overflow.
+    let mut digits = integer.chain(fraction).skip_while(|&&c| c == b'0');
+    let mut mantissa: u128 = 0;
+    let mut count = 0;
+    for &c in digits.by_ref().take(38) {
+        mantissa = mantissa * 10 + (c - b'0') as u128;
+        count += 1;
+    }
+    match digits.all(|&c| c == b'0') {
+        // Cannot overflow, since the exponent is in the range of the powers of 10.
+        true => Some((mantissa, num.exponent - (count - 19))),
+        false => None,
+    }
+}
+
+/// Use the Eisel-Lemire algorithm with a 128-bit mantissa.
+///
+/// This is only used if the number has more than 19 significant digits,
+/// and the 64-bit algorithm could not determine the rounding due to the
+/// truncated digits. If there are at most 38 significant digits, they
+/// can be represented exactly as a `u128`, which avoids the slow path
+/// algorithms for most inputs with 20 to 38 significant digits.
+///
+/// Returns `None` if the float cannot be correctly rounded.
+#[cfg(feature = "wide_mantissa")]
+#[inline]
+pub fn lemire_wide<'a, F, Iter1, Iter2>(
+    num: &Number,
+    integer: Iter1,
+    fraction: Iter2,
+) -> Option<ExtendedFloat>
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    let (mantissa, exponent) = parse_wide_mantissa(num, integer, fraction)?;
+    compute_float_wide::<F>(exponent, mantissa)
+}
+
+/// Compute a float from a 128-bit mantissa using an extended-precision representation.
+///
+/// This computes the full, 256-bit product of the normalized mantissa
+/// and the 128-bit approximation of the power of 5. The approximation
+/// is exact for `q ∈ [0, 55]`, since `5^55 < 2^128`, and otherwise, is
+/// within 1 ULP of the true value, so the product is within `w` of the
+/// exact product. Since rounding is monotonic, if the lower and upper
+/// bounds of the product round to the same float, the float is correctly
+/// rounded.
+///
+/// Returns `None` if the bounds round to different floats, or if the float
+/// is denormal, which is left to the slow path algorithms.
+#[cfg(feature = "wide_mantissa")]
+pub fn compute_float_wide<F: Float>(q: i32, mut w: u128) -> Option<ExtendedFloat> {
+    if w == 0 || !(F::SMALLEST_POWER_OF_FIVE..=F::LARGEST_POWER_OF_FIVE).contains(&q) {
+        return None;
+    }
+
+    // Normalize our significant digits, so the most-significant bit is set.
+    let lz = w.leading_zeros() as i32;
+    w <<= lz;
+    let index = (q - F::SMALLEST_POWER_OF_FIVE) as usize;
+    // The high 64 bits of the power of 5 are stored first.
+    let (hi5, lo5) = F::powers()[index];
+    let (lo, hi) = mul_wide(w, ((hi5 as u128) << 64) | lo5 as u128);
+    if (0..=55).contains(&q) {
+        return round_wide::<F>(q, lz, lo, hi);
+    }
+
+    // Cannot overflow or underflow, since `w < 2^128`, and the product
+    // is at least `2^254`, and at most `2^256 - 2^129 + 1`.
+    let (lower_lo, borrow) = lo.overflowing_sub(w);
+    let lower_hi = hi - borrow as u128;
+    let (upper_lo, carry) = lo.overflowing_add(w);
+    let upper_hi = hi + carry as u128;
+    let lower = round_wide::<F>(q, lz, lower_lo, lower_hi)?;
+    let upper = round_wide::<F>(q, lz, upper_lo, upper_hi)?;
+    // Compare the bits of the rounded floats, not the values.
+    match lower.mant == upper.mant && lower.exp == upper.exp {
+        true => Some(lower),
+        false => None,
+    }
+}
+
+/// Round the exact, 256-bit product to the nearest float, with ties to even.
+///
+/// Returns `None` if the float is denormal.
+#[cfg(feature = "wide_mantissa")]
+fn round_wide<F: Float>(q: i32, lz: i32, lo: u128, hi: u128) -> Option<ExtendedFloat> {
+    let upperbit = (hi >> 127) as i32;
+    let shift = upperbit + 128 - F::MANTISSA_SIZE - 3;
+    let mut mantissa = (hi >> shift) as u64;
+    // The high 64 bits are scaled like the 64-bit algorithm, with
+    // the mantissa having 64 fewer leading zeros.
+    let mut power2 = power::<F>(q) + upperbit - (lz - 64) - F::MINIMUM_EXPONENT;
+    if power2 <= 0 {
+        return None;
+    }
+
+    // If we're exactly halfway, round down if the mantissa is even.
+    let truncated = hi & ((1_u128 << shift) - 1);
+    if lo == 0 && truncated == 0 && mantissa & 3 == 1 {
+        mantissa &= !1_u64;
+    }
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= (2_u64 << F::MANTISSA_SIZE) {
+        mantissa = 1_u64 << F::MANTISSA_SIZE;
+        power2 += 1;
+    }
+    mantissa &= !(1_u64 << F::MANTISSA_SIZE);
+    if power2 >= F::INFINITE_POWER {
+        return Some(ExtendedFloat {
+            mant: 0,
+            exp: F::INFINITE_POWER,
+        });
+    }
+    Some(ExtendedFloat {
+        mant: mantissa,
+        exp: power2,
+    })
+}
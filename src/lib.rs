@@ -5,6 +5,9 @@
 //!
 //! [`parse_float`] expects a forward iterator for the integer
 //! and fraction digits, as well as a parsed exponent as an [`i32`].
+//! For convenience, [`parse_complete`] tokenizes and parses a
+//! complete float directly from bytes, and [`parse_float_parts`]
+//! parses a float that was already tokenized into its components.
 //!
 //! For more examples, please see [simple-example](https://github.com/Alexhuszagh/minimal-lexical/blob/master/examples/simple.rs).
 //!
@@ -20,16 +23,42 @@
 //! // integer (0).
 //! // Warning:
 //! // --------
-//! //  Please note that leading zeros must be trimmed from the integer,
-//! //  and trailing zeros must be trimmed from the fraction. This cannot
-//! //  be handled by minimal-lexical, since we accept iterators.
+//! //  Please note that trailing zeros must be trimmed from the fraction.
+//! //  This cannot be handled by minimal-lexical, since we accept forward iterators, which
+//! //  cannot be trimmed from the end.
+//! //  Leading zeros in the integer are skipped, but trimming them is faster.
 //! let integer = b"1";
 //! let fraction = b"2345";
 //! let float: f64 = minimal_lexical::parse_float(integer.iter(), fraction.iter(), 0);
 //! println!("float={:?}", float);    // 1.235
 //! ```
 //!
+//! PANICS
+//! ------
+//!
+//! [`parse_float`], and the other functions that accept the digits
+//! directly, require valid digits, and may panic otherwise. The
+//! following functions validate their input, and never panic for
+//! any bytes: [`parse_float_total`], [`parse_complete`],
+//! [`parse_float_str`], [`parse_float_str_partial`],
+//! [`parse_float_str_checked`], [`parse_float_streaming`],
+//! [`parse_float_rust_strict`], [`try_parse_float_parts`],
+//! [`try_parse_float_chars`], [`scan_float`], and [`FloatIter`],
+//! including the variants with custom options.
+//!
 //! [`parse_float`]: fn.parse_float.html
+//! [`parse_complete`]: fn.parse_complete.html
+//! [`parse_float_parts`]: fn.parse_float_parts.html
+//! [`parse_float_total`]: fn.parse_float_total.html
+//! [`parse_float_str`]: fn.parse_float_str.html
+//! [`parse_float_str_partial`]: fn.parse_float_str_partial.html
+//! [`parse_float_str_checked`]: fn.parse_float_str_checked.html
+//! [`parse_float_streaming`]: fn.parse_float_streaming.html
+//! [`parse_float_rust_strict`]: fn.parse_float_rust_strict.html
+//! [`try_parse_float_parts`]: fn.try_parse_float_parts.html
+//! [`try_parse_float_chars`]: fn.try_parse_float_chars.html
+//! [`scan_float`]: fn.scan_float.html
+//! [`FloatIter`]: struct.FloatIter.html
 //! [`i32`]: https://doc.rust-lang.org/stable/std/primitive.i32.html
 
 // FEATURES
@@ -45,6 +74,11 @@ extern crate alloc;
 
 pub mod bellerophon;
 pub mod bigint;
+pub mod decimal;
+pub mod digits;
+// Requires Rust 1.83 for const-evaluable integer operations.
+#[clippy::msrv = "1.83"]
+pub mod exact;
 pub mod extended_float;
 pub mod fpu;
 pub mod heapvec;
@@ -54,15 +88,68 @@ pub mod mask;
 pub mod num;
 pub mod number;
 pub mod parse;
+pub mod radix;
 pub mod rounding;
+pub mod scan;
 pub mod slow;
+pub mod special;
 pub mod stackvec;
 pub mod table;
+pub mod wide;
 
 mod table_bellerophon;
 mod table_lemire;
 mod table_small;
 
 // API
-pub use self::num::Float;
-pub use self::parse::parse_float;
+pub use self::bigint::Bigint;
+#[cfg(all(feature = "decimal", any(feature = "std", feature = "alloc")))]
+pub use self::decimal::Decimal;
+#[cfg(feature = "const_fn")]
+pub use self::exact::{parse_float_exact, parse_float_exact_f32};
+pub use self::extended_float::ExtendedFloat;
+pub use self::num::{is_exactly_representable, Float};
+pub use self::parse::{
+    parse_fixed_point,
+    parse_float,
+    parse_float_chars,
+    parse_float_exp64,
+    parse_float_parts,
+    parse_float_slice,
+    parse_float_total,
+    parse_float_truncated,
+    parse_float_with_confidence,
+    parse_float_with_digits,
+    parse_float_with_exponent,
+    parse_floats_into,
+    parse_integer_float,
+    parse_integer_mantissa,
+    try_parse_exact,
+    NumberParts,
+};
+#[cfg(feature = "diagnostics")]
+pub use self::parse::{parse_float_diagnostic, ParsePath};
+pub use self::parse::{parse_float_with_scaler, parse_to_parts, FloatParts};
+#[cfg(feature = "radix")]
+pub use self::radix::parse_float_radix;
+pub use self::scan::{
+    parse_complete,
+    parse_complete_with_options,
+    parse_float_rust_strict,
+    parse_float_str,
+    parse_float_str_checked,
+    parse_float_str_partial,
+    parse_float_streaming,
+    parse_float_streaming_with_options,
+    scan_float,
+    scan_float_with_options,
+    try_parse_float_chars,
+    try_parse_float_parts,
+    FloatIter,
+    FloatSpans,
+    ParseFloatError,
+    ParseFloatErrorKind,
+    ParseOptions,
+    ParseProgress,
+};
+pub use self::slow::DecimalScaler;
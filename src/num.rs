@@ -2,36 +2,41 @@
 
 #![doc(hidden)]
 
+use crate::extended_float::ExtendedFloat;
 #[cfg(all(not(feature = "std"), feature = "compact"))]
 use crate::libm::{powd, powf};
+#[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+use crate::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
 #[cfg(not(feature = "compact"))]
 use crate::table::{SMALL_F32_POW10, SMALL_F64_POW10, SMALL_INT_POW10, SMALL_INT_POW5};
-use core::ops;
+use core::{cmp, ops};
 
 /// Generic floating-point type, to be used in generic code for parsing.
 ///
 /// Although the trait is part of the public API, the trait provides methods
 /// and constants that are effectively non-public: they may be removed
 /// at any time without any breaking changes.
-pub trait Float:
-    Sized
-    + Copy
-    + PartialEq
-    + PartialOrd
-    + Send
-    + Sync
-    + ops::Add<Output = Self>
-    + ops::AddAssign
-    + ops::Div<Output = Self>
-    + ops::DivAssign
-    + ops::Mul<Output = Self>
-    + ops::MulAssign
-    + ops::Rem<Output = Self>
-    + ops::RemAssign
-    + ops::Sub<Output = Self>
-    + ops::SubAssign
-    + ops::Neg<Output = Self>
-{
+///
+/// The algorithms only use integer arithmetic on the bits of the float,
+/// so the trait may be implemented for a software float, such as for
+/// a target without a floating-point unit. An implementation only needs:
+///
+/// 1. The constants describing the IEEE-754 binary layout.
+/// 2. [`from_bits`] and [`to_bits`], to convert to and from the bits.
+/// 3. [`Neg`], which must only flip the sign bit.
+///
+/// Every other method has a default implementation in terms of these,
+/// and [`from_u64`], [`pow_fast_path`], [`mul_round`] and [`div_round`]
+/// may be overridden with native arithmetic, for performance.
+///
+/// [`from_bits`]: #tymethod.from_bits
+/// [`to_bits`]: #tymethod.to_bits
+/// [`Neg`]: https://doc.rust-lang.org/core/ops/trait.Neg.html
+/// [`from_u64`]: #method.from_u64
+/// [`pow_fast_path`]: #method.pow_fast_path
+/// [`mul_round`]: #method.mul_round
+/// [`div_round`]: #method.div_round
+pub trait Float: Sized + Copy + PartialEq + Send + Sync + ops::Neg<Output = Self> {
     /// Maximum number of digits that can contribute in the mantissa.
     ///
     /// We can exactly represent a float in radix `b` from radix 2 if
@@ -56,8 +61,22 @@ pub trait Float:
     ///     `-emin + p2 + math.floor((emin+1)*math.log(2, b) - math.log(1-2**(-p2), b))`
     ///
     /// This was used to calculate the maximum number of digits for [2, 36].
+    ///
+    /// The slow path algorithms never use more than `MAX_DIGITS` digits:
+    /// any truncated digits are only checked for a non-zero value, which
+    /// is used as a sticky bit when rounding. This bounds the work in
+    /// the slow path algorithms, regardless of the number of input digits.
     const MAX_DIGITS: usize;
 
+    /// Maximum number of bits in the big integers for the slow path algorithms.
+    ///
+    /// This is the number of bits required to store the significant digits
+    /// scaled to the smallest power of 10, that is, approximately
+    /// `log2(10^(MAX_DIGITS - SMALLEST_POWER_OF_TEN))`, rounded-up. The
+    /// big integers are pre-allocated to this size with the `alloc` feature,
+    /// and are always bounded by the crate-wide maximum.
+    const BIGINT_BITS: usize;
+
     // MASKS
 
     /// Bitmask for the sign bit.
@@ -69,6 +88,20 @@ pub trait Float:
     /// Bitmask for the mantissa (fraction), excluding the hidden bit.
     const MANTISSA_MASK: u64;
 
+    // SPECIAL VALUES
+
+    /// Bits of positive infinity.
+    const INFINITY_BITS: u64 = Self::EXPONENT_MASK;
+    /// Bits of negative infinity.
+    const NEG_INFINITY_BITS: u64 = Self::SIGN_MASK | Self::EXPONENT_MASK;
+    /// Bits of the canonical NaN, created when parsing `NaN`.
+    ///
+    /// This is a positive, quiet NaN with an empty payload, which is
+    /// the same as Rust's `NAN` constants. It is not the NaN from invalid
+    /// operations on x86, such as `0.0 / 0.0`, which has the sign bit set,
+    /// and which some C libraries use for `NAN`.
+    const NAN_BITS: u64 = Self::EXPONENT_MASK | (Self::HIDDEN_BIT_MASK >> 1);
+
     // PROPERTIES
 
     /// Size of the significand (mantissa) without hidden bit.
@@ -135,19 +168,144 @@ pub trait Float:
     /// This is `MAX_EXPONENT_FAST_PATH + ⌊(MANTISSA_SIZE+1)/log2(10)⌋`
     const MAX_EXPONENT_DISGUISED_FAST_PATH: i32;
 
-    /// Convert 64-bit integer to float.
-    fn from_u64(u: u64) -> Self;
+    /// Integer approximation of `log2(10)`, scaled by `2^16`, for the Eisel-Lemire algorithm.
+    ///
+    /// `⌊q * LEMIRE_MAGIC / 2^16⌋` must be `⌊q * log2(10)⌋` for every
+    /// decimal exponent `q` in the range of [`powers`](#method.powers).
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    const LEMIRE_MAGIC: i32 = 152_170 + 65536;
+
+    /// Smallest decimal exponent in [`powers`](#method.powers).
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    const SMALLEST_POWER_OF_FIVE: i32 = SMALLEST_POWER_OF_FIVE;
+
+    /// Largest decimal exponent in [`powers`](#method.powers).
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    const LARGEST_POWER_OF_FIVE: i32 = LARGEST_POWER_OF_FIVE;
+
+    /// Get the normalized, 128-bit powers of 5 for the Eisel-Lemire algorithm.
+    ///
+    /// Each power is stored as the high and then low 64 bits, starting
+    /// from `5^SMALLEST_POWER_OF_FIVE`. The table sized for `f64` covers
+    /// the exponents of any narrower float.
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    #[inline]
+    fn powers() -> &'static [(u64, u64)] {
+        &POWER_OF_FIVE_128
+    }
+
+    /// Convert 64-bit integer to float, rounded to the nearest float.
+    #[inline]
+    fn from_u64(u: u64) -> Self {
+        Self::from_mantissa_exp2(u, 0)
+    }
 
     // Re-exported methods from std.
+
+    /// Create a float from its IEEE-754 bits, which must fit in the float.
+    ///
+    /// Every bit is preserved, including the sign and payload of a NaN,
+    /// so `F::from_bits(x.to_bits())` has the same bits as `x`, and the
+    /// distinction between signaling and quiet NaNs is kept. However,
+    /// like the standard library, moving a signaling NaN through x87 FPU
+    /// registers on 32-bit x86 without SSE2 may quiet it.
     fn from_bits(u: u64) -> Self;
+
+    /// Get the IEEE-754 bits of the float.
+    ///
+    /// This is the inverse of [`from_bits`](#tymethod.from_bits), and
+    /// preserves every bit of the float.
     fn to_bits(self) -> u64;
 
     /// Get a small power-of-radix for fast-path multiplication.
     ///
+    /// The default calculates `10^exponent` as `5^exponent * 2^exponent`,
+    /// which is exact for any exponent in the fast path.
+    ///
     /// # Safety
     ///
-    /// Safe as long as the exponent is smaller than the table size.
-    unsafe fn pow_fast_path(exponent: usize) -> Self;
+    /// Safe as long as the exponent is smaller than the table size. The
+    /// default requires the exponent to be at most 27, so `5^exponent`
+    /// fits in 64 bits.
+    #[inline]
+    unsafe fn pow_fast_path(exponent: usize) -> Self {
+        // SAFETY: safe as long as `exponent <= 27`.
+        let power = unsafe { int_pow_fast_path(exponent, FastPathRadix::Five) };
+        Self::from_mantissa_exp2(power, exponent as i32)
+    }
+
+    /// Multiply two finite floats, rounded to the nearest float.
+    ///
+    /// Ties are rounded to even. The default multiplies the mantissas
+    /// as integers, so it is correctly rounded without native floats.
+    #[inline]
+    fn mul_round(self, other: Self) -> Self {
+        let sign = (self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK;
+        let product = self.mantissa() as u128 * other.mantissa() as u128;
+        let exp2 = self.exponent() + other.exponent();
+        Self::from_bits(from_u128_exp2::<Self>(product, exp2).to_bits() | sign)
+    }
+
+    /// Divide two finite floats, rounded to the nearest float.
+    ///
+    /// Ties are rounded to even. The divisor must not be zero. The
+    /// default divides the mantissas as integers, with any remainder
+    /// as a sticky bit, so it is correctly rounded without native floats.
+    #[inline]
+    fn div_round(self, other: Self) -> Self {
+        debug_assert!(other.mantissa() != 0, "div_round() divide by zero.");
+        let sign = (self.to_bits() ^ other.to_bits()) & Self::SIGN_MASK;
+        let mantissa = self.mantissa();
+        if mantissa == 0 {
+            return Self::from_bits(sign);
+        }
+        // Normalize the dividend, so the quotient has at least 74 bits.
+        let shift = mantissa.leading_zeros();
+        let dividend = ((mantissa << shift) as u128) << 64;
+        let divisor = other.mantissa() as u128;
+        let quotient = (dividend / divisor) | (dividend % divisor != 0) as u128;
+        let exp2 = self.exponent() - other.exponent() - shift as i32 - 64;
+        Self::from_bits(from_u128_exp2::<Self>(quotient, exp2).to_bits() | sign)
+    }
+
+    /// Get the minimum and maximum exponents where the power of the radix is exact.
+    ///
+    /// Within these limits, `radix^|exponent|` is exactly representable
+    /// as a finite float, so it can be used for fast-path multiplication
+    /// or division. The power of 2 in the radix only shifts the binary
+    /// exponent, so only the odd factor must fit in the mantissa.
+    #[cfg(feature = "radix")]
+    fn exponent_limit(radix: u32) -> (i32, i32) {
+        debug_assert!((2..=36).contains(&radix), "exponent_limit() invalid radix.");
+        let shift = radix.trailing_zeros() as i32;
+        let odd = (radix >> shift) as u64;
+        let max = if odd == 1 {
+            (Self::MAX_EXPONENT + Self::MANTISSA_SIZE - 1) / shift
+        } else {
+            let mut max = 0;
+            let mut power = odd;
+            while power <= Self::MAX_MANTISSA_FAST_PATH {
+                power *= odd;
+                max += 1;
+            }
+            max
+        };
+        (-max, max)
+    }
+
+    /// Get the maximum number of digits in the radix that are always exactly representable.
+    #[cfg(feature = "radix")]
+    fn mantissa_limit(radix: u32) -> i32 {
+        debug_assert!((2..=36).contains(&radix), "mantissa_limit() invalid radix.");
+        let radix = radix as u64;
+        let mut limit = 0;
+        let mut power = radix;
+        while power <= Self::MAX_MANTISSA_FAST_PATH {
+            power *= radix;
+            limit += 1;
+        }
+        limit
+    }
 
     /// Returns true if the float is a denormal.
     #[inline]
@@ -155,6 +313,30 @@ pub trait Float:
         self.to_bits() & Self::EXPONENT_MASK == 0
     }
 
+    /// Returns true if the float is NaN or infinite.
+    #[inline]
+    fn is_special(self) -> bool {
+        self.to_bits() & Self::EXPONENT_MASK == Self::EXPONENT_MASK
+    }
+
+    /// Returns true if the float is positive or negative infinity.
+    #[inline]
+    fn is_inf(self) -> bool {
+        self.is_special() && self.to_bits() & Self::MANTISSA_MASK == 0
+    }
+
+    /// Returns true if the float is NaN.
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_special() && self.to_bits() & Self::MANTISSA_MASK != 0
+    }
+
+    /// Returns true if the float is neither infinite nor NaN.
+    #[inline]
+    fn is_finite(self) -> bool {
+        !self.is_special()
+    }
+
     /// Get exponent component from the float.
     #[inline]
     fn exponent(self) -> i32 {
@@ -178,10 +360,155 @@ pub trait Float:
             s
         }
     }
+
+    /// Get the next float towards positive infinity.
+    ///
+    /// Both zeros step to the smallest positive denormal, positive
+    /// infinity and NaN are returned unchanged, and negative infinity
+    /// steps to the most negative finite float.
+    #[inline]
+    fn next_up(self) -> Self {
+        let bits = self.to_bits();
+        let abs = bits & !Self::SIGN_MASK;
+        if abs > Self::EXPONENT_MASK || bits == Self::EXPONENT_MASK {
+            self
+        } else if abs == 0 {
+            Self::from_bits(1)
+        } else if bits == abs {
+            Self::from_bits(bits + 1)
+        } else {
+            Self::from_bits(bits - 1)
+        }
+    }
+
+    /// Get the next float towards negative infinity.
+    ///
+    /// Both zeros step to the smallest negative denormal, negative
+    /// infinity and NaN are returned unchanged, and positive infinity
+    /// steps to the largest finite float.
+    #[inline]
+    fn next_down(self) -> Self {
+        -(-self).next_up()
+    }
+
+    /// Get the unit in the last place, the positive gap from the float's
+    /// magnitude to the next float with a larger magnitude.
+    ///
+    /// The largest finite float has the same gap as the floats just
+    /// below it, both zeros have the smallest denormal as their gap,
+    /// infinity has an infinite gap, and NaN is returned unchanged.
+    #[inline]
+    fn ulp(self) -> Self {
+        let bits = self.to_bits() & !Self::SIGN_MASK;
+        let biased_e = bits >> Self::MANTISSA_SIZE;
+        if bits > Self::EXPONENT_MASK {
+            self
+        } else if bits == Self::EXPONENT_MASK {
+            Self::from_bits(bits)
+        } else if biased_e > Self::MANTISSA_SIZE as u64 {
+            // The gap is normal: `2^exponent` with a zero mantissa.
+            Self::from_bits((biased_e - Self::MANTISSA_SIZE as u64) << Self::MANTISSA_SIZE)
+        } else if biased_e != 0 {
+            Self::from_bits(1 << (biased_e - 1))
+        } else {
+            Self::from_bits(1)
+        }
+    }
+
+    /// Create a float from `mantissa * 2^exp2`, rounded to the nearest float.
+    ///
+    /// Ties are rounded to even. Values too large to be represented
+    /// round to infinity, and values too small round to 0.
+    #[inline]
+    fn from_mantissa_exp2(mantissa: u64, exp2: i32) -> Self {
+        // Any exponent outside this range is guaranteed to be zero or
+        // infinite, and clamping it avoids overflow when normalizing.
+        let mut fp = ExtendedFloat {
+            mant: mantissa,
+            exp: cmp::max(cmp::min(exp2, 0x4000), -0x4000),
+        };
+        fp.normalize();
+        fp.into_float::<Self>()
+    }
+
+    /// Multiply the float by `2^exp2`, rounded to the nearest float.
+    ///
+    /// This only adjusts the exponent of normal results, so it is exact
+    /// unless the result is denormal. Ties are rounded to even, values
+    /// too large to be represented round to infinity, and values too
+    /// small round to 0. The sign is preserved, and infinity and NaN
+    /// are returned unchanged.
+    #[inline]
+    fn ldexp(self, exp2: i32) -> Self {
+        if !self.is_finite() {
+            return self;
+        }
+        let sign = self.to_bits() & Self::SIGN_MASK;
+        let float = Self::from_mantissa_exp2(self.mantissa(), self.exponent().saturating_add(exp2));
+        Self::from_bits(float.to_bits() | sign)
+    }
+
+    /// Convert the float to a narrower float type, rounded to the nearest float.
+    ///
+    /// Ties are rounded to even, directly from the exact value of the
+    /// wider float, so there is only a single rounding. Values too large
+    /// to be represented round to infinity, and values too small round to
+    /// 0. The sign is preserved, and NaN is converted to the canonical,
+    /// quiet NaN.
+    ///
+    /// This cannot recover the float the original decimal would have
+    /// rounded to, since the wider float was already rounded from the
+    /// decimal: parse directly to the narrower type instead.
+    #[inline]
+    fn narrow<G: Float>(self) -> G {
+        debug_assert!(G::MANTISSA_SIZE <= Self::MANTISSA_SIZE, "narrow() into wider float.");
+        convert(self)
+    }
+
+    /// Convert the float to a wider float type, which is always exact.
+    ///
+    /// The sign is preserved, and NaN is converted to the canonical,
+    /// quiet NaN.
+    #[inline]
+    fn widen<G: Float>(self) -> G {
+        debug_assert!(G::MANTISSA_SIZE >= Self::MANTISSA_SIZE, "widen() into narrower float.");
+        convert(self)
+    }
+}
+
+/// Create a float from `mantissa * 2^exp2`, rounded to the nearest float.
+///
+/// Any bits truncated to fit the mantissa in 64 bits are collapsed
+/// into the least significant bit, which is always below the rounding bit.
+#[inline]
+fn from_u128_exp2<F: Float>(mantissa: u128, exp2: i32) -> F {
+    let shift = 64u32.saturating_sub(mantissa.leading_zeros());
+    let is_truncated = mantissa & ((1 << shift) - 1) != 0;
+    F::from_mantissa_exp2((mantissa >> shift) as u64 | is_truncated as u64, exp2 + shift as i32)
+}
+
+/// Convert between float types, rounded to the nearest float, with ties to even.
+#[inline]
+fn convert<F: Float, G: Float>(float: F) -> G {
+    let bits = float.to_bits();
+    let sign = match bits & F::SIGN_MASK {
+        0 => 0,
+        _ => G::SIGN_MASK,
+    };
+    let bits = if float.is_finite() {
+        G::from_mantissa_exp2(float.mantissa(), float.exponent()).to_bits()
+    } else if float.is_inf() {
+        G::INFINITY_BITS
+    } else {
+        G::NAN_BITS
+    };
+    G::from_bits(bits | sign)
 }
 
 impl Float for f32 {
     const MAX_DIGITS: usize = 114;
+    // ≅ 595 bits for 179 decimal digits.
+    const BIGINT_BITS: usize = 640;
     const SIGN_MASK: u64 = 0x80000000;
     const EXPONENT_MASK: u64 = 0x7F800000;
     const HIDDEN_BIT_MASK: u64 = 0x00800000;
@@ -215,6 +542,16 @@ impl Float for f32 {
         u as _
     }
 
+    #[inline]
+    fn mul_round(self, other: f32) -> f32 {
+        self * other
+    }
+
+    #[inline]
+    fn div_round(self, other: f32) -> f32 {
+        self / other
+    }
+
     #[inline]
     fn from_bits(u: u64) -> f32 {
         // Constant is `u32::MAX` for older Rustc versions.
@@ -230,6 +567,8 @@ impl Float for f32 {
 
 impl Float for f64 {
     const MAX_DIGITS: usize = 769;
+    // ≅ 3691 bits for 1111 decimal digits.
+    const BIGINT_BITS: usize = 4000;
     const SIGN_MASK: u64 = 0x8000000000000000;
     const EXPONENT_MASK: u64 = 0x7FF0000000000000;
     const HIDDEN_BIT_MASK: u64 = 0x0010000000000000;
@@ -263,6 +602,16 @@ impl Float for f64 {
         u as _
     }
 
+    #[inline]
+    fn mul_round(self, other: f64) -> f64 {
+        self * other
+    }
+
+    #[inline]
+    fn div_round(self, other: f64) -> f64 {
+        self / other
+    }
+
     #[inline]
     fn from_bits(u: u64) -> f64 {
         f64::from_bits(u)
@@ -286,11 +635,82 @@ pub fn powd(x: f64, y: f64) -> f64 {
     x.powf(y)
 }
 
+/// Determine if `mantissa * 10^exponent` is exactly representable as `F`.
+///
+/// Unlike the fast path, which only requires the native arithmetic to
+/// be correctly rounded, this requires no rounding at all: the value
+/// must be a multiple of the smallest denormal, and the significant
+/// bits must fit in the mantissa of a finite float.
+///
+/// * `mantissa`    - Significant digits of the value.
+/// * `exponent`    - Decimal exponent of the value.
+pub fn is_exactly_representable<F: Float>(mut mantissa: u64, mut exponent: i32) -> bool {
+    if mantissa == 0 {
+        return true;
+    }
+
+    // Move trailing decimal zeros into the exponent.
+    while mantissa % 10 == 0 {
+        mantissa /= 10;
+        exponent = exponent.saturating_add(1);
+    }
+
+    // Move the power-of-2 into the binary exponent first, so the odd
+    // mantissa only overflows when scaled if it has too many bits.
+    let trailing_zeros = mantissa.trailing_zeros();
+    let mantissa = mantissa >> trailing_zeros;
+
+    // Scale by the power-of-5, which must be exact, since 5^28 > 2^64.
+    let (mantissa, shift) = if exponent >= 0 {
+        if exponent >= 28 {
+            return false;
+        }
+        // SAFETY: safe, since `exponent < 28`.
+        let power = unsafe { int_pow_fast_path(exponent as usize, FastPathRadix::Five) };
+        match mantissa.checked_mul(power) {
+            Some(mantissa) => (mantissa, exponent + trailing_zeros as i32),
+            None => return false,
+        }
+    } else {
+        if exponent <= -28 {
+            return false;
+        }
+        // SAFETY: safe, since `-exponent < 28`.
+        let power = unsafe { int_pow_fast_path((-exponent) as usize, FastPathRadix::Five) };
+        match mantissa % power {
+            0 => (mantissa / power, exponent + trailing_zeros as i32),
+            _ => return false,
+        }
+    };
+
+    // The value is now `mantissa * 2^shift`, with an odd mantissa.
+    let bits = 64 - mantissa.leading_zeros() as i32;
+    bits <= F::MANTISSA_SIZE + 1
+        && shift >= F::DENORMAL_EXPONENT
+        && shift + bits <= F::MAX_EXPONENT + F::MANTISSA_SIZE
+}
+
+/// Radix of the small, integral powers used for fast-path multiplication.
+///
+/// Only the radixes with pre-computed tables are representable, so an
+/// invalid radix cannot index out-of-bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum FastPathRadix {
     Five,
     Ten,
 }
 
+impl FastPathRadix {
+    /// Get the largest exponent where the power of the radix fits in a `u64`.
+    #[inline(always)]
+    pub(crate) fn max_exponent(self) -> usize {
+        match self {
+            FastPathRadix::Five => 27,
+            FastPathRadix::Ten => 19,
+        }
+    }
+}
+
 impl From<FastPathRadix> for u64 {
     fn from(radix: FastPathRadix) -> u64 {
         match radix {
@@ -304,9 +724,10 @@ impl From<FastPathRadix> for u64 {
 ///
 /// # Safety
 ///
-/// Safe as long as the exponent is smaller than the table size.
+/// Safe as long as the exponent is not larger than `radix.max_exponent()`.
 #[inline(always)]
 pub(crate) unsafe fn int_pow_fast_path(exponent: usize, radix: FastPathRadix) -> u64 {
+    debug_assert!(exponent <= radix.max_exponent(), "int_pow_fast_path() exponent overflows.");
     // SAFETY: safe as long as the exponent is smaller than the radix table.
     #[cfg(not(feature = "compact"))]
     return match radix {
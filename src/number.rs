@@ -20,7 +20,16 @@ pub struct Number {
     pub exponent: i32,
     /// The significant digits of the float.
     pub mantissa: u64,
-    /// If the significant digits were truncated.
+    /// If nonzero significant digits were truncated.
+    ///
+    /// The mantissa is then only the leading significant digits, and the
+    /// exponent is scaled to them, so the value is greater than `mantissa *
+    /// 10^exponent`, and less than `(mantissa + 1) * 10^exponent`. If only
+    /// zeros were truncated, the value is exact and this is not set. The
+    /// fast path is never used for truncated digits, and the moderate
+    /// path only rounds the float if both bounds round to the same float,
+    /// otherwise, the slow path compares all the digits. Clearing this
+    /// when digits were truncated may round the float incorrectly.
     pub many_digits: bool,
 }
 
@@ -34,6 +43,23 @@ impl Number {
             && !self.many_digits
     }
 
+    /// Short-circuit if the float can only be a literal 0 or infinity.
+    ///
+    /// Since the mantissa has at most 19 significant digits, any exponent
+    /// outside of `[F::SMALLEST_POWER_OF_TEN, F::LARGEST_POWER_OF_TEN]`
+    /// guarantees underflow or overflow, regardless of the digits,
+    /// including any truncated digits.
+    #[inline]
+    pub fn try_zero_or_infinity<F: Float>(&self) -> Option<F> {
+        if self.mantissa == 0 || self.exponent < F::SMALLEST_POWER_OF_TEN {
+            Some(F::from_u64(0))
+        } else if self.exponent > F::LARGEST_POWER_OF_TEN {
+            Some(F::from_bits(F::INFINITY_BITS))
+        } else {
+            None
+        }
+    }
+
     /// The fast path algorithmn using machine-sized integers and floats.
     ///
     /// This is extracted into a separate function so that it can be attempted before constructing
@@ -42,7 +68,8 @@ impl Number {
     /// no rounding will occur.
     ///
     /// There is an exception: disguised fast-path cases, where we can shift
-    /// powers-of-10 from the exponent to the significant digits.
+    /// powers-of-10 from the exponent to the significant digits, or
+    /// trailing zeros from the significant digits to the exponent.
     pub fn try_fast_path<F: Float>(&self) -> Option<F> {
         // The fast path crucially depends on arithmetic being rounded to the correct number of bits
         // without any intermediate rounding. On x86 (without SSE or SSE2) this requires the precision
@@ -53,31 +80,147 @@ impl Number {
         let _cw = set_precision::<F>();
 
         if self.is_fast_path::<F>() {
-            let max_exponent = F::MAX_EXPONENT_FAST_PATH;
-            Some(if self.exponent <= max_exponent {
-                // normal fast path
-                let value = F::from_u64(self.mantissa);
-                if self.exponent < 0 {
-                    // SAFETY: safe, since the `exponent <= max_exponent`.
-                    value / unsafe { F::pow_fast_path((-self.exponent) as _) }
-                } else {
-                    // SAFETY: safe, since the `exponent <= max_exponent`.
-                    value * unsafe { F::pow_fast_path(self.exponent as _) }
-                }
+            self.fast_path::<F>()
+        } else if !self.many_digits && self.mantissa != 0 && self.mantissa % 10 == 0 {
+            // Disguised fast path: the exponent is too small, or the mantissa
+            // too large, but dividing out trailing zeros is exact.
+            let mut num = *self;
+            while num.mantissa % 10 == 0 && num.exponent < i32::max_value() {
+                num.mantissa /= 10;
+                num.exponent += 1;
+            }
+            if num.is_fast_path::<F>() {
+                num.fast_path::<F>()
             } else {
-                // disguised fast path
-                let shift = self.exponent - max_exponent;
-                // SAFETY: safe, since `shift <= (max_disguised - max_exponent)`.
-                let int_power = unsafe { int_pow_fast_path(shift as usize, FastPathRadix::Ten) };
-                let mantissa = self.mantissa.checked_mul(int_power)?;
-                if mantissa > F::MAX_MANTISSA_FAST_PATH {
-                    return None;
-                }
-                // SAFETY: safe, since the `table.len() - 1 == max_exponent`.
-                F::from_u64(mantissa) * unsafe { F::pow_fast_path(max_exponent as _) }
-            })
+                None
+            }
         } else {
             None
         }
     }
+
+    /// The fast path algorithm, for a number that is a fast-path case.
+    #[inline]
+    fn fast_path<F: Float>(&self) -> Option<F> {
+        debug_assert!(self.is_fast_path::<F>());
+        let max_exponent = F::MAX_EXPONENT_FAST_PATH;
+        Some(if self.exponent <= max_exponent {
+            // normal fast path
+            let value = F::from_u64(self.mantissa);
+            if self.exponent < 0 {
+                // SAFETY: safe, since the `exponent <= max_exponent`.
+                value.div_round(unsafe { F::pow_fast_path((-self.exponent) as _) })
+            } else {
+                // SAFETY: safe, since the `exponent <= max_exponent`.
+                value.mul_round(unsafe { F::pow_fast_path(self.exponent as _) })
+            }
+        } else {
+            // disguised fast path
+            let shift = self.exponent - max_exponent;
+            // SAFETY: safe, since `shift <= (max_disguised - max_exponent)`.
+            let int_power = unsafe { int_pow_fast_path(shift as usize, FastPathRadix::Ten) };
+            let mantissa = self.mantissa.checked_mul(int_power)?;
+            if mantissa > F::MAX_MANTISSA_FAST_PATH {
+                return None;
+            }
+            // SAFETY: safe, since the `table.len() - 1 == max_exponent`.
+            F::from_u64(mantissa).mul_round(unsafe { F::pow_fast_path(max_exponent as _) })
+        })
+    }
+
+    /// Short-circuit if the float in the radix can only be a literal 0 or infinity.
+    ///
+    /// The value is in `[radix^(sci_exp - 1), radix^sci_exp)`, where
+    /// `sci_exp` is the exponent of the leading digit plus one, including
+    /// any truncated digits, so it is bounded by the powers of 2 using
+    /// `⌊log2(radix)⌋`.
+    #[cfg(feature = "radix")]
+    pub fn try_zero_or_infinity_radix<F: Float>(&self, radix: u32) -> Option<F> {
+        if self.mantissa == 0 {
+            return Some(F::from_u64(0));
+        }
+
+        let mut digits: i64 = 0;
+        let mut mantissa = self.mantissa;
+        while mantissa != 0 {
+            mantissa /= radix as u64;
+            digits += 1;
+        }
+        let sci_exp = self.exponent as i64 + digits;
+        let log2 = (31 - radix.leading_zeros()) as i64;
+        if sci_exp <= 0 && sci_exp * log2 < F::DENORMAL_EXPONENT as i64 {
+            // Below half the smallest denormal float.
+            Some(F::from_u64(0))
+        } else if sci_exp > 0 && (sci_exp - 1) * log2 >= (F::MAX_EXPONENT + F::MANTISSA_SIZE) as i64
+        {
+            // Above the largest finite float, and its halfway point.
+            Some(F::from_bits(F::INFINITY_BITS))
+        } else {
+            None
+        }
+    }
+
+    /// The fast path algorithm in the radix, using machine-sized integers and floats.
+    ///
+    /// This is identical to [`try_fast_path`], except the exact powers
+    /// of the radix are limited by [`Float::exponent_limit`], and are
+    /// calculated rather than pre-computed.
+    ///
+    /// [`try_fast_path`]: #method.try_fast_path
+    /// [`Float::exponent_limit`]: ../num/trait.Float.html#method.exponent_limit
+    #[cfg(feature = "radix")]
+    pub fn try_fast_path_radix<F: Float>(&self, radix: u32) -> Option<F> {
+        #[cfg(feature = "nightly")]
+        let _cw = set_precision::<F>();
+
+        let (min_exponent, max_exponent) = F::exponent_limit(radix);
+        if self.many_digits
+            || self.mantissa > F::MAX_MANTISSA_FAST_PATH
+            || self.exponent < min_exponent
+        {
+            return None;
+        }
+
+        let mut mantissa = self.mantissa;
+        let mut exponent = self.exponent;
+        if exponent > max_exponent {
+            // disguised fast path
+            let shift = (exponent - max_exponent) as u32;
+            mantissa = mantissa.checked_mul((radix as u64).checked_pow(shift)?)?;
+            if mantissa > F::MAX_MANTISSA_FAST_PATH {
+                return None;
+            }
+            exponent = max_exponent;
+        }
+
+        let value = F::from_u64(mantissa);
+        Some(if exponent < 0 {
+            value.div_round(pow_radix::<F>(radix, -exponent))
+        } else {
+            value.mul_round(pow_radix::<F>(radix, exponent))
+        })
+    }
+}
+
+/// Calculate an exact power of the radix, using exponentiation by squaring.
+///
+/// The power must be within [`Float::exponent_limit`], so that every
+/// intermediate power is also exact.
+///
+/// [`Float::exponent_limit`]: ../num/trait.Float.html#method.exponent_limit
+#[cfg(feature = "radix")]
+fn pow_radix<F: Float>(radix: u32, mut exponent: i32) -> F {
+    debug_assert!(exponent >= 0);
+    let mut base = F::from_u64(radix as u64);
+    let mut power = F::from_u64(1);
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            power = power.mul_round(base);
+        }
+        exponent >>= 1;
+        if exponent != 0 {
+            base = base.mul_round(base);
+        }
+    }
+    power
 }
@@ -2,18 +2,30 @@
 
 #![doc(hidden)]
 
-#[cfg(feature = "compact")]
-use crate::bellerophon::bellerophon;
+#[cfg(any(feature = "compact", feature = "bellerophon"))]
+use crate::bellerophon::{bellerophon, bellerophon_error};
+use crate::digits::parse_digits;
 use crate::extended_float::{extended_to_float, ExtendedFloat};
-#[cfg(not(feature = "compact"))]
-use crate::lemire::lemire;
-use crate::num::Float;
+#[cfg(feature = "nightly")]
+use crate::fpu::set_precision;
+#[cfg(all(feature = "wide_mantissa", not(any(feature = "compact", feature = "bellerophon"))))]
+use crate::lemire::lemire_wide;
+#[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+use crate::lemire::{lemire, lemire_error};
+use crate::num::{is_exactly_representable, Float};
 use crate::number::Number;
-use crate::slow::slow;
+#[cfg(feature = "no_slow_path")]
+use crate::rounding::{round, round_nearest_tie_even};
+#[cfg(not(feature = "no_slow_path"))]
+use crate::slow::slow_with_scaler;
+use crate::slow::{compare_float, slow, DecimalScaler};
+use core::{cmp, iter, ops};
 
 /// Try to parse the significant digits quickly.
 ///
 /// This attempts a very quick parse, to deal with common cases.
+/// This stops after the first 20 digits, so long inputs are
+/// never fully traversed before falling back.
 ///
 /// * `integer`     - Slice containing the integer digits.
 /// * `fraction`    - Slice containing the fraction digits.
@@ -28,38 +40,46 @@ where
     Iter2: Iterator<Item = &'a u8>,
 {
     let mut num = Number::default();
-    let mut integer_count: usize = 0;
+    let mut count: usize = 0;
     let mut fraction_count: usize = 0;
     for &c in integer {
-        integer_count += 1;
+        count += 1;
+        if count > 19 {
+            return None;
+        }
         let digit = c - b'0';
-        num.mantissa = num.mantissa.wrapping_mul(10).wrapping_add(digit as u64);
+        num.mantissa = num.mantissa * 10 + digit as u64;
     }
     for &c in fraction {
+        count += 1;
         fraction_count += 1;
+        if count > 19 {
+            return None;
+        }
         let digit = c - b'0';
-        num.mantissa = num.mantissa.wrapping_mul(10).wrapping_add(digit as u64);
+        num.mantissa = num.mantissa * 10 + digit as u64;
     }
 
-    if integer_count + fraction_count <= 19 {
-        // Can't overflow, since must be <= 19.
-        num.exponent = exponent.saturating_sub(fraction_count as i32);
-        Some(num)
-    } else {
-        None
-    }
+    num.exponent = mantissa_exponent(exponent, fraction_count, 0);
+    Some(num)
 }
 
 /// Parse the significant digits of the float and adjust the exponent.
 ///
+/// Only leading zeros in the fraction and the first 20 significant digits
+/// are visited, except for the integer digits, which must all be counted
+/// to scale the exponent.
+///
 /// * `integer`     - Slice containing the integer digits.
 /// * `fraction`    - Slice containing the fraction digits.
 #[inline]
-fn parse_number<'a, Iter1, Iter2>(mut integer: Iter1, mut fraction: Iter2, exponent: i32) -> Number
+pub fn parse_number<'a, Iter1, Iter2>(integer: Iter1, mut fraction: Iter2, exponent: i32) -> Number
 where
     Iter1: Iterator<Item = &'a u8> + Clone,
     Iter2: Iterator<Item = &'a u8> + Clone,
 {
+    let mut integer = skip_leading_zeros(integer);
+
     // NOTE: for performance, we do this in 2 passes:
     if let Some(num) = parse_number_fast(integer.clone(), fraction.clone(), exponent) {
         return num;
@@ -71,9 +91,18 @@ where
     while let Some(&c) = integer.next() {
         count += 1;
         if count == 20 {
-            // Only the integer digits affect the exponent.
-            num.many_digits = true;
-            num.exponent = exponent.saturating_add(into_i32(1 + integer.count()));
+            // Only the integer digits affect the exponent. Trailing zeros
+            // in the integer are not trimmed, however, they do not lose
+            // any significant digits, so the mantissa is exact if all
+            // the truncated digits are zero and there is no fraction.
+            let mut truncated = 1;
+            let mut is_zero = c == b'0';
+            for &c in integer {
+                truncated += 1;
+                is_zero &= c == b'0';
+            }
+            num.many_digits = !is_zero || fraction.next().is_some();
+            num.exponent = mantissa_exponent(exponent, 0, truncated);
             return num;
         } else {
             let digit = c - b'0';
@@ -99,12 +128,14 @@ where
         fraction_count += 1;
         count += 1;
         if count == 20 {
+            // The fraction has no trailing zeros, so a nonzero digit is truncated.
             num.many_digits = true;
-            // This can't wrap, since we have at most 20 digits.
             // We've adjusted the exponent too high by `fraction_count - 1`.
             // Note: -1 is due to incrementing this loop iteration, which we
-            // didn't use.
-            num.exponent = exponent.saturating_sub(fraction_count as i32 - 1);
+            // didn't use. This can't underflow, since we've visited at least
+            // 1 fraction digit, however, many leading zeros in the fraction
+            // could overflow an `i32`, so we must saturate.
+            num.exponent = mantissa_exponent(exponent, fraction_count, 1);
             return num;
         } else {
             let digit = c - b'0';
@@ -113,11 +144,37 @@ where
     }
 
     // No truncated digits: easy.
-    // Cannot overflow: <= 20 digits.
-    num.exponent = exponent.saturating_sub(fraction_count as i32);
+    // The fraction may be empty, or only have leading zeros.
+    num.exponent = mantissa_exponent(exponent, fraction_count, 0);
     num
 }
 
+/// Parse the significant digits of the float from contiguous digits.
+///
+/// This is identical to [`parse_number`], however, it parses many
+/// digits at a time if there are at most 19 significant digits.
+///
+/// * `integer`     - Slice containing the integer digits.
+/// * `fraction`    - Slice containing the fraction digits.
+///
+/// [`parse_number`]: fn.parse_number.html
+#[inline]
+pub fn parse_number_slice(integer: &[u8], fraction: &[u8], exponent: i32) -> Number {
+    let zeros = integer.iter().take_while(|&&c| c == b'0').count();
+    let integer = &integer[zeros..];
+    if integer.len() + fraction.len() > 19 {
+        return parse_number(integer.iter(), fraction.iter(), exponent);
+    }
+
+    // Can't overflow, since we have at most 19 digits.
+    let mantissa = parse_digits(parse_digits(0, integer), fraction);
+    Number {
+        exponent: mantissa_exponent(exponent, fraction.len(), 0),
+        mantissa,
+        many_digits: false,
+    }
+}
+
 /// Parse float from extracted float components.
 ///
 /// * `integer`     - Cloneable, forward iterator over integer digits.
@@ -125,10 +182,26 @@ where
 /// * `exponent`    - Parsed, 32-bit exponent.
 ///
 /// # Preconditions
-/// 1. The integer should not have leading zeros.
+/// 1. The integer should not have leading zeros. This is only required
+///    for performance, since leading zeros are skipped internally.
 /// 2. The fraction should not have trailing zeros.
 /// 3. All bytes in `integer` and `fraction` should be valid digits,
-///     in the range [`b'0', b'9'].
+///    in the range [`b'0', b'9'].
+///
+/// # Rounding
+///
+/// The float is always correctly rounded from the decimal value,
+/// with ties rounded to even. For `f32`, the float is rounded directly
+/// from the decimal value and never through an `f64`, so it cannot
+/// be double-rounded.
+///
+/// With the `no_slow_path` feature, inputs that cannot be unambiguously
+/// rounded by the fast or moderate path algorithms, that is, those
+/// within a few bits of halfway between two floats, use the rounded
+/// moderate path approximation instead. The result is then either
+/// the correctly-rounded float, or an adjacent float, and is always
+/// within 1 ULP of the correct result. All other inputs are still
+/// correctly rounded.
 ///
 /// # Panics
 ///
@@ -151,42 +224,1125 @@ where
 {
     // Parse the mantissa and attempt the fast and moderate-path algorithms.
     let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    number_to_float(num, integer, fraction, None).0
+}
+
+/// Convert the parsed significant digits to a float, and the algorithm used.
+///
+/// This is the shared implementation of all the parsers. If `scaler`
+/// is provided, the power of 5 used by the slow path algorithms is
+/// cached in it. With the `no_slow_path` feature, the scaler is unused,
+/// and the slow path is reported if the fallback algorithm approximated
+/// the float.
+///
+/// The iterators must be over the same digits used to parse `num`.
+#[inline]
+fn number_to_float<'a, F, Iter1, Iter2>(
+    num: Number,
+    integer: Iter1,
+    fraction: Iter2,
+    scaler: Option<&mut DecimalScaler>,
+) -> (F, ParsePath)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    // Check for guaranteed underflow or overflow.
+    if let Some(value) = num.try_zero_or_infinity() {
+        return (value, ParsePath::Fast);
+    }
     // Try the fast-path algorithm.
     if let Some(value) = num.try_fast_path() {
-        return value;
+        return (value, ParsePath::Fast);
     }
+    let (fp, path) = number_to_extended::<F, _, _>(num, integer, fraction, scaler);
+    (extended_to_float::<F>(fp), path)
+}
 
-    // Now try the moderate path algorithm.
+/// Convert the parsed significant digits to the rounded float bits, and the algorithm used.
+///
+/// This is identical to [`number_to_float`] after the fast path fails,
+/// but returns the mantissa bits and the biased exponent of the float,
+/// before they are packed into the native float.
+///
+/// [`number_to_float`]: fn.number_to_float.html
+#[inline]
+fn number_to_extended<'a, F, Iter1, Iter2>(
+    num: Number,
+    integer: Iter1,
+    fraction: Iter2,
+    scaler: Option<&mut DecimalScaler>,
+) -> (ExtendedFloat, ParsePath)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    // Try the moderate path algorithm.
     let mut fp = moderate_path::<F>(&num);
+    // Retry with more significant digits if the digits were truncated.
+    #[cfg(all(feature = "wide_mantissa", not(any(feature = "compact", feature = "bellerophon"))))]
+    {
+        if fp.exp < 0 && num.many_digits {
+            fp = lemire_wide::<F, _, _>(&num, integer.clone(), fraction.clone()).unwrap_or(fp);
+        }
+    }
+    if fp.exp >= 0 {
+        return (fp, ParsePath::Moderate);
+    }
+
+    // Unable to correctly round the float using the fast or moderate algorithms.
+    // Fallback to a slower, but always correct algorithm. Undo the invalid
+    // extended float biasing first.
+    fp.exp -= F::INVALID_FP;
+    fp = match scaler {
+        #[cfg(not(feature = "no_slow_path"))]
+        Some(scaler) => slow_with_scaler::<F, _, _>(num, fp, integer, fraction, scaler),
+        _ => fallback_path::<F, _, _>(num, fp, integer, fraction),
+    };
+    (fp, ParsePath::Slow)
+}
+
+/// Split a float into the mantissa bits and the biased exponent.
+///
+/// This is the inverse of [`extended_to_float`], and does not round.
+///
+/// [`extended_to_float`]: ../extended_float/fn.extended_to_float.html
+#[inline(always)]
+fn float_to_extended<F: Float>(float: F) -> ExtendedFloat {
+    let bits = float.to_bits();
+    ExtendedFloat {
+        mant: bits & F::MANTISSA_MASK,
+        exp: ((bits & F::EXPONENT_MASK) >> F::MANTISSA_SIZE) as i32,
+    }
+}
+
+/// Parse float from extracted float components, as slices of digits.
+///
+/// This is identical to [`parse_float`], but accepts the digits
+/// directly, for callers that do not have cloneable iterators. Since
+/// the digits are contiguous, many digits can be parsed at a time.
+///
+/// * `integer`     - Slice containing the integer digits.
+/// * `fraction`    - Slice containing the fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// The preconditions are the same as [`parse_float`].
+///
+/// [`parse_float`]: fn.parse_float.html
+#[inline]
+pub fn parse_float_slice<F: Float>(integer: &[u8], fraction: &[u8], exponent: i32) -> F {
+    if let Some(float) = try_parse_small(integer, fraction, exponent) {
+        return float;
+    }
+    let num = parse_number_slice(integer, fraction, exponent);
+    number_to_float(num, integer.iter(), fraction.iter(), None).0
+}
+
+/// Parse a float with few significant digits and a small exponent.
+///
+/// This is the most common input, such as `1.5`, so it is handled
+/// before parsing the digits into a [`Number`]. The digits fit in the
+/// float's mantissa, and the power of 10 is exact, so a single
+/// multiplication or division is correctly rounded. Otherwise, this
+/// returns `None`.
+///
+/// [`Number`]: ../number/struct.Number.html
+#[inline(always)]
+fn try_parse_small<F: Float>(integer: &[u8], fraction: &[u8], exponent: i32) -> Option<F> {
+    // At most 15 digits cannot overflow, or be far from the fast path.
+    if integer.len() + fraction.len() > 15 {
+        return None;
+    }
+    let mantissa = parse_digits(parse_digits(0, integer), fraction);
+    let exponent = exponent.checked_sub(fraction.len() as i32)?;
+    if mantissa > F::MAX_MANTISSA_FAST_PATH
+        || exponent < F::MIN_EXPONENT_FAST_PATH
+        || exponent > F::MAX_EXPONENT_FAST_PATH
+    {
+        return None;
+    }
+
+    #[cfg(feature = "nightly")]
+    let _cw = set_precision::<F>();
+    let value = F::from_u64(mantissa);
+    Some(if exponent < 0 {
+        // SAFETY: safe, since `exponent >= F::MIN_EXPONENT_FAST_PATH`.
+        value.div_round(unsafe { F::pow_fast_path((-exponent) as _) })
+    } else {
+        // SAFETY: safe, since `exponent <= F::MAX_EXPONENT_FAST_PATH`.
+        value.mul_round(unsafe { F::pow_fast_path(exponent as _) })
+    })
+}
+
+/// Float components, as tokenized by an external parser.
+///
+/// The digits may have leading and trailing zeros, and may be empty,
+/// however, they must all be valid digits, in the range [`b'0', b'9'].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NumberParts<'a> {
+    /// If the float is positive, or has no sign.
+    pub is_positive: bool,
+    /// The integer digits.
+    pub integer: &'a [u8],
+    /// The fraction digits.
+    pub fraction: &'a [u8],
+    /// Parsed, 32-bit exponent.
+    pub exponent: i32,
+}
+
+/// Parse float from the tokenized float components.
+///
+/// This is identical to [`parse_float_slice`], but trims leading zeros
+/// from the integer and trailing zeros from the fraction, and applies
+/// the sign. Empty or zero digits are parsed as 0, with the sign, for
+/// any exponent.
+///
+/// * `parts`       - Tokenized float components.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{parse_float_parts, NumberParts};
+///
+/// let parts = NumberParts {
+///     is_positive: false,
+///     integer: b"0012",
+///     fraction: b"500",
+///     exponent: -1,
+/// };
+/// assert_eq!(parse_float_parts::<f64>(parts), -1.25);
+/// ```
+///
+/// [`parse_float_slice`]: fn.parse_float_slice.html
+#[inline]
+pub fn parse_float_parts<F: Float>(parts: NumberParts) -> F {
+    let integer = ltrim_zero(parts.integer);
+    let fraction = rtrim_zero(parts.fraction);
+    let float: F = parse_float_slice(integer, fraction, parts.exponent);
+    if parts.is_positive {
+        float
+    } else {
+        -float
+    }
+}
+
+/// Parse float from extracted float components, validating the digits.
+///
+/// This is identical to [`parse_float_parts`] for a positive float,
+/// but accepts any bytes, and never panics. The digits end at the
+/// first byte that is not a digit, as if it were the end of the input:
+/// if the integer contains an invalid byte, the fraction is ignored,
+/// and if the fraction contains one, the remaining bytes are ignored.
+/// The exponent is applied to the digits before the invalid byte.
+///
+/// This is suitable for untrusted input that has not been tokenized,
+/// where [`parse_float`] would require validating the digits first.
+///
+/// * `integer`     - Slice containing the integer digits.
+/// * `fraction`    - Slice containing the fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_float_total;
+///
+/// assert_eq!(parse_float_total::<f64>(b"12", b"50", 0), 12.5);
+/// assert_eq!(parse_float_total::<f64>(b"12", b"5x7", 0), 12.5);
+/// assert_eq!(parse_float_total::<f64>(b"1x2", b"5", 2), 100.0);
+/// assert_eq!(parse_float_total::<f64>(b"-1", b"", 0), 0.0);
+/// ```
+///
+/// [`parse_float_parts`]: fn.parse_float_parts.html
+/// [`parse_float`]: fn.parse_float.html
+#[inline]
+pub fn parse_float_total<F: Float>(integer: &[u8], fraction: &[u8], exponent: i32) -> F {
+    let integer_count = integer.iter().take_while(|c| c.is_ascii_digit()).count();
+    let fraction = if integer_count == integer.len() {
+        fraction
+    } else {
+        &fraction[..0]
+    };
+    let fraction_count = fraction.iter().take_while(|c| c.is_ascii_digit()).count();
+    let integer = ltrim_zero(&integer[..integer_count]);
+    let fraction = rtrim_zero(&fraction[..fraction_count]);
+    parse_float_slice(integer, fraction, exponent)
+}
+
+/// Float components, as the rounded binary significand and exponent.
+///
+/// The float is exactly `mant * 2^exp`, with the sign. Normal floats
+/// have the hidden bit set in `mant`, and denormal floats and 0 use
+/// the denormal exponent, so `mant` and `exp` are identical to the
+/// [`Float::mantissa`] and [`Float::exponent`] of the native float.
+/// An infinite float is the hidden bit times `2^(F::MAX_EXPONENT)`,
+/// one power of two past the largest finite float.
+///
+/// `rounding` is the float compared to the exact decimal value: it is
+/// `Less` if the value was rounded down, and `Greater` if it was rounded
+/// up. This is the sticky information needed to round the float again
+/// to a narrower type, without double rounding: if the truncated bits
+/// are exactly halfway, the narrower float is rounded up if `rounding`
+/// is `Less`, down if it is `Greater`, and to even if it is `Equal`.
+///
+/// [`Float::mantissa`]: trait.Float.html#method.mantissa
+/// [`Float::exponent`]: trait.Float.html#method.exponent
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FloatParts {
+    /// If the float is positive, or has no sign.
+    pub is_positive: bool,
+    /// The binary significand.
+    pub mant: u64,
+    /// The binary exponent.
+    pub exp: i32,
+    /// The rounded float compared to the exact decimal value.
+    pub rounding: cmp::Ordering,
+}
+
+/// Parse float from the tokenized float components, as the binary significand and exponent.
+///
+/// This is identical to [`parse_float_parts`], but returns the
+/// correctly-rounded float before it is packed into the native float,
+/// and the direction the decimal value was rounded in. This is useful
+/// to construct custom or narrower float types.
+///
+/// The float is resolved with the same algorithms as [`parse_float`],
+/// but if it is not exact, the digits are then compared to the float
+/// using the slow path algorithms, to find the rounding direction.
+/// Values with at most 19 significant digits that are exactly
+/// representable are never compared. With the `no_slow_path` feature,
+/// near-halfway inputs may return an adjacent float, but `rounding`
+/// is still exact for the float returned.
+///
+/// * `parts`       - Tokenized float components.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{parse_to_parts, FloatParts, NumberParts};
+/// use std::cmp::Ordering;
+///
+/// let parts = NumberParts {
+///     is_positive: false,
+///     integer: b"16777217",
+///     fraction: b"",
+///     exponent: 0,
+/// };
+/// assert_eq!(parse_to_parts::<f32>(parts), FloatParts {
+///     is_positive: false,
+///     mant: 1 << 23,
+///     exp: 1,
+///     rounding: Ordering::Less,
+/// });
+/// ```
+///
+/// [`parse_float_parts`]: fn.parse_float_parts.html
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_to_parts<F: Float>(parts: NumberParts) -> FloatParts {
+    let integer = ltrim_zero(parts.integer);
+    let fraction = rtrim_zero(parts.fraction);
+    let num = parse_number_slice(integer, fraction, parts.exponent);
+    if num.mantissa == 0 {
+        return FloatParts {
+            is_positive: parts.is_positive,
+            mant: 0,
+            exp: F::DENORMAL_EXPONENT,
+            rounding: cmp::Ordering::Equal,
+        };
+    }
+
+    let fast = num.try_zero_or_infinity::<F>().or_else(|| num.try_fast_path::<F>());
+    let fp = match fast {
+        Some(float) => float_to_extended(float),
+        None => number_to_extended::<F, _, _>(num, integer.iter(), fraction.iter(), None).0,
+    };
+    let rounding = if fp.mant == 0 && fp.exp == 0 {
+        cmp::Ordering::Less
+    } else if fp.exp == F::INFINITE_POWER {
+        cmp::Ordering::Greater
+    } else if !num.many_digits && is_exactly_representable::<F>(num.mantissa, num.exponent) {
+        cmp::Ordering::Equal
+    } else {
+        let float = extended_to_float::<F>(fp);
+        let ordering = compare_float(float, integer.iter(), fraction.iter(), parts.exponent);
+        // The digits after `F::MAX_DIGITS` were rounded-up into the compared
+        // digits, so if those are equal to the float, the digits are below it.
+        let is_truncated = || significant_digits(integer.iter(), fraction.iter()) > F::MAX_DIGITS;
+        match ordering {
+            cmp::Ordering::Equal if is_truncated() => cmp::Ordering::Greater,
+            _ => ordering.reverse(),
+        }
+    };
+
+    let (mant, exp) = match fp.exp {
+        0 => (fp.mant, F::DENORMAL_EXPONENT),
+        biased => (fp.mant | F::HIDDEN_BIT_MASK, biased - F::EXPONENT_BIAS),
+    };
+    FloatParts {
+        is_positive: parts.is_positive,
+        mant,
+        exp,
+        rounding,
+    }
+}
+
+/// Parse float from extracted float components, only if it is exactly representable.
+///
+/// This returns `None` if the decimal value would be rounded, for example,
+/// `0.1` cannot be exactly represented, while `0.5` and the exact decimal
+/// value of the float nearest `0.1` can. This is useful to flag lossy
+/// float literals. Values that overflow to infinity or underflow to 0
+/// are never exact, while a literal 0 always is.
+///
+/// Values with at most 19 significant digits are checked without any
+/// big-integer arithmetic. Otherwise, the digits are compared to the
+/// float using the slow path algorithms, so with the `no_slow_path`
+/// feature, values with more than 19 significant digits are treated as
+/// inexact.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// The preconditions are the same as [`parse_float`].
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::try_parse_exact;
+///
+/// assert_eq!(try_parse_exact::<f64, _, _>(b"0".iter(), b"5".iter(), 0), Some(0.5));
+/// assert_eq!(try_parse_exact::<f64, _, _>(b"0".iter(), b"1".iter(), 0), None);
+/// ```
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn try_parse_exact<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> Option<F>
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    if !num.many_digits {
+        return match is_exactly_representable::<F>(num.mantissa, num.exponent) {
+            true => Some(number_to_float(num, integer, fraction, None).0),
+            false => None,
+        };
+    }
+
+    // An exact float never has more than `F::MAX_DIGITS` significant
+    // digits, so the slow path never truncates the digits compared.
+    #[cfg(not(feature = "no_slow_path"))]
+    {
+        if significant_digits(integer.clone(), fraction.clone()) <= F::MAX_DIGITS {
+            let float: F = number_to_float(num, integer.clone(), fraction.clone(), None).0;
+            let bits = float.to_bits();
+            if bits != 0
+                && bits != F::EXPONENT_MASK
+                && compare_float(float, integer, fraction, exponent) == cmp::Ordering::Equal
+            {
+                return Some(float);
+            }
+        }
+    }
+
+    None
+}
+
+/// Parse float from extracted float components, and count the significant digits.
+///
+/// This is identical to [`parse_float`], but also returns the number of
+/// significant digits, from the first to the last non-zero digit.
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_with_digits<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (F, usize)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let count = significant_digits(integer.clone(), fraction.clone());
+    (parse_float(integer, fraction, exponent), count)
+}
+
+/// Parse float from extracted float components, and the exponent of the mantissa.
+///
+/// This is identical to [`parse_float`], but also returns the saturated
+/// exponent of the parsed mantissa: `1.2345e10` is `12345` and `6`.
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_with_exponent<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (F, i32)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    (number_to_float(num, integer, fraction, None).0, num.exponent)
+}
+
+/// Parse float from extracted float components, and if it is correctly rounded.
+///
+/// This is identical to [`parse_float`], but also returns `false` if the
+/// float was approximated to within 1 ULP with the `no_slow_path` feature.
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_with_confidence<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (F, bool)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    let (float, path) = number_to_float(num, integer, fraction, None);
+    (float, path != ParsePath::Slow || cfg!(not(feature = "no_slow_path")))
+}
+
+/// Parse float from integer digits, without a fraction or exponent.
+///
+/// This is a dedicated path for integers that are exactly representable,
+/// the common case for integers in JSON, and otherwise delegates to
+/// [`parse_float`].
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+///
+/// # Preconditions
+/// 1. The integer should not have leading zeros. This is only required
+///    for performance, since leading zeros are skipped internally.
+/// 2. All bytes in `integer` should be valid digits, in the range [`b'0', b'9'].
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_integer_float<'a, F, Iter>(integer: Iter) -> F
+where
+    F: Float,
+    Iter: Iterator<Item = &'a u8> + Clone,
+{
+    // Can only add 19 digits without overflow, and any integer with more
+    // digits is larger than the maximum exactly representable mantissa.
+    let mut mantissa: u64 = 0;
+    for (index, &c) in integer.clone().enumerate() {
+        if index == 19 {
+            return parse_float(integer, [].iter(), 0);
+        }
+        let digit = c - b'0';
+        mantissa = mantissa * 10 + digit as u64;
+    }
+
+    if mantissa <= F::MAX_MANTISSA_FAST_PATH {
+        // Exactly representable, no rounding can occur.
+        F::from_u64(mantissa)
+    } else {
+        parse_float(integer, [].iter(), 0)
+    }
+}
+
+/// Parse float from integer digits and an exponent, without a fraction.
+///
+/// This is identical to [`parse_float`] with an empty fraction, so the
+/// code to parse the fraction digits is removed after inlining.
+///
+/// [`parse_float`]: fn.parse_float.html
+#[inline]
+pub fn parse_integer_mantissa<'a, F, Iter>(integer: Iter, exponent: i32) -> F
+where
+    F: Float,
+    Iter: Iterator<Item = &'a u8> + Clone,
+{
+    parse_float(integer, iter::empty(), exponent)
+}
+
+/// ASCII digits, to convert chars to digit bytes.
+static ASCII_DIGITS: [u8; 10] = *b"0123456789";
+
+/// Convert a char, which must be an ASCII digit, to a digit byte.
+#[inline(always)]
+fn char_to_digit(c: char) -> &'static u8 {
+    debug_assert!(c.is_ascii_digit());
+    &ASCII_DIGITS[(c as u32).wrapping_sub('0' as u32) as usize]
+}
+
+/// Parse float from extracted float components, as chars.
+///
+/// This is identical to [`parse_float`], but accepts iterators over chars,
+/// which must all be ASCII digits, or this will panic.
+///
+/// [`parse_float`]: fn.parse_float.html
+#[inline]
+pub fn parse_float_chars<F, Iter1, Iter2>(integer: Iter1, fraction: Iter2, exponent: i32) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = char> + Clone,
+    Iter2: Iterator<Item = char> + Clone,
+{
+    parse_float(integer.map(char_to_digit), fraction.map(char_to_digit), exponent)
+}
+
+/// Parse float from extracted float components, with a 64-bit exponent.
+///
+/// This is identical to [`parse_float`], but the exponent is saturated
+/// to an `i32` with [`saturate_exponent`].
+///
+/// [`parse_float`]: fn.parse_float.html
+/// [`saturate_exponent`]: fn.saturate_exponent.html
+#[inline]
+pub fn parse_float_exp64<'a, F, Iter1, Iter2>(integer: Iter1, fraction: Iter2, exponent: i64) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    parse_float(integer, fraction, saturate_exponent(exponent))
+}
+
+/// Parse float from extracted float components, truncated to a maximum
+/// number of significant digits.
+///
+/// Only the first `max_digits` significant digits are used, and the
+/// exponent is adjusted accordingly. If any of the discarded digits are
+/// non-zero, they act as a sticky bit when rounding, so the float is
+/// rounded as if from a value just above the truncated digits. This is
+/// useful to emulate a source with limited precision. For example,
+/// using 17 significant digits for an `f64` will recover the original
+/// float from most exact, decimal representations, however, since the
+/// digits are truncated rather than rounded, this is not guaranteed.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+/// * `max_digits`  - Maximum number of significant digits, which must be non-zero.
+///
+/// The preconditions are the same as [`parse_float`].
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_truncated<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    max_digits: usize,
+) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    debug_assert!(max_digits != 0);
+    let integer = skip_leading_zeros(integer);
+
+    // Leading zeros in the fraction are only insignificant without integer digits.
+    let integer_count = integer.clone().count();
+    let leading_zeros = match integer_count {
+        0 => fraction.clone().take_while(|&&c| c == b'0').count(),
+        _ => 0,
+    };
+    let is_truncated = integer
+        .clone()
+        .chain(fraction.clone().skip(leading_zeros))
+        .skip(max_digits)
+        .any(|&c| c != b'0');
+    if !is_truncated {
+        // Any discarded digits are 0, so this is exact.
+        return parse_float(integer, fraction, exponent);
+    }
+
+    // Replace the discarded integer digits with 0s, to keep the same
+    // magnitude, and drop the discarded fraction digits. For the sticky
+    // bit, add a non-zero digit past the maximum number of digits the
+    // slow path uses: it rounds identically to any non-zero remainder.
+    let integer = integer.enumerate().map(move |(index, c)| match index < max_digits {
+        true => c,
+        false => &b'0',
+    });
+    let fraction_count = max_digits.saturating_sub(integer_count);
+    let padding = F::MAX_DIGITS.saturating_sub(integer_count.max(max_digits));
+    let fraction = fraction
+        .take(leading_zeros + fraction_count)
+        .chain(iter::repeat(&b'0').take(padding))
+        .chain(iter::once(&b'1'));
+    parse_float(integer, fraction, exponent)
+}
+
+/// Parse extracted float components to an integer at a fixed decimal scale.
+///
+/// Returns the value multiplied by `10^scale`, if it is an integer that
+/// fits in an `i64`, otherwise, `None`. For example, `"12.340"` at scale
+/// `3` is `12340`, while at scale `1` it has a non-zero fractional
+/// remainder, and is `None`. This never converts to a float, so there is
+/// no rounding error. The components are unsigned, so the result is never
+/// negative, and the caller should negate it as needed.
+///
+/// * `integer`     - Forward iterator over integer digits.
+/// * `fraction`    - Forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+/// * `scale`       - Number of decimal places in the result.
+///
+/// # Preconditions
+/// 1. All bytes in `integer` and `fraction` should be valid digits,
+///    in the range [`b'0', b'9'].
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_fixed_point;
+///
+/// assert_eq!(parse_fixed_point(b"12".iter(), b"340".iter(), 0, 3), Some(12340));
+/// assert_eq!(parse_fixed_point(b"12".iter(), b"340".iter(), 0, 1), None);
+/// assert_eq!(parse_fixed_point(b"1".iter(), b"5".iter(), 2, 0), Some(150));
+/// ```
+pub fn parse_fixed_point<'a, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    scale: i32,
+) -> Option<i64>
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    // Trailing zeros are deferred, and only added to the mantissa if
+    // followed by a non-zero digit, so they never cause an overflow.
+    let mut mantissa: u64 = 0;
+    let mut zeros: usize = 0;
+    let mut fraction_count: usize = 0;
+    let mut push_digit = |c: u8| -> Option<()> {
+        if c != b'0' {
+            mantissa = mul_pow10(mantissa, zeros + 1)?.checked_add((c - b'0') as u64)?;
+            zeros = 0;
+        } else if mantissa != 0 {
+            zeros += 1;
+        }
+        Some(())
+    };
+    for &c in integer {
+        push_digit(c)?;
+    }
+    for &c in fraction {
+        push_digit(c)?;
+        fraction_count += 1;
+    }
+    if mantissa == 0 {
+        return Some(0);
+    }
+
+    // The mantissa has no trailing zeros, so any negative power of 10
+    // leaves a non-zero fractional remainder.
+    let shift = mantissa_exponent(exponent, fraction_count, zeros).saturating_add(scale);
+    if shift < 0 {
+        return None;
+    }
+    let value = mul_pow10(mantissa, shift as usize)?;
+    match value <= i64::max_value() as u64 {
+        true => Some(value as i64),
+        false => None,
+    }
+}
+
+/// Multiply the value by `10^exp`, or `None` on overflow.
+#[inline]
+fn mul_pow10(mut value: u64, exp: usize) -> Option<u64> {
+    // Any non-zero value overflows after at most 20 multiplications.
+    if value != 0 {
+        for _ in 0..exp {
+            value = value.checked_mul(10)?;
+        }
+    }
+    Some(value)
+}
+
+/// Algorithm used to determine the correctly-rounded float.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParsePath {
+    /// Exact conversion using native floats, or a literal 0 or infinity.
+    Fast,
+    /// Extended-precision approximation (Lemire or Bellerophon).
+    Moderate,
+    /// Arbitrary-precision, big-integer comparison.
+    /// This is never reported with the `no_slow_path` feature.
+    Slow,
+}
+
+/// Parse float from extracted float components, and the algorithm used.
+///
+/// This is identical to [`parse_float`], but also reports which
+/// algorithm resolved the float, which is useful for diagnosing
+/// performance regressions due to slow-path hits.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// The preconditions are the same as [`parse_float`].
+///
+/// [`parse_float`]: fn.parse_float.html
+#[cfg(feature = "diagnostics")]
+pub fn parse_float_diagnostic<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (F, ParsePath)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    let (float, path) = number_to_float(num, integer, fraction, None);
+    // The fallback algorithm only rounds the moderate path approximation.
+    #[cfg(feature = "no_slow_path")]
+    let path = match path {
+        ParsePath::Slow => ParsePath::Moderate,
+        path => path,
+    };
+    (float, path)
+}
+
+/// Parse float from extracted float components, reusing powers in the slow path.
+///
+/// This is identical to [`parse_float`], but caches the power of 5 used by
+/// the slow path in `scaler`, for floats with the same exponent and number
+/// of digits. With the `no_slow_path` feature, the scaler is unused.
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_with_scaler<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    scaler: &mut DecimalScaler,
+) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    number_to_float(num, integer, fraction, Some(scaler)).0
+}
+
+/// Parse many floats from spans of digits in a buffer.
+///
+/// Each span is the byte range of the float in `buf`, and the offset
+/// of the decimal point from the start of the range. An offset equal
+/// to the length of the range means the float has no decimal point.
+/// The float may have a leading `+` or `-` sign, which is part of the
+/// integer digits, but not an exponent. Leading and trailing zeros
+/// are trimmed, as with [`parse_float_parts`].
+///
+/// The float for each span is written to the same index in `out`,
+/// and `valid` is set to if the span is a valid float. A span is
+/// invalid if the range or the decimal point offset are out of bounds,
+/// it has no digits, or it contains any other characters. The value
+/// in `out` for an invalid span is left unchanged. Returns the number
+/// of valid spans.
+///
+/// Unless the `no_slow_path` feature is enabled, all the floats share
+/// a [`DecimalScaler`], so the power of 5 used to round near-halfway
+/// floats is reused for floats with the same exponent and number of
+/// digits, such as a column of fixed-precision values.
+///
+/// * `spans`       - Byte range and decimal point offset of each float.
+/// * `buf`         - Buffer containing the floats.
+/// * `out`         - Parsed floats, for each span.
+/// * `valid`       - If each span is a valid float.
+///
+/// # Panics
+///
+/// Panics if `out` or `valid` are shorter than `spans`.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_floats_into;
+///
+/// let buf = b"1.5,-20,.25,1.2.3";
+/// let spans = [(0..3, 1), (4..7, 3), (8..11, 0), (12..17, 1)];
+/// let mut out = [0f64; 4];
+/// let mut valid = [false; 4];
+/// assert_eq!(parse_floats_into(&spans, buf, &mut out, &mut valid), 3);
+/// assert_eq!(out[..3], [1.5, -20.0, 0.25]);
+/// assert_eq!(valid, [true, true, true, false]);
+/// ```
+///
+/// [`parse_float_parts`]: fn.parse_float_parts.html
+/// [`DecimalScaler`]: ../slow/struct.DecimalScaler.html
+pub fn parse_floats_into<F: Float>(
+    spans: &[(ops::Range<usize>, usize)],
+    buf: &[u8],
+    out: &mut [F],
+    valid: &mut [bool],
+) -> usize {
+    assert!(out.len() >= spans.len(), "parse_floats_into() `out` is too short.");
+    assert!(valid.len() >= spans.len(), "parse_floats_into() `valid` is too short.");
+
+    let mut scaler = DecimalScaler::new();
+    let mut count = 0;
+    for (index, &(ref range, point)) in spans.iter().enumerate() {
+        let parts = match split_span(buf, range.clone(), point) {
+            Some(parts) => parts,
+            None => {
+                valid[index] = false;
+                continue;
+            },
+        };
+        let integer = ltrim_zero(parts.integer);
+        let fraction = rtrim_zero(parts.fraction);
+        let num = parse_number_slice(integer, fraction, 0);
+        let float: F = number_to_float(num, integer.iter(), fraction.iter(), Some(&mut scaler)).0;
+        out[index] = if parts.is_positive {
+            float
+        } else {
+            -float
+        };
+        valid[index] = true;
+        count += 1;
+    }
+    count
+}
+
+/// Split a span of the buffer into the sign, integer and fraction digits.
+///
+/// Returns `None` if the span is out of bounds, has no digits,
+/// or has any invalid characters.
+#[inline]
+fn split_span<'a>(
+    buf: &'a [u8],
+    range: ops::Range<usize>,
+    point: usize,
+) -> Option<NumberParts<'a>> {
+    let bytes = buf.get(range)?;
+    let (integer, fraction) = match point {
+        _ if point == bytes.len() => (bytes, &b""[..]),
+        _ if point < bytes.len() && bytes[point] == b'.' => (&bytes[..point], &bytes[point + 1..]),
+        _ => return None,
+    };
+    let (is_positive, integer) = match integer.first() {
+        Some(&b'+') => (true, &integer[1..]),
+        Some(&b'-') => (false, &integer[1..]),
+        _ => (true, integer),
+    };
+    let is_digit = |&c: &u8| c.is_ascii_digit();
+    if integer.len() + fraction.len() == 0
+        || !integer.iter().all(is_digit)
+        || !fraction.iter().all(is_digit)
+    {
+        return None;
+    }
+
+    Some(NumberParts {
+        is_positive,
+        integer,
+        fraction,
+        exponent: 0,
+    })
+}
+
+/// Parse float from extracted float components, always using the slow path.
+///
+/// This bypasses the fast and moderate path algorithms, and is therefore
+/// considerably slower than [`parse_float`]. It is useful to validate
+/// that the fast and moderate paths produce identical results to the
+/// slow path algorithms, without requiring near-halfway inputs.
+/// Literal 0 and infinite values, determined only from the exponent
+/// range, do not use the slow path algorithms.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// The preconditions are the same as [`parse_float`].
+///
+/// [`parse_float`]: fn.parse_float.html
+pub fn parse_float_slow<'a, F, Iter1, Iter2>(integer: Iter1, fraction: Iter2, exponent: i32) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let num = parse_number(integer.clone(), fraction.clone(), exponent);
+    let mut fp = moderate_path_error::<F>(&num);
     if fp.exp < 0 {
-        // Undo the invalid extended float biasing.
         fp.exp -= F::INVALID_FP;
         fp = slow::<F, _, _>(num, fp, integer, fraction);
     }
-
-    // Unable to correctly round the float using the fast or moderate algorithms.
-    // Fallback to a slower, but always correct algorithm. If we have
-    // lossy, we can't be here.
     extended_to_float::<F>(fp)
 }
 
+/// Wrapper for different moderate-path algorithms, without rounding.
+/// A return exponent of `-1` indicates an invalid value.
+#[inline]
+pub fn moderate_path_error<F: Float>(num: &Number) -> ExtendedFloat {
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    return lemire_error::<F>(num);
+
+    #[cfg(any(feature = "compact", feature = "bellerophon"))]
+    return bellerophon_error::<F>(num);
+}
+
 /// Wrapper for different moderate-path algorithms.
 /// A return exponent of `-1` indicates an invalid value.
 #[inline]
 pub fn moderate_path<F: Float>(num: &Number) -> ExtendedFloat {
-    #[cfg(not(feature = "compact"))]
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
     return lemire::<F>(num);
 
-    #[cfg(feature = "compact")]
+    #[cfg(any(feature = "compact", feature = "bellerophon"))]
     return bellerophon::<F>(num);
 }
 
+/// Wrapper for the fallback algorithms, when the moderate path fails.
+///
+/// With the `no_slow_path` feature, the slow path algorithms are not
+/// available, and the moderate path approximation, `fp`, is rounded
+/// to nearest directly. The approximation is within a few units of
+/// the truncated extended-precision representation, so the result
+/// is either the correctly-rounded float, or an adjacent float,
+/// that is, within 1 ULP of the correct result.
+#[inline]
+pub fn fallback_path<'a, F, Iter1, Iter2>(
+    num: Number,
+    fp: ExtendedFloat,
+    integer: Iter1,
+    fraction: Iter2,
+) -> ExtendedFloat
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    #[cfg(not(feature = "no_slow_path"))]
+    return slow::<F, _, _>(num, fp, integer, fraction);
+
+    #[cfg(feature = "no_slow_path")]
+    {
+        let _ = (num, integer, fraction);
+        let mut fp = fp;
+        if -fp.exp + 1 >= 65 {
+            // Below half the smallest denormal: always rounds to 0.
+            return ExtendedFloat {
+                mant: 0,
+                exp: 0,
+            };
+        }
+        round::<F, _>(&mut fp, |f, s| {
+            round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+                is_above || (is_odd && is_halfway)
+            });
+        });
+        fp
+    }
+}
+
+/// Skip the leading zeros in the integer digits.
+///
+/// Leading zeros are not significant digits, and would otherwise count
+/// towards the maximum number of digits used in each algorithm.
+#[inline]
+pub fn skip_leading_zeros<'a, Iter>(integer: Iter) -> iter::SkipWhile<Iter, fn(&&'a u8) -> bool>
+where
+    Iter: Iterator<Item = &'a u8>,
+{
+    let is_zero: fn(&&'a u8) -> bool = |&&c| c == b'0';
+    integer.skip_while(is_zero)
+}
+
+/// Trim leading 0s.
+#[inline]
+pub fn ltrim_zero(bytes: &[u8]) -> &[u8] {
+    let count = bytes.iter().take_while(|&&c| c == b'0').count();
+    &bytes[count..]
+}
+
+/// Trim trailing 0s.
+#[inline]
+pub fn rtrim_zero(bytes: &[u8]) -> &[u8] {
+    let count = bytes.iter().rev().take_while(|&&c| c == b'0').count();
+    &bytes[..bytes.len() - count]
+}
+
+/// Count the significant digits, from the first to the last non-zero digit.
+///
+/// Leading and trailing zeros are not significant, so `0` has no
+/// significant digits, and `0.00120` has 2 significant digits.
+#[inline]
+pub fn significant_digits<'a, Iter1, Iter2>(integer: Iter1, fraction: Iter2) -> usize
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    let mut count = 0;
+    let mut zeros = 0;
+    for &c in integer.chain(fraction) {
+        if c != b'0' {
+            count += zeros + 1;
+            zeros = 0;
+        } else if count != 0 {
+            zeros += 1;
+        }
+    }
+    count
+}
+
+/// Calculate the exponent of the significant digits, from the parsed exponent.
+///
+/// The significant digits are parsed as an integer mantissa, so the
+/// parsed exponent must be adjusted for the number of fraction digits
+/// in the mantissa, and the number of truncated digits not in the
+/// mantissa. The value is then `mantissa * 10^mantissa_exponent`.
+///
+/// The digit counts saturate when converted to an `i32`, and the result
+/// saturates rather than overflows, which is always correct since any
+/// exponent this large is guaranteed to be zero or infinite.
+///
+/// * `exponent`        - Parsed, 32-bit exponent.
+/// * `fraction_count`  - Number of fraction digits, including truncated digits.
+/// * `truncated`       - Number of truncated digits, not in the mantissa.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse::mantissa_exponent;
+///
+/// // "1.2345e10", with the mantissa 12345.
+/// assert_eq!(mantissa_exponent(10, 4, 0), 6);
+/// // "123456.7e0", truncated to the mantissa 1234.
+/// assert_eq!(mantissa_exponent(0, 1, 3), 2);
+/// // "1.234567e0", truncated to the mantissa 1234.
+/// assert_eq!(mantissa_exponent(0, 6, 3), -3);
+/// ```
+#[inline]
+pub fn mantissa_exponent(exponent: i32, fraction_count: usize, truncated: usize) -> i32 {
+    if fraction_count > truncated {
+        exponent.saturating_sub(into_i32(fraction_count - truncated))
+    } else {
+        exponent.saturating_add(into_i32(truncated - fraction_count))
+    }
+}
+
 /// Convert usize into i32 without overflow.
 ///
 /// This is needed to ensure when adjusting the exponent relative to
 /// the mantissa we do not overflow for comically-long exponents.
+/// Digit counts are saturated, rather than wrapped, so combining them
+/// with the exponent must also saturate. Any count this large already
+/// guarantees the float is zero or infinite.
 #[inline]
-fn into_i32(value: usize) -> i32 {
+pub fn into_i32(value: usize) -> i32 {
     if value > i32::max_value() as usize {
         i32::max_value()
     } else {
@@ -194,8 +1350,56 @@ fn into_i32(value: usize) -> i32 {
     }
 }
 
-// Add digit to mantissa.
+/// Saturate a 64-bit exponent to an `i32`.
+///
+/// The exponents of every finite, non-zero float are far within an
+/// `i32`, and the exponent is combined with the digit counts using
+/// saturating arithmetic, so any exponent outside an `i32` is a literal
+/// 0 or infinity, and saturating it does not change the float.
+#[inline]
+pub fn saturate_exponent(exponent: i64) -> i32 {
+    if exponent > i32::max_value() as i64 {
+        i32::max_value()
+    } else if exponent < i32::min_value() as i64 {
+        i32::min_value()
+    } else {
+        exponent as i32
+    }
+}
+
+/// Convert a decimal character to a digit, or `None` if invalid.
+#[inline]
+pub fn to_digit(c: u8) -> Option<u8> {
+    to_digit_radix(c, 10)
+}
+
+/// Convert a character to a digit in the radix, or `None` if invalid.
+///
+/// Digits above 9 use the letters `a-z`, case-insensitively.
+/// The radix must be in the range `[2, 36]`.
+#[inline]
+pub fn to_digit_radix(c: u8, radix: u32) -> Option<u8> {
+    debug_assert!((2..=36).contains(&radix), "to_digit_radix() invalid radix.");
+    let digit = match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'z' => c - b'a' + 10,
+        b'A'..=b'Z' => c - b'A' + 10,
+        _ => return None,
+    };
+    match (digit as u32) < radix {
+        true => Some(digit),
+        false => None,
+    }
+}
+
+/// Add a decimal digit to the mantissa, or `None` on overflow.
 #[inline]
 pub fn add_digit(value: u64, digit: u8) -> Option<u64> {
-    value.checked_mul(10)?.checked_add(digit as u64)
+    add_digit_radix(value, digit, 10)
+}
+
+/// Add a digit in the radix to the mantissa, or `None` on overflow.
+#[inline]
+pub fn add_digit_radix(value: u64, digit: u8, radix: u32) -> Option<u64> {
+    value.checked_mul(radix as u64)?.checked_add(digit as u64)
 }
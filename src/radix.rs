@@ -0,0 +1,361 @@
+//! Parse floats from significant digits in any radix from 2 to 36.
+//!
+//! The algorithms mirror the decimal ones, with a few differences:
+//!     1. Power-of-two radixes only shift the binary exponent, so they
+//!        are always exact, and any truncated digits are a sticky bit.
+//!     2. The fast path calculates the exact powers of the radix, rather
+//!        than using pre-computed tables.
+//!     3. The slow path rounds the truncated significant digits exactly,
+//!        and if any digits were truncated, compares all the digits to
+//!        the halfway point, one digit at a time. Halfway points are not
+//!        exactly representable in odd radixes, so there is no maximum
+//!        number of digits, unlike the decimal algorithms.
+//!
+//! With the `no_slow_path` feature, the slow path is replaced by an
+//! extended-precision estimate, which may be incorrect for near-halfway
+//! representations.
+
+#![cfg(feature = "radix")]
+#![doc(hidden)]
+
+use crate::bigint::{self, Bigint};
+use crate::extended_float::ExtendedFloat;
+use crate::num::Float;
+use crate::number::Number;
+use crate::parse::{add_digit_radix, into_i32, parse_float, skip_leading_zeros, to_digit_radix};
+#[cfg(not(feature = "no_slow_path"))]
+use crate::slow::bh;
+use core::cmp;
+
+// ALGORITHM
+// ---------
+
+/// Parse a float from the integer and fraction digits in a radix, and the exponent.
+///
+/// The value is `integer.fraction * radix^exponent`, so the exponent
+/// is a power of the radix, not of 10. The digits may be uppercase or
+/// lowercase, and decimal floats use [`parse_float`].
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent, as a power of the radix.
+/// * `radix`       - Radix of the digits and the exponent, from 2 to 36.
+///
+/// # Preconditions
+/// 1. The integer should not have leading zeros, for performance.
+/// 2. The fraction should not have trailing zeros, for performance.
+/// 3. All bytes in `integer` and `fraction` should be valid digits in the radix.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_float_radix;
+///
+/// let float: f64 = parse_float_radix(b"1f".iter(), b"8".iter(), 0, 16);
+/// assert_eq!(float, 31.5);
+/// let float: f64 = parse_float_radix(b"1".iter(), b"".iter(), -1, 3);
+/// assert_eq!(float, 1.0 / 3.0);
+/// ```
+///
+/// [`parse_float`]: ../fn.parse_float.html
+pub fn parse_float_radix<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    radix: u32,
+) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    debug_assert!((2..=36).contains(&radix), "parse_float_radix() invalid radix.");
+    if radix == 10 {
+        return parse_float::<F, _, _>(integer, fraction, exponent);
+    }
+
+    let num = parse_number_radix(integer.clone(), fraction.clone(), exponent, radix);
+    if num.mantissa == 0 {
+        return F::from_u64(0);
+    }
+
+    // Truncated digits are at least 58 bits below the leading bit, so
+    // they can be collapsed into the least significant bit.
+    if radix.is_power_of_two() {
+        let exp2 = radix.trailing_zeros() as i64 * num.exponent as i64;
+        let exp2 = cmp::max(cmp::min(exp2, 0x4000), -0x4000) as i32;
+        return F::from_mantissa_exp2(num.mantissa | num.many_digits as u64, exp2);
+    }
+    if let Some(float) = num.try_fast_path_radix::<F>(radix) {
+        return float;
+    }
+    if let Some(float) = num.try_zero_or_infinity_radix::<F>(radix) {
+        return float;
+    }
+
+    #[cfg(not(feature = "no_slow_path"))]
+    return slow_radix::<F, _, _>(num, integer, fraction, exponent, radix);
+
+    #[cfg(feature = "no_slow_path")]
+    return moderate_radix::<F>(num, radix);
+}
+
+/// Parse the significant digits and the exponent of the truncated mantissa.
+///
+/// This stops at the first digit that would overflow the mantissa, so
+/// a truncated mantissa always has at least 58 significant bits.
+pub fn parse_number_radix<'a, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    radix: u32,
+) -> Number
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    let mut mantissa: u64 = 0;
+    let mut is_truncated = false;
+    let mut many_digits = false;
+    let mut exponent = exponent as i64;
+
+    for &c in skip_leading_zeros(integer) {
+        let digit = to_digit(c, radix);
+        if !is_truncated {
+            match add_digit_radix(mantissa, digit, radix) {
+                Some(value) => {
+                    mantissa = value;
+                    continue;
+                },
+                None => is_truncated = true,
+            }
+        }
+        many_digits |= digit != 0;
+        exponent += 1;
+    }
+
+    for &c in fraction {
+        let digit = to_digit(c, radix);
+        if !is_truncated {
+            match add_digit_radix(mantissa, digit, radix) {
+                Some(value) => {
+                    mantissa = value;
+                    exponent -= 1;
+                    continue;
+                },
+                None => is_truncated = true,
+            }
+        }
+        many_digits |= digit != 0;
+    }
+
+    let exponent = cmp::max(cmp::min(exponent, i32::max_value() as i64), i32::min_value() as i64);
+    Number {
+        exponent: exponent as i32,
+        mantissa,
+        many_digits,
+    }
+}
+
+/// Round the significant digits exactly, and then compare any truncated digits.
+///
+/// The truncated digits are at least 58 bits below the leading bit, so
+/// the correctly-rounded float is either `b`, the rounded significant
+/// digits, or `b+u`. The digits are then compared to `b+h` to round.
+#[cfg(not(feature = "no_slow_path"))]
+fn slow_radix<'a, F, Iter1, Iter2>(
+    num: Number,
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    radix: u32,
+) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let float = round_number_radix::<F>(num, radix);
+    if !num.many_digits || float.to_bits() == F::EXPONENT_MASK {
+        return float;
+    }
+
+    let bits = float.to_bits();
+    match compare_digits_radix(integer, fraction, exponent, radix, bh(float)) {
+        cmp::Ordering::Greater => F::from_bits(bits + 1),
+        cmp::Ordering::Less => float,
+        cmp::Ordering::Equal => F::from_bits(bits + (bits & 1)),
+    }
+}
+
+/// Round `mantissa * radix^exponent` to the nearest float, exactly.
+///
+/// The value must be in the range of the float, from
+/// [`Number::try_zero_or_infinity_radix`], so the big integers cannot
+/// overflow.
+///
+/// [`Number::try_zero_or_infinity_radix`]: ../number/struct.Number.html#method.try_zero_or_infinity_radix
+#[cfg(not(feature = "no_slow_path"))]
+fn round_number_radix<F: Float>(num: Number, radix: u32) -> F {
+    let mut bigmant = Bigint::from_u64(num.mantissa);
+    if num.exponent >= 0 {
+        bigmant.pow(radix, num.exponent as u32).unwrap();
+        let (mant, is_truncated) = bigmant.hi64();
+        let exp2 = bigmant.bit_length() as i32 - 64;
+        F::from_mantissa_exp2(mant | is_truncated as u64, exp2)
+    } else {
+        // Scale the mantissa so the quotient has at least 65 bits, and
+        // any truncated bits or remainder are below the rounding bit.
+        let mut divisor = Bigint::from_u64(1);
+        divisor.pow(radix, num.exponent.wrapping_neg() as u32).unwrap();
+        let shift = divisor.bit_length() as i32 + 65 - bigmant.bit_length() as i32;
+        let shift = cmp::max(shift, 0);
+        if shift != 0 {
+            bigmant.shl(shift as usize).unwrap();
+        }
+        let (quotient, rem) = bigint::large_div(&bigmant.data, &divisor.data).unwrap();
+        let (mant, is_truncated) = bigint::hi64(&quotient);
+        let exp2 = bigint::bit_length(&quotient) as i32 - 64 - shift;
+        F::from_mantissa_exp2(mant | (is_truncated || !rem.is_empty()) as u64, exp2)
+    }
+}
+
+/// Compare the real digits in the radix to the theoretical digits, `m * 2^exp`.
+///
+/// The theoretical digits are scaled to the scientific exponent of the
+/// real digits, and then generated one digit at a time, so any number
+/// of real digits can be compared exactly.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent, as a power of the radix.
+/// * `radix`       - Radix of the digits and the exponent, from 2 to 36.
+/// * `theor`       - Theoretical digits, with an unbiased exponent.
+///
+/// # Preconditions
+/// 1. At least one integer or fraction digit must be non-zero.
+/// 2. The value must be in the range of the float, so the big integers
+///    cannot overflow.
+pub fn compare_digits_radix<'a, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+    radix: u32,
+    theor: ExtendedFloat,
+) -> cmp::Ordering
+where
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    // The real value is `0.digits * radix^sci_exp`, without leading zeros.
+    let integer_count = into_i32(integer.clone().count());
+    let digits = integer.chain(fraction);
+    let zeros = into_i32(digits.clone().take_while(|&&c| c == b'0').count());
+    let sci_exp = exponent.saturating_add(integer_count).saturating_sub(zeros);
+    let mut digits = digits.skip_while(|&&c| c == b'0');
+
+    // Scale the theoretical digits to `num / den`, so they are also
+    // in `[0, 1)`, unless they are larger than the real digits.
+    let mut num = Bigint::from_u64(theor.mant);
+    let mut den = Bigint::from_u64(1);
+    if theor.exp > 0 {
+        num.shl(theor.exp as usize).unwrap();
+    } else if theor.exp < 0 {
+        den.shl(theor.exp.wrapping_neg() as usize).unwrap();
+    }
+    if sci_exp > 0 {
+        den.pow(radix, sci_exp as u32).unwrap();
+    } else if sci_exp < 0 {
+        num.pow(radix, sci_exp.wrapping_neg() as u32).unwrap();
+    }
+    if bigint::compare(&num.data, &den.data) != cmp::Ordering::Less {
+        return cmp::Ordering::Less;
+    }
+
+    // Generate each theoretical digit, by long division, and compare it.
+    for &c in &mut digits {
+        num.data.mul_small(radix as bigint::Limb).unwrap();
+        let mut theor_digit = 0;
+        while bigint::compare(&num.data, &den.data) != cmp::Ordering::Less {
+            bigint::large_sub(&mut num.data, &den.data);
+            theor_digit += 1;
+        }
+        let real_digit = to_digit(c, radix);
+        if real_digit != theor_digit {
+            return real_digit.cmp(&theor_digit);
+        } else if num.data.is_empty() {
+            // The theoretical digits are exhausted.
+            return match digits.any(|&c| c != b'0') {
+                true => cmp::Ordering::Greater,
+                false => cmp::Ordering::Equal,
+            };
+        }
+    }
+
+    // The real digits are exhausted, and the theoretical digits are not.
+    cmp::Ordering::Less
+}
+
+/// Estimate `mantissa * radix^exponent` using extended-precision floats.
+///
+/// This is accurate to within a few bits of the extended-precision
+/// mantissa, so it is only incorrect for near-halfway representations.
+#[cfg(feature = "no_slow_path")]
+fn moderate_radix<F: Float>(num: Number, radix: u32) -> F {
+    let mut fp = ExtendedFloat {
+        mant: num.mantissa,
+        exp: 0,
+    };
+    fp.normalize();
+    let mut fp = fp.mul(&extended_pow_radix(radix, num.exponent));
+    fp.normalize();
+    fp.into_float::<F>()
+}
+
+/// Calculate a normalized power of the radix, using exponentiation by squaring.
+///
+/// Negative powers start from the reciprocal of the radix, so they are
+/// only approximate.
+#[cfg(feature = "no_slow_path")]
+fn extended_pow_radix(radix: u32, exponent: i32) -> ExtendedFloat {
+    let mut base = if exponent >= 0 {
+        ExtendedFloat {
+            mant: radix as u64,
+            exp: 0,
+        }
+    } else {
+        let reciprocal = (1u128 << 127) / radix as u128;
+        let shift = reciprocal.leading_zeros();
+        ExtendedFloat {
+            mant: ((reciprocal << shift) >> 64) as u64,
+            exp: -63 - shift as i32,
+        }
+    };
+    base.normalize();
+
+    let mut power = ExtendedFloat {
+        mant: 1 << 63,
+        exp: -63,
+    };
+    let mut exponent = (exponent as i64).abs();
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            power = power.mul(&base);
+            power.normalize();
+        }
+        exponent >>= 1;
+        if exponent != 0 {
+            base = base.mul(&base);
+            base.normalize();
+        }
+    }
+    power
+}
+
+/// Convert a character to a digit in the radix.
+#[inline]
+fn to_digit(c: u8, radix: u32) -> u8 {
+    let digit = to_digit_radix(c, radix);
+    debug_assert!(digit.is_some(), "parse_float_radix() invalid digit.");
+    digit.unwrap_or(0)
+}
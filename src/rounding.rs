@@ -129,3 +129,15 @@ pub fn round_down(fp: &mut ExtendedFloat, shift: i32) {
     };
     fp.exp += shift;
 }
+
+/// Round our significant digits into place, rounding towards infinity.
+#[cfg_attr(not(feature = "compact"), inline)]
+pub fn round_up(fp: &mut ExtendedFloat, shift: i32) {
+    // Ensure we've already handled denormal values that underflow.
+    debug_assert!(shift <= 64);
+
+    // Round-up if any of the truncated bits are set.
+    let truncated_bits = fp.mant & lower_n_mask(shift as u64);
+    round_down(fp, shift);
+    fp.mant += (truncated_bits != 0) as u64;
+}
@@ -0,0 +1,1096 @@
+//! Tokenize and parse complete floats from bytes.
+//!
+//! This provides a simple front-end to [`parse_float_slice`], which handles
+//! the sign, the integer, fraction and exponent components, trimming
+//! zeros from the significant digits, and special values.
+//!
+//! [`parse_float_slice`]: ../parse/fn.parse_float_slice.html
+
+#![doc(hidden)]
+
+use crate::num::Float;
+use crate::parse::{
+    ltrim_zero,
+    parse_float,
+    parse_float_chars,
+    parse_float_parts,
+    parse_float_slice,
+    rtrim_zero,
+    NumberParts,
+};
+use crate::special::{is_continued_special, is_special_prefix, parse_special};
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::{cmp, fmt, iter};
+
+// HELPERS
+// -------
+
+/// Parse the sign, and get the number of bytes consumed.
+#[inline]
+fn parse_sign(bytes: &[u8]) -> (bool, usize) {
+    match bytes.first() {
+        Some(&b'+') => (true, 1),
+        Some(&b'-') => (false, 1),
+        _ => (true, 0),
+    }
+}
+
+/// Get the number of leading digits in the buffer.
+#[inline]
+fn count_digits(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&c| c.is_ascii_digit()).count()
+}
+
+/// Get the number of leading integer digits and group separators in the buffer.
+///
+/// The integer must start with a digit, and trailing group separators
+/// are not consumed, since they cannot be followed by any digits.
+#[inline]
+fn count_grouped_digits(bytes: &[u8], separator: u8) -> usize {
+    let mut count = 0;
+    for (index, &c) in bytes.iter().enumerate() {
+        if c.is_ascii_digit() {
+            count = index + 1;
+        } else if c != separator || index == 0 {
+            break;
+        }
+    }
+    count
+}
+
+/// Check if the group separators in the integer digits are in groups of 3.
+///
+/// The first group must have 1 to 3 digits, and every subsequent group
+/// must have exactly 3 digits. If `is_partial`, the last group may have
+/// fewer digits, since more digits could follow. Integers without any
+/// group separators are always valid.
+#[inline]
+fn is_valid_grouping(integer: &[u8], separator: u8, is_partial: bool) -> bool {
+    if !integer.contains(&separator) {
+        return true;
+    }
+
+    let mut groups = integer.split(|&c| c == separator);
+    let first = groups.next().map_or(0, |group| group.len());
+    if first == 0 || first > 3 {
+        return false;
+    }
+    let mut previous = 3;
+    for group in groups {
+        if previous != 3 || group.len() > 3 {
+            return false;
+        }
+        previous = group.len();
+    }
+    previous == 3 || is_partial
+}
+
+/// Parse the exponent digits, saturating on overflow.
+///
+/// Any exponent too large to fit in an `i32` is guaranteed to be
+/// infinite or zero, so saturating does not affect the result.
+#[inline]
+fn parse_exponent<'a, Iter>(digits: Iter, is_positive: bool) -> i32
+where
+    Iter: IntoIterator<Item = &'a u8>,
+{
+    let mut value: i32 = 0;
+    for &c in digits {
+        let digit = (c - b'0') as i32;
+        value = match is_positive {
+            true => value.saturating_mul(10).saturating_add(digit),
+            false => value.saturating_mul(10).saturating_sub(digit),
+        };
+    }
+    value
+}
+
+// OPTIONS
+// -------
+
+/// Validation rules for the float format, applied when scanning.
+///
+/// By default, all rules are disabled, and the most permissive format
+/// is accepted. Floats which violate an enabled rule are rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Character separating the integer and fraction digits, `.` by default.
+    ///
+    /// This must not be a digit, a sign or an exponent symbol.
+    pub decimal_point: u8,
+    /// Character introducing the exponent, `e` by default.
+    ///
+    /// Letters match case-insensitively, so `e` matches `e` or `E`, and
+    /// any other character, such as `^`, must match exactly. This must
+    /// not be a digit, a sign or the decimal point.
+    pub exponent: u8,
+    /// Character grouping the integer digits, such as `,` in `1,000,000`.
+    ///
+    /// If `None`, the default, grouping is not allowed. Group separators
+    /// may only occur between integer digits, and must not be the same
+    /// character as the decimal point.
+    pub group_separator: Option<u8>,
+    /// Require the integer digits to be in groups of 3, if grouped.
+    ///
+    /// The first group may have 1 to 3 digits. Otherwise, group
+    /// separators may occur anywhere between integer digits.
+    pub strict_grouping: bool,
+    /// Disallow a leading `+` sign before the significant digits.
+    pub no_positive_mantissa_sign: bool,
+    /// Require digits after the exponent symbol.
+    pub require_exponent_digits: bool,
+    /// Disallow a `+` sign before the exponent digits.
+    pub no_positive_exponent_sign: bool,
+    /// Accept the Fortran-style `d` or `D` exponent symbols, as well as `e` or `E`.
+    pub allow_fortran_exponent: bool,
+    /// Maximum number of exponent digits to consume, including leading zeros.
+    ///
+    /// If `None`, the default, all exponent digits are consumed. Otherwise,
+    /// any further digits are not part of the float, and are left as
+    /// trailing bytes. The exponent saturates, so this only bounds the
+    /// work spent on absurdly long exponents.
+    pub max_exponent_digits: Option<usize>,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            decimal_point: b'.',
+            exponent: b'e',
+            group_separator: None,
+            strict_grouping: false,
+            no_positive_mantissa_sign: false,
+            require_exponent_digits: false,
+            no_positive_exponent_sign: false,
+            allow_fortran_exponent: false,
+            max_exponent_digits: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Create the default options with a custom exponent symbol.
+    ///
+    /// Returns `None` if the exponent symbol is invalid, that is,
+    /// if it is a digit, a sign, or the decimal point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use minimal_lexical::{parse_complete_with_options, ParseOptions};
+    ///
+    /// let options = ParseOptions::with_exponent(b'^').unwrap();
+    /// assert_eq!(parse_complete_with_options::<f64>(b"1.5^3", &options), Some((1500.0, 5)));
+    /// assert_eq!(ParseOptions::with_exponent(b'1'), None);
+    /// ```
+    #[inline]
+    pub fn with_exponent(exponent: u8) -> Option<Self> {
+        let options = Self {
+            exponent,
+            ..Self::default()
+        };
+        match options.is_valid() {
+            true => Some(options),
+            false => None,
+        }
+    }
+
+    /// Check if the characters used by the options are unambiguous.
+    ///
+    /// The decimal point, exponent symbol and group separator must not be
+    /// digits or signs, and must all be distinct. Letters are compared
+    /// case-insensitively, since the exponent symbol is case-insensitive.
+    pub fn is_valid(&self) -> bool {
+        let is_reserved = |c: u8| c.is_ascii_digit() || c == b'+' || c == b'-';
+        let is_same = |x: u8, y: u8| x.eq_ignore_ascii_case(&y);
+        let is_valid_separator = self.group_separator.map_or(true, |c| {
+            !is_reserved(c) && !is_same(c, self.decimal_point) && !self.is_exponent(c)
+        });
+        !is_reserved(self.decimal_point)
+            && !is_reserved(self.exponent)
+            && !self.is_exponent(self.decimal_point)
+            && is_valid_separator
+    }
+
+    /// Check if the character is a valid exponent symbol.
+    #[inline]
+    pub fn is_exponent(&self, c: u8) -> bool {
+        match c {
+            _ if c.eq_ignore_ascii_case(&self.exponent) => true,
+            b'd' | b'D' => self.allow_fortran_exponent,
+            _ => false,
+        }
+    }
+
+    /// Get the number of leading integer digits, including group separators.
+    #[inline]
+    fn count_integer_digits(&self, bytes: &[u8]) -> usize {
+        match self.group_separator {
+            Some(separator) => count_grouped_digits(bytes, separator),
+            None => count_digits(bytes),
+        }
+    }
+}
+
+// SCAN
+// ----
+
+/// Byte ranges of the components of a float within a buffer.
+///
+/// Each component is `None` if it is not present in the buffer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FloatSpans {
+    /// Range of the leading `+` or `-` sign.
+    pub sign: Option<Range<usize>>,
+    /// Range of the integer digits, including any group separators.
+    pub integer: Option<Range<usize>>,
+    /// Range of the fraction digits, after the decimal point.
+    pub fraction: Option<Range<usize>>,
+    /// Range of the exponent sign and digits, after the exponent symbol.
+    pub exponent: Option<Range<usize>>,
+    /// Total number of bytes in the float, or 0 if no float was found.
+    pub len: usize,
+}
+
+impl FloatSpans {
+    /// Check if the float has any integer or fraction digits.
+    #[inline]
+    pub fn has_digits(&self) -> bool {
+        self.integer.is_some() || self.fraction.is_some()
+    }
+}
+
+/// Get the range of `count` bytes at `start`, or `None` if empty.
+#[inline]
+fn span(start: usize, count: usize) -> Option<Range<usize>> {
+    match count {
+        0 => None,
+        _ => Some(start..start + count),
+    }
+}
+
+/// Scan the components of a float from bytes, without parsing it.
+///
+/// This identifies an optional sign, the integer digits, an optional `.`
+/// followed by the fraction digits, and an optional exponent, introduced
+/// by `e` or `E`, followed by an optional sign and the exponent digits.
+/// Scanning stops at the first byte which cannot be part of the float,
+/// and an exponent symbol without any exponent digits is not consumed.
+///
+/// If the bytes do not start with any integer or fraction digits,
+/// every component is `None` and the length is 0. Special values,
+/// such as `NaN` and `inf`, are not scanned.
+///
+/// The digit spans may be passed to [`parse_float`], after trimming
+/// leading zeros from the integer and trailing zeros from the fraction.
+///
+/// * `bytes`       - Buffer leading with the float.
+///
+/// [`parse_float`]: ../parse/fn.parse_float.html
+#[inline]
+pub fn scan_float(bytes: &[u8]) -> FloatSpans {
+    scan_float_with_options(bytes, &ParseOptions::default())
+}
+
+/// Scan the components of a float from bytes, validating the format.
+///
+/// This is identical to [`scan_float`], except the fraction digits follow
+/// the decimal point in `options`, and every component is `None` and the
+/// length is 0 if the float violates any of the rules enabled in `options`.
+///
+/// * `bytes`       - Buffer leading with the float.
+/// * `options`     - Validation rules for the float format.
+///
+/// [`scan_float`]: fn.scan_float.html
+pub fn scan_float_with_options(bytes: &[u8], options: &ParseOptions) -> FloatSpans {
+    // Scan the sign.
+    if options.no_positive_mantissa_sign && bytes.first() == Some(&b'+') {
+        return FloatSpans::default();
+    }
+    let sign_count = parse_sign(bytes).1;
+    let mut index = sign_count;
+
+    // Scan the integer and fraction digits.
+    let integer_count = options.count_integer_digits(&bytes[index..]);
+    let integer = span(index, integer_count);
+    if let (Some(separator), true) = (options.group_separator, options.strict_grouping) {
+        if !is_valid_grouping(&bytes[index..index + integer_count], separator, false) {
+            return FloatSpans::default();
+        }
+    }
+    index += integer_count;
+    let mut fraction = None;
+    if bytes.get(index) == Some(&options.decimal_point) {
+        let fraction_count = count_digits(&bytes[index + 1..]);
+        if integer_count == 0 && fraction_count == 0 {
+            // A lone decimal point is not a valid float.
+            return FloatSpans::default();
+        }
+        fraction = span(index + 1, fraction_count);
+        index += 1 + fraction_count;
+    }
+
+    if integer.is_none() && fraction.is_none() {
+        return FloatSpans::default();
+    }
+
+    // Scan the exponent, if it has any digits.
+    let mut exponent = None;
+    if bytes.get(index).map_or(false, |&c| options.is_exponent(c)) {
+        let exponent_sign_count = parse_sign(&bytes[index + 1..]).1;
+        let digits_start = index + 1 + exponent_sign_count;
+        let digits = &bytes[digits_start..];
+        let digits = match options.max_exponent_digits {
+            Some(max) => &digits[..cmp::min(max, digits.len())],
+            None => digits,
+        };
+        let digits_count = count_digits(digits);
+        if digits_count != 0 {
+            let is_positive_sign = bytes[index + 1] == b'+';
+            if options.no_positive_exponent_sign && is_positive_sign {
+                return FloatSpans::default();
+            }
+            exponent = span(index + 1, exponent_sign_count + digits_count);
+            index = digits_start + digits_count;
+        } else if options.require_exponent_digits {
+            return FloatSpans::default();
+        }
+    }
+
+    FloatSpans {
+        sign: span(0, sign_count),
+        integer,
+        fraction,
+        exponent,
+        len: index,
+    }
+}
+
+// PARSE
+// -----
+
+/// Parse a complete float from bytes.
+///
+/// This parses an optional sign, the integer digits, an optional `.`
+/// followed by the fraction digits, and an optional exponent, introduced
+/// by `e` or `E`, followed by an optional sign and the exponent digits.
+/// If no significant digits are present, the special values `NaN`, `inf`
+/// and `infinity` are parsed case-insensitively, as by [`parse_special`].
+///
+/// Returns the parsed float and the number of bytes consumed, or `None`
+/// if the bytes do not start with a valid float. Parsing stops at the
+/// first byte which cannot be part of the float, and an exponent symbol
+/// without any exponent digits is not consumed.
+///
+/// * `bytes`       - Buffer leading with the float.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_complete;
+/// use std::f64;
+///
+/// assert_eq!(parse_complete::<f64>(b"1.2345e2,"), Some((123.45, 8)));
+/// assert_eq!(parse_complete::<f64>(b"-inf"), Some((f64::NEG_INFINITY, 4)));
+/// assert_eq!(parse_complete::<f64>(b"e5"), None);
+/// ```
+///
+/// [`parse_special`]: ../special/fn.parse_special.html
+#[inline]
+pub fn parse_complete<F: Float>(bytes: &[u8]) -> Option<(F, usize)> {
+    parse_complete_with_options(bytes, &ParseOptions::default())
+}
+
+/// Parse a complete float from bytes, validating the format.
+///
+/// This is identical to [`parse_complete`], except the fraction digits
+/// follow the decimal point in `options`, and `None` is returned if the
+/// float violates any of the rules enabled in `options`.
+///
+/// * `bytes`       - Buffer leading with the float.
+/// * `options`     - Validation rules for the float format.
+///
+/// [`parse_complete`]: fn.parse_complete.html
+pub fn parse_complete_with_options<F: Float>(
+    bytes: &[u8],
+    options: &ParseOptions,
+) -> Option<(F, usize)> {
+    if options.no_positive_mantissa_sign && bytes.first() == Some(&b'+') {
+        return None;
+    }
+    let spans = scan_float_with_options(bytes, options);
+
+    // Check for special values if we have no significant digits.
+    if !spans.has_digits() {
+        return parse_special(bytes);
+    }
+    Some((parse_spans(bytes, &spans, options), spans.len))
+}
+
+/// Parse a float from the scanned components, which must have digits.
+///
+/// * `bytes`       - Buffer leading with the float.
+/// * `spans`       - Components of the float, from `scan_float_with_options`.
+/// * `options`     - Validation rules used to scan the float.
+#[inline]
+fn parse_spans<F: Float>(bytes: &[u8], spans: &FloatSpans, options: &ParseOptions) -> F {
+    // Parse the exponent, including the sign.
+    let exponent = match spans.exponent {
+        Some(ref range) => {
+            let (is_exponent_positive, count) = parse_sign(&bytes[range.clone()]);
+            parse_exponent(&bytes[range.start + count..range.end], is_exponent_positive)
+        },
+        None => 0,
+    };
+
+    // Trim leading zeros from the integer, and trailing zeros from the
+    // fraction, as required by `parse_float_slice`. Grouped integers
+    // must skip the group separators, so they cannot use slices.
+    let integer = spans.integer.clone().map_or(&bytes[..0], |range| &bytes[range]);
+    let fraction = spans.fraction.clone().map_or(&bytes[..0], |range| &bytes[range]);
+    let fraction = rtrim_zero(fraction);
+    let float: F = match options.group_separator {
+        Some(separator) if integer.contains(&separator) => {
+            let integer = integer.iter().filter(move |&&c| c != separator);
+            parse_float(integer, fraction.iter(), exponent)
+        },
+        _ => parse_float_slice(ltrim_zero(integer), fraction, exponent),
+    };
+    if parse_sign(bytes).0 {
+        float
+    } else {
+        -float
+    }
+}
+
+/// Get the number of leading digits and underscores in a Rust literal.
+#[inline]
+fn count_rust_digits(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|&&c| c.is_ascii_digit() || c == b'_').count()
+}
+
+/// Check if the character may continue a Rust identifier or literal suffix.
+///
+/// Any non-ASCII character is assumed to be an identifier character,
+/// since it may be `XID_Continue`.
+#[inline]
+fn is_rust_identifier(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || !c.is_ascii()
+}
+
+/// Parse a float from bytes, using the grammar of Rust float literals.
+///
+/// This accepts exactly the decimal literals that the Rust reference
+/// lexes as floats, or as integers with a float suffix:
+///
+/// * `DEC_LITERAL . DEC_LITERAL SUFFIX?`, such as `1.5` or `1_000.0_f32`.
+/// * `DEC_LITERAL (. DEC_LITERAL)? EXPONENT SUFFIX?`, such as `1e5` or `2.5E-3`.
+/// * `DEC_LITERAL .`, such as `1.`, if not followed by `.`, `_` or an
+///   identifier character, since `1..2` is a range and `1.e5` is a field.
+/// * `DEC_LITERAL SUFFIX`, such as `1f64`.
+///
+/// A `DEC_LITERAL` is a digit followed by any digits or `_`, and the
+/// exponent is `e` or `E`, an optional sign, and digits or `_` with at
+/// least one digit. The suffix must be `f32` or `f64`, and is consumed
+/// but does not change the float type. Unlike [`parse_complete`], signs,
+/// a leading `.`, integers without a suffix, special values and exponents
+/// without digits are rejected.
+///
+/// Returns the parsed float and the number of bytes consumed, or `None`
+/// if the bytes do not start with a float literal. Parsing stops at the
+/// first byte which cannot be part of the literal, except an identifier
+/// character, which makes the literal invalid.
+///
+/// * `bytes`       - Buffer leading with the float literal.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_float_rust_strict;
+///
+/// assert_eq!(parse_float_rust_strict::<f64>(b"1_000.5e-1_f64;"), Some((100.05, 14)));
+/// assert_eq!(parse_float_rust_strict::<f64>(b"1."), Some((1.0, 2)));
+/// assert_eq!(parse_float_rust_strict::<f64>(b"1..2"), None);
+/// assert_eq!(parse_float_rust_strict::<f64>(b".5"), None);
+/// assert_eq!(parse_float_rust_strict::<f64>(b"-1.5"), None);
+/// ```
+///
+/// [`parse_complete`]: fn.parse_complete.html
+pub fn parse_float_rust_strict<F: Float>(bytes: &[u8]) -> Option<(F, usize)> {
+    if !bytes.first().map_or(false, u8::is_ascii_digit) {
+        return None;
+    }
+    let integer = &bytes[..count_rust_digits(bytes)];
+    let mut index = integer.len();
+    let mut fraction = &bytes[..0];
+    let mut is_float = false;
+
+    // Scan the fraction, which must start with a digit.
+    if bytes.get(index) == Some(&b'.') {
+        match bytes.get(index + 1) {
+            Some(c) if c.is_ascii_digit() => {
+                let start = index + 1;
+                fraction = &bytes[start..start + count_rust_digits(&bytes[start..])];
+                index = start + fraction.len();
+            },
+            Some(&c) if c == b'.' || is_rust_identifier(c) => return None,
+            _ => {
+                let float = parse_rust_float(integer, fraction, 0);
+                return Some((float, index + 1));
+            },
+        }
+        is_float = true;
+    }
+
+    // Scan the exponent, which must have at least 1 digit.
+    let mut exponent = 0;
+    if let Some(&b'e') | Some(&b'E') = bytes.get(index) {
+        let (is_positive, sign_count) = parse_sign(&bytes[index + 1..]);
+        let start = index + 1 + sign_count;
+        let digits = &bytes[start..start + count_rust_digits(&bytes[start..])];
+        if !digits.iter().any(u8::is_ascii_digit) {
+            return None;
+        }
+        exponent = parse_exponent(digits.iter().filter(|&&c| c != b'_'), is_positive);
+        index = start + digits.len();
+        is_float = true;
+    }
+
+    // Scan the suffix, which is required for integers.
+    let suffix_count = bytes[index..].iter().take_while(|&&c| is_rust_identifier(c)).count();
+    match &bytes[index..index + suffix_count] {
+        b"f32" | b"f64" => index += suffix_count,
+        b"" if is_float => (),
+        _ => return None,
+    }
+
+    Some((parse_rust_float(integer, fraction, exponent), index))
+}
+
+/// Parse a float from the digits of a Rust literal, which may contain `_`.
+#[inline]
+fn parse_rust_float<F: Float>(integer: &[u8], fraction: &[u8], exponent: i32) -> F {
+    let is_digit = |&&c: &&u8| c != b'_';
+    let leading = integer.iter().take_while(|&&c| c == b'0' || c == b'_').count();
+    let trailing = fraction.iter().rev().take_while(|&&c| c == b'0' || c == b'_').count();
+    let integer = integer[leading..].iter().filter(is_digit);
+    let fraction = fraction[..fraction.len() - trailing].iter().filter(is_digit);
+    parse_float(integer, fraction, exponent)
+}
+
+// STREAMING
+// ---------
+
+/// Progress of parsing a float from a buffer that may be incomplete.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParseProgress<F> {
+    /// The float could continue past the end of the buffer.
+    Incomplete,
+    /// The parsed float and the number of bytes consumed.
+    Complete(F, usize),
+    /// The buffer does not start with a valid float.
+    Invalid,
+}
+
+/// Check if the bytes are an exponent symbol and optional sign.
+#[inline]
+fn is_exponent_prefix(bytes: &[u8], options: &ParseOptions) -> bool {
+    match bytes.first() {
+        Some(&c) if options.is_exponent(c) => bytes.len() == 1 + parse_sign(&bytes[1..]).1,
+        _ => false,
+    }
+}
+
+/// Check if the integer digits, of `count` bytes, may continue after group separators.
+#[inline]
+fn is_group_prefix(bytes: &[u8], count: usize, options: &ParseOptions) -> bool {
+    match options.group_separator {
+        Some(separator) => {
+            let is_integer = bytes[..count].iter().all(|&c| c.is_ascii_digit() || c == separator);
+            let is_grouped = !options.strict_grouping || is_valid_grouping(bytes, separator, true);
+            is_integer && bytes[count..].iter().all(|&c| c == separator) && is_grouped
+        },
+        None => false,
+    }
+}
+
+/// Check if the bytes are a prefix of a valid float, after a failed parse.
+#[inline]
+fn is_partial_float(bytes: &[u8], options: &ParseOptions) -> bool {
+    if options.no_positive_mantissa_sign && bytes.first() == Some(&b'+') {
+        return false;
+    }
+    let sign_count = parse_sign(bytes).1;
+    let rest = &bytes[sign_count..];
+    if rest == [options.decimal_point] || is_special_prefix(bytes) {
+        return true;
+    }
+
+    // Check if the exponent digits are required, but not yet available.
+    let relaxed = ParseOptions {
+        require_exponent_digits: false,
+        ..*options
+    };
+    let spans = scan_float_with_options(bytes, &relaxed);
+    if spans.has_digits() && is_exponent_prefix(&bytes[spans.len..], options) {
+        return true;
+    }
+
+    // Check if the last group of digits is incomplete, with strict grouping.
+    match options.group_separator {
+        Some(separator) if options.strict_grouping => {
+            let integer_count = count_grouped_digits(rest, separator);
+            let is_integer = rest[integer_count..].iter().all(|&c| c == separator);
+            integer_count != 0 && is_integer && is_valid_grouping(rest, separator, true)
+        },
+        _ => false,
+    }
+}
+
+/// Check if a parsed float could continue past the end of the buffer.
+#[inline]
+fn is_continued_float(bytes: &[u8], count: usize, options: &ParseOptions) -> bool {
+    let sign_count = parse_sign(bytes).1;
+    let float = &bytes[sign_count..count];
+    let rest = &bytes[count..];
+    match is_continued_special(float, rest) {
+        // Special values, where only `inf` might continue into `infinity`.
+        Some(is_continued) => is_continued,
+        // Digits, a `.` or exponent digits might continue, or an exponent
+        // symbol and sign might be followed by exponent digits, or group
+        // separators might be followed by integer digits.
+        None => {
+            rest.is_empty()
+                || is_exponent_prefix(rest, options)
+                || is_group_prefix(&bytes[sign_count..], count - sign_count, options)
+        },
+    }
+}
+
+/// Parse a float from a buffer which may not contain the complete float.
+///
+/// This is identical to [`parse_complete`], except that if `is_final` is
+/// false, and the float could continue past the end of the buffer, such
+/// as trailing digits or an exponent symbol without digits, `Incomplete`
+/// is returned. If `is_final` is true, the buffer is assumed to contain
+/// all the remaining input, and `Incomplete` is never returned.
+///
+/// * `bytes`       - Buffer leading with the float.
+/// * `is_final`    - If no more input follows the buffer.
+///
+/// [`parse_complete`]: fn.parse_complete.html
+#[inline]
+pub fn parse_float_streaming<F: Float>(bytes: &[u8], is_final: bool) -> ParseProgress<F> {
+    parse_float_streaming_with_options(bytes, is_final, &ParseOptions::default())
+}
+
+/// Parse a float from a buffer which may be incomplete, validating the format.
+///
+/// This is identical to [`parse_float_streaming`], except the float is
+/// invalid if it violates any of the rules enabled in `options`.
+///
+/// * `bytes`       - Buffer leading with the float.
+/// * `is_final`    - If no more input follows the buffer.
+/// * `options`     - Validation rules for the float format.
+///
+/// [`parse_float_streaming`]: fn.parse_float_streaming.html
+pub fn parse_float_streaming_with_options<F: Float>(
+    bytes: &[u8],
+    is_final: bool,
+    options: &ParseOptions,
+) -> ParseProgress<F> {
+    match parse_complete_with_options::<F>(bytes, options) {
+        Some((float, count)) => {
+            if !is_final && is_continued_float(bytes, count, options) {
+                ParseProgress::Incomplete
+            } else {
+                ParseProgress::Complete(float, count)
+            }
+        },
+        None => {
+            if !is_final && is_partial_float(bytes, options) {
+                ParseProgress::Incomplete
+            } else {
+                ParseProgress::Invalid
+            }
+        },
+    }
+}
+
+/// Parse a float from a string, which must contain only the float.
+///
+/// This is a drop-in replacement for [`str::parse`] for floats, using
+/// the correctly-rounded algorithms in this crate. The format is the same
+/// as [`parse_complete`], and like [`str::parse`], whitespace is not
+/// skipped. An error is returned if the string does not start with
+/// a valid float, or if any bytes follow it, with the index of the
+/// first invalid byte.
+///
+/// * `string`      - String containing the float.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::parse_float_str;
+///
+/// assert_eq!(parse_float_str::<f64>("1.2345e2"), Ok(123.45));
+/// assert_eq!(parse_float_str::<f64>("1.2345e2,").unwrap_err().index, 8);
+/// assert_eq!(parse_float_str::<f64>("e5").unwrap_err().index, 0);
+/// ```
+///
+/// [`str::parse`]: https://doc.rust-lang.org/std/primitive.str.html#method.parse
+/// [`parse_complete`]: fn.parse_complete.html
+#[inline]
+pub fn parse_float_str<F: Float>(string: &str) -> Result<F, ParseFloatError> {
+    match parse_float_str_partial(string)? {
+        (float, count) if count == string.len() => Ok(float),
+        (_, count) => Err(ParseFloatError {
+            index: count,
+            kind: ParseFloatErrorKind::Invalid,
+        }),
+    }
+}
+
+/// Parse a float from the start of a string, permitting trailing bytes.
+///
+/// This is identical to [`parse_float_str`], except any bytes may follow
+/// the float. Returns the float and the number of bytes consumed, so the
+/// trailing bytes are `&string[count..]`.
+///
+/// * `string`      - String leading with the float.
+///
+/// [`parse_float_str`]: fn.parse_float_str.html
+#[inline]
+pub fn parse_float_str_partial<F: Float>(string: &str) -> Result<(F, usize), ParseFloatError> {
+    parse_complete(string.as_bytes()).ok_or(ParseFloatError {
+        index: 0,
+        kind: ParseFloatErrorKind::Invalid,
+    })
+}
+
+/// Parse a float from a string, rejecting values out of the float's range.
+///
+/// This is identical to [`parse_float_str`], except a float with
+/// significant digits which overflows to infinity returns an `Overflow`
+/// error, and a float with non-zero significant digits which underflows
+/// to zero returns an `Underflow` error. Both errors are at index 0.
+/// Literal infinities, such as `inf`, literal zeros, and denormal floats
+/// are accepted.
+///
+/// * `string`      - String containing the float.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{parse_float_str_checked, ParseFloatErrorKind};
+/// use std::f64;
+///
+/// assert_eq!(parse_float_str_checked::<f64>("inf"), Ok(f64::INFINITY));
+/// let error = parse_float_str_checked::<f64>("1e400").unwrap_err();
+/// assert_eq!(error.kind, ParseFloatErrorKind::Overflow);
+/// ```
+///
+/// [`parse_float_str`]: fn.parse_float_str.html
+pub fn parse_float_str_checked<F: Float>(string: &str) -> Result<F, ParseFloatError> {
+    let bytes = string.as_bytes();
+    let spans = scan_float(bytes);
+    let float = match spans.len {
+        // Special values have no digits, and are never out of range.
+        0 => return parse_float_str(string),
+        len if len == bytes.len() => parse_spans::<F>(bytes, &spans, &ParseOptions::default()),
+        index => {
+            return Err(ParseFloatError {
+                index,
+                kind: ParseFloatErrorKind::Invalid,
+            })
+        },
+    };
+
+    let is_nonzero = |range: Option<Range<usize>>| {
+        range.map_or(false, |range| bytes[range].iter().any(|&c| c != b'0'))
+    };
+    let is_zero = float.to_bits() & !F::SIGN_MASK == 0;
+    if float.is_inf() {
+        Err(ParseFloatError {
+            index: 0,
+            kind: ParseFloatErrorKind::Overflow,
+        })
+    } else if is_zero && (is_nonzero(spans.integer) || is_nonzero(spans.fraction)) {
+        Err(ParseFloatError {
+            index: 0,
+            kind: ParseFloatErrorKind::Underflow,
+        })
+    } else {
+        Ok(float)
+    }
+}
+
+/// Parse float from the tokenized float components, validating the digits.
+///
+/// This is identical to [`parse_float_parts`], except every byte in the
+/// integer and fraction must be a digit. Otherwise, an `InvalidDigit`
+/// error is returned, with the index of the first invalid byte in the
+/// integer digits followed by the fraction digits.
+///
+/// * `parts`       - Tokenized float components.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{try_parse_float_parts, NumberParts, ParseFloatErrorKind};
+///
+/// let mut parts = NumberParts {
+///     is_positive: true,
+///     integer: b"12",
+///     fraction: b"5",
+///     exponent: 0,
+/// };
+/// assert_eq!(try_parse_float_parts::<f64>(parts), Ok(12.5));
+///
+/// parts.fraction = b"5x";
+/// let error = try_parse_float_parts::<f64>(parts).unwrap_err();
+/// assert_eq!(error.kind, ParseFloatErrorKind::InvalidDigit);
+/// assert_eq!(error.index, 3);
+/// ```
+///
+/// [`parse_float_parts`]: ../parse/fn.parse_float_parts.html
+#[inline]
+pub fn try_parse_float_parts<F: Float>(parts: NumberParts) -> Result<F, ParseFloatError> {
+    let mut digits = parts.integer.iter().chain(parts.fraction.iter());
+    match digits.position(|c| !c.is_ascii_digit()) {
+        Some(index) => Err(ParseFloatError {
+            index,
+            kind: ParseFloatErrorKind::InvalidDigit,
+        }),
+        None => Ok(parse_float_parts(parts)),
+    }
+}
+
+/// Parse float from extracted float components, as chars, validating the digits.
+///
+/// This is identical to [`parse_float_chars`], except every char in the
+/// integer and fraction must be an ASCII digit. Otherwise, an
+/// `InvalidDigit` error is returned, with the index of the first invalid
+/// char in the integer digits followed by the fraction digits.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{try_parse_float_chars, ParseFloatErrorKind};
+///
+/// assert_eq!(try_parse_float_chars::<f64, _, _>("12".chars(), "5".chars(), 0), Ok(12.5));
+///
+/// let error = try_parse_float_chars::<f64, _, _>("1٢".chars(), "".chars(), 0).unwrap_err();
+/// assert_eq!(error.kind, ParseFloatErrorKind::InvalidDigit);
+/// assert_eq!(error.index, 1);
+/// ```
+///
+/// [`parse_float_chars`]: ../parse/fn.parse_float_chars.html
+#[inline]
+pub fn try_parse_float_chars<F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> Result<F, ParseFloatError>
+where
+    F: Float,
+    Iter1: Iterator<Item = char> + Clone,
+    Iter2: Iterator<Item = char> + Clone,
+{
+    let mut digits = integer.clone().chain(fraction.clone());
+    match digits.position(|c| !c.is_ascii_digit()) {
+        Some(index) => Err(ParseFloatError {
+            index,
+            kind: ParseFloatErrorKind::InvalidDigit,
+        }),
+        None => Ok(parse_float_chars(integer, fraction, exponent)),
+    }
+}
+
+// ITERATOR
+// --------
+
+/// Kind of error from parsing a float.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFloatErrorKind {
+    /// The bytes are not a valid float.
+    Invalid,
+    /// A significant digit is not a valid digit.
+    InvalidDigit,
+    /// The significant digits overflowed to infinity.
+    Overflow,
+    /// The non-zero significant digits underflowed to zero.
+    Underflow,
+}
+
+/// Error from parsing a float with [`FloatIter`] or [`parse_float_str`].
+///
+/// This only requires `core`, and implements `std::error::Error`
+/// with the `std` feature.
+///
+/// [`FloatIter`]: struct.FloatIter.html
+/// [`parse_float_str`]: fn.parse_float_str.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFloatError {
+    /// Index of the first byte which could not be parsed, or of the start
+    /// of the float if it is out of range. For an invalid digit, this is
+    /// the index in the integer digits followed by the fraction digits.
+    pub index: usize,
+    /// Kind of error.
+    pub kind: ParseFloatErrorKind,
+}
+
+impl fmt::Display for ParseFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ParseFloatErrorKind::Invalid => write!(f, "invalid float at index {}", self.index),
+            ParseFloatErrorKind::InvalidDigit => write!(f, "invalid digit at index {}", self.index),
+            ParseFloatErrorKind::Overflow => write!(f, "float overflow at index {}", self.index),
+            ParseFloatErrorKind::Underflow => write!(f, "float underflow at index {}", self.index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFloatError {
+}
+
+/// Iterator over delimited floats in a buffer.
+///
+/// Each float is parsed with [`parse_complete_with_options`], and must be
+/// followed by the delimiter or the end of the buffer. ASCII whitespace
+/// around each float is skipped, so if the delimiter is whitespace, any
+/// amount of whitespace separates floats.
+///
+/// An invalid or missing float, including an empty field between two
+/// delimiters or after a trailing delimiter, yields an error, after
+/// which the iterator is exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::FloatIter;
+///
+/// let floats: Vec<f64> = FloatIter::new(b"1.5, -2e3,4", b',').map(Result::unwrap).collect();
+/// assert_eq!(floats, [1.5, -2000.0, 4.0]);
+/// ```
+///
+/// [`parse_complete_with_options`]: fn.parse_complete_with_options.html
+#[derive(Clone, Debug)]
+pub struct FloatIter<'a, F> {
+    bytes: &'a [u8],
+    index: usize,
+    delimiter: u8,
+    options: ParseOptions,
+    /// If the next float is separated from the previous float.
+    is_separated: bool,
+    /// If a float must follow, after a delimiter that isn't whitespace.
+    is_pending: bool,
+    is_done: bool,
+    float: PhantomData<F>,
+}
+
+impl<'a, F: Float> FloatIter<'a, F> {
+    /// Create an iterator over floats separated by `delimiter`.
+    ///
+    /// * `bytes`       - Buffer containing the delimited floats.
+    /// * `delimiter`   - Character separating each float.
+    #[inline]
+    pub fn new(bytes: &'a [u8], delimiter: u8) -> Self {
+        Self::with_options(bytes, delimiter, ParseOptions::default())
+    }
+
+    /// Create an iterator over floats separated by `delimiter`, validating the format.
+    ///
+    /// * `bytes`       - Buffer containing the delimited floats.
+    /// * `delimiter`   - Character separating each float.
+    /// * `options`     - Validation rules for the float format.
+    #[inline]
+    pub fn with_options(bytes: &'a [u8], delimiter: u8, options: ParseOptions) -> Self {
+        Self {
+            bytes,
+            index: 0,
+            delimiter,
+            options,
+            is_separated: true,
+            is_pending: false,
+            is_done: false,
+            float: PhantomData,
+        }
+    }
+
+    /// Get the index of the next byte to parse.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Skip ASCII whitespace, and get if any whitespace was skipped.
+    #[inline]
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.bytes[self.index..];
+        let count = rest.iter().take_while(|c| c.is_ascii_whitespace()).count();
+        self.index += count;
+        count != 0
+    }
+
+    /// Stop iterating, and return an error at the current index.
+    #[inline]
+    fn error(&mut self) -> Option<Result<F, ParseFloatError>> {
+        self.is_done = true;
+        Some(Err(ParseFloatError {
+            index: self.index,
+            kind: ParseFloatErrorKind::Invalid,
+        }))
+    }
+}
+
+impl<'a, F: Float> Iterator for FloatIter<'a, F> {
+    type Item = Result<F, ParseFloatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        self.skip_whitespace();
+        if self.index == self.bytes.len() {
+            self.is_done = true;
+            return match self.is_pending {
+                true => self.error(),
+                false => None,
+            };
+        } else if !self.is_separated {
+            return self.error();
+        }
+
+        let (float, count) =
+            match parse_complete_with_options::<F>(&self.bytes[self.index..], &self.options) {
+                Some(value) => value,
+                None => return self.error(),
+            };
+        self.index += count;
+
+        // Consume the delimiter, if present.
+        let is_whitespace = self.skip_whitespace();
+        if self.bytes.get(self.index) == Some(&self.delimiter) {
+            self.index += 1;
+            self.is_separated = true;
+            self.is_pending = !self.delimiter.is_ascii_whitespace();
+        } else {
+            self.is_separated = is_whitespace && self.delimiter.is_ascii_whitespace();
+            self.is_pending = false;
+        }
+
+        Some(Ok(float))
+    }
+}
+
+impl<'a, F: Float> iter::FusedIterator for FloatIter<'a, F> {
+}
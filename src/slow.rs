@@ -6,10 +6,11 @@
 
 #![doc(hidden)]
 
-use crate::bigint::{Bigint, Limb, LIMB_BITS};
+use crate::bigint::{large_mul, Bigint, Limb, LIMB_BITS};
 use crate::extended_float::{extended_to_float, ExtendedFloat};
 use crate::num::{int_pow_fast_path, FastPathRadix, Float};
 use crate::number::Number;
+use crate::parse::{into_i32, parse_float, skip_leading_zeros};
 use crate::rounding::{round, round_down, round_nearest_tie_even};
 use core::cmp;
 
@@ -52,26 +53,249 @@ where
     // starting with the integer digits, and the float format has been
     // correctly validated.
     let sci_exp = scientific_exponent(&num);
+    digit_comp::<F, _, _>(fp, integer, fraction, sci_exp, None)
+}
 
+/// Parse the significant digits and biased, binary exponent of a float, reusing powers.
+///
+/// This is identical to [`slow`], except the power of 5 used to scale
+/// the digits is cached in `scaler`, and reused if the next float has
+/// digits at the same exponent.
+///
+/// [`slow`]: fn.slow.html
+#[inline]
+pub fn slow_with_scaler<'a, F, Iter1, Iter2>(
+    num: Number,
+    fp: ExtendedFloat,
+    integer: Iter1,
+    fraction: Iter2,
+    scaler: &mut DecimalScaler,
+) -> ExtendedFloat
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    debug_assert!(fp.mant & (1 << 63) != 0);
+
+    let sci_exp = scientific_exponent(&num);
+    digit_comp::<F, _, _>(fp, integer, fraction, sci_exp, Some(scaler))
+}
+
+/// Compare the significant digits to the halfway point of `b`, or `b+h`.
+///
+/// This is the slow path algorithm, accepting an approximation of the
+/// float rather than the moderate path results, so it can be directly
+/// compared against a reference, arbitrary-precision implementation.
+///
+/// * `b`           - Approximation of the float, rounded-down.
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Preconditions
+/// 1. `b` must be finite, positive or zero, and be the exact value of the
+///    digits rounded-down, that is, `b <= value < b + ulp(b)`.
+/// 2. The integer should not have leading zeros, for performance.
+/// 3. At least one integer or fraction digit must be non-zero.
+/// 4. All bytes in `integer` and `fraction` should be valid digits,
+///    in the range [`b'0', b'9'].
+pub fn bhcomp<'a, F, Iter1, Iter2>(b: F, integer: Iter1, fraction: Iter2, exponent: i32) -> F
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let sci_exp = scientific_exponent_digits(integer.clone(), fraction.clone(), exponent);
+    let fp = digit_comp::<F, _, _>(normalized_b(b), integer, fraction, sci_exp, None);
+    extended_to_float::<F>(fp)
+}
+
+/// Get the big integers compared by the slow path, for the digits and `b+h`.
+///
+/// Returns the real digits and the theoretical digits of `b+h`, scaled
+/// to the same order as in [`compare_digits`], so comparing them
+/// determines whether `b` or `b+u` is the correctly-rounded float. With
+/// the `diagnostics` feature, both can be rendered as exact decimal
+/// integers, to inspect rounding decisions.
+///
+/// * `b`           - Approximation of the float, rounded-down.
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Preconditions
+///
+/// The preconditions are identical to [`bhcomp`].
+///
+/// [`compare_digits`]: fn.compare_digits.html
+/// [`bhcomp`]: fn.bhcomp.html
+#[cfg(feature = "diagnostics")]
+pub fn bhcomp_digits<'a, F, Iter1, Iter2>(
+    b: F,
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (Bigint, Bigint)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let sci_exp = scientific_exponent_digits(integer.clone(), fraction.clone(), exponent);
+    let (bigmant, digits) = parse_mantissa_with_capacity::<F, _, _>(integer, fraction, sci_exp);
+    let real_exp = sci_exp + 1 - digits as i32;
+    scale_digits(bigmant, real_exp, bh(b), None)
+}
+
+/// Compare the significant digits to the exact value of a float.
+///
+/// Returns `Equal` only if the digits are exactly the float. Only the
+/// first `F::MAX_DIGITS` significant digits are compared, and any
+/// non-zero digits after them are rounded-up into the last digit, so
+/// inputs with more significant digits cannot be exactly compared.
+///
+/// * `float`       - Float to compare to the digits.
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Preconditions
+/// 1. `float` must be finite, positive and non-zero, and be the digits
+///    rounded to the nearest float.
+/// 2. The integer should not have leading zeros, for performance.
+/// 3. At least one integer or fraction digit must be non-zero.
+/// 4. All bytes in `integer` and `fraction` should be valid digits,
+///    in the range [`b'0', b'9'].
+pub fn compare_float<'a, F, Iter1, Iter2>(
+    float: F,
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> cmp::Ordering
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let sci_exp = scientific_exponent_digits(integer.clone(), fraction.clone(), exponent);
+    let (bigmant, digits) = parse_mantissa_with_capacity::<F, _, _>(integer, fraction, sci_exp);
+    let real_exp = sci_exp + 1 - digits as i32;
+    compare_digits(bigmant, real_exp, b(float))
+}
+
+/// Find the adjacent floats bracketing the significant digits, `b` and `b+u`.
+///
+/// Returns `b`, `b+u`, and the ordering of the significant digits relative
+/// to the halfway point, `b+h`: `Less` if `b` is closer, `Greater` if `b+u`
+/// is closer, and `Equal` if the digits are exactly halfway. This is
+/// useful to audit rounding decisions, since the correctly-rounded float
+/// is `b`, `b+u`, or if exactly halfway, whichever has an even mantissa.
+///
+/// If the digits are larger than the largest finite float, `b` is the
+/// largest finite float and `b+u` is infinite.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `exponent`    - Parsed, 32-bit exponent.
+///
+/// # Preconditions
+/// 1. The integer should not have leading zeros, for performance.
+/// 2. The fraction should not have trailing zeros.
+/// 3. At least one integer or fraction digit must be non-zero.
+/// 4. All bytes in `integer` and `fraction` should be valid digits,
+///    in the range [`b'0', b'9'].
+pub fn bracketing_floats<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> (F, F, cmp::Ordering)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let max_finite = F::from_bits(F::EXPONENT_MASK - 1);
+    let infinity = F::from_bits(F::INFINITY_BITS);
+
+    // Values outside the range of powers of 10 are far from any halfway
+    // point, and would overflow the big integers.
+    let sci_exp = scientific_exponent_digits(integer.clone(), fraction.clone(), exponent);
+    if sci_exp > F::LARGEST_POWER_OF_TEN {
+        return (max_finite, infinity, cmp::Ordering::Greater);
+    } else if sci_exp < F::SMALLEST_POWER_OF_TEN {
+        return (F::from_bits(0), F::from_bits(1), cmp::Ordering::Less);
+    }
+
+    // The correctly-rounded float is either `b` or `b+u`.
+    let float = parse_float::<F, _, _>(integer.clone(), fraction.clone(), exponent);
+    let (bigmant, digits) = parse_mantissa_with_capacity::<F, _, _>(integer, fraction, sci_exp);
+    let real_exp = sci_exp + 1 - digits as i32;
+    // A literal 0 cannot be above the digits, which must be non-zero.
+    let bits = float.to_bits();
+    let lower = if bits == infinity.to_bits() {
+        max_finite
+    } else if bits != 0
+        && compare_digits(bigmant.clone(), real_exp, b(float)) == cmp::Ordering::Less
+    {
+        F::from_bits(bits - 1)
+    } else {
+        float
+    };
+    let upper = F::from_bits(lower.to_bits() + 1);
+
+    (lower, upper, compare_digits(bigmant, real_exp, bh(lower)))
+}
+
+/// Compare the real digits to the theoretical digits, from the scientific exponent.
+#[inline]
+fn digit_comp<'a, F, Iter1, Iter2>(
+    fp: ExtendedFloat,
+    integer: Iter1,
+    fraction: Iter2,
+    sci_exp: i32,
+    scaler: Option<&mut DecimalScaler>,
+) -> ExtendedFloat
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
     // We have 2 major algorithms we use for this:
     //  1. An algorithm with a finite number of digits and a positive exponent.
     //  2. An algorithm with a finite number of digits and a negative exponent.
-    let (bigmant, digits) = parse_mantissa(integer, fraction, F::MAX_DIGITS);
+    let (bigmant, digits) = parse_mantissa_with_capacity::<F, _, _>(integer, fraction, sci_exp);
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
-        positive_digit_comp::<F>(bigmant, exponent)
+        scaled_positive_digit_comp::<F>(bigmant, exponent, scaler)
     } else {
-        negative_digit_comp::<F>(bigmant, fp, exponent)
+        scaled_negative_digit_comp::<F>(bigmant, fp, exponent, scaler)
     }
 }
 
 /// Generate the significant digits with a positive exponent relative to mantissa.
-pub fn positive_digit_comp<F: Float>(mut bigmant: Bigint, exponent: i32) -> ExtendedFloat {
+#[inline]
+pub fn positive_digit_comp<F: Float>(bigmant: Bigint, exponent: i32) -> ExtendedFloat {
+    scaled_positive_digit_comp::<F>(bigmant, exponent, None)
+}
+
+/// Generate the significant digits with a positive exponent, optionally reusing powers.
+fn scaled_positive_digit_comp<F: Float>(
+    mut bigmant: Bigint,
+    exponent: i32,
+    scaler: Option<&mut DecimalScaler>,
+) -> ExtendedFloat {
     // Simple, we just need to multiply by the power of the radix.
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    bigmant.pow(10, exponent as u32).unwrap();
+    match scaler {
+        Some(scaler) => {
+            scaler.mul_pow5(&mut bigmant, exponent as u32).unwrap();
+            bigmant.shl(exponent as usize).unwrap();
+        },
+        None => bigmant.pow(10, exponent as u32).unwrap(),
+    }
 
     // Get the exact representation of the float from the big integer.
     // hi64 checks **all** the remaining bits after the mantissa,
@@ -112,18 +336,28 @@ pub fn positive_digit_comp<F: Float>(mut bigmant: Bigint, exponent: i32) -> Exte
 ///
 /// This allows us to compare both floats using integers efficiently
 /// without any loss of precision.
-#[allow(clippy::comparison_chain)]
+#[inline]
 pub fn negative_digit_comp<F: Float>(
+    bigmant: Bigint,
+    fp: ExtendedFloat,
+    exponent: i32,
+) -> ExtendedFloat {
+    scaled_negative_digit_comp::<F>(bigmant, fp, exponent, None)
+}
+
+/// Generate the significant digits with a negative exponent, optionally reusing powers.
+fn scaled_negative_digit_comp<F: Float>(
     bigmant: Bigint,
     mut fp: ExtendedFloat,
     exponent: i32,
+    scaler: Option<&mut DecimalScaler>,
 ) -> ExtendedFloat {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0);
 
     // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = bigmant;
+    let real_digits = bigmant;
     let real_exp = exponent;
     debug_assert!(real_exp < 0);
 
@@ -132,18 +366,59 @@ pub fn negative_digit_comp<F: Float>(
     round::<F, _>(&mut b, round_down);
     let b = extended_to_float::<F>(b);
 
-    // Get the significant digits and the binary exponent for `b+h`.
-    let theor = bh(b);
-    let mut theor_digits = Bigint::from_u64(theor.mant);
+    // Compare the real digits to the significant digits and the binary
+    // exponent for `b+h`.
+    let (real_digits, theor_digits) = scale_digits(real_digits, real_exp, bh(b), scaler);
+    let ord = real_digits.data.cmp(&theor_digits.data);
+
+    // Round nearest, tie even, from our comparison.
+    round::<F, _>(&mut fp, |f, s| {
+        round_nearest_tie_even(f, s, |is_odd, _, _| {
+            // Can ignore `is_halfway` and `is_above`, since those were
+            // calculates using less significant digits.
+            match ord {
+                cmp::Ordering::Greater => true,
+                cmp::Ordering::Less => false,
+                cmp::Ordering::Equal if is_odd => true,
+                cmp::Ordering::Equal => false,
+            }
+        });
+    });
+    fp
+}
+
+/// Compare the real digits, `m1 * 10^N1`, to the theoretical digits, `m2 * 2^N2`.
+///
+/// Both values are scaled to big integers of the same order, so the
+/// comparison is exact. See [`negative_digit_comp`] for the algorithm.
+///
+/// [`negative_digit_comp`]: fn.negative_digit_comp.html
+pub fn compare_digits(real_digits: Bigint, real_exp: i32, theor: ExtendedFloat) -> cmp::Ordering {
+    let (real_digits, theor_digits) = scale_digits(real_digits, real_exp, theor, None);
+    real_digits.data.cmp(&theor_digits.data)
+}
+
+/// Scale the real digits, `m1 * 10^N1`, and theoretical digits, `m2 * 2^N2`, to the same order.
+#[inline]
+#[allow(clippy::comparison_chain)]
+fn scale_digits(
+    mut real_digits: Bigint,
+    real_exp: i32,
+    theor: ExtendedFloat,
+    scaler: Option<&mut DecimalScaler>,
+) -> (Bigint, Bigint) {
+    // Both digits are scaled to the same order, so need the same capacity.
+    let bits = real_digits.data.capacity() * LIMB_BITS;
+    let mut theor_digits = Bigint::from_u64_with_capacity(theor.mant, bits);
     let theor_exp = theor.exp;
 
-    // We need to scale the real digits and `b+h` digits to be the same
+    // We need to scale the real digits and theoretical digits to be the same
     // order. We currently have `real_exp`, in `radix`, that needs to be
-    // shifted to `theor_digits` (since it is negative), and `theor_exp`
-    // to either `theor_digits` or `real_digits` as a power of 2 (since it
-    // may be positive or negative). Try to remove as many powers of 2
-    // as possible. All values are relative to `theor_digits`, that is,
-    // reflect the power you need to multiply `theor_digits` by.
+    // shifted to `theor_digits` if it is negative, otherwise `real_digits`,
+    // and `theor_exp` to either `theor_digits` or `real_digits` as a power
+    // of 2 (since it may be positive or negative). Try to remove as many
+    // powers of 2 as possible. All values are relative to `theor_digits`,
+    // that is, reflect the power you need to multiply `theor_digits` by.
     //
     // Both are on opposite-sides of equation, can factor out a
     // power of two.
@@ -152,10 +427,17 @@ pub fn negative_digit_comp<F: Float>(
     // Example: 10^-10, 2^-15   -> (-5, 10, 0)
     // Example: 10^-10, 2^-5    -> ( 5, 10, 0)
     // Example: 10^-10, 2^5     -> (15, 10, 0)
+    // Example: 10^10, 2^15     -> ( 5, 0, 10)
     let binary_exp = theor_exp - real_exp;
-    let halfradix_exp = -real_exp;
-    if halfradix_exp != 0 {
-        theor_digits.pow(5, halfradix_exp as u32).unwrap();
+    if real_exp != 0 {
+        let (digits, exp) = match real_exp < 0 {
+            true => (&mut theor_digits, (-real_exp) as u32),
+            false => (&mut real_digits, real_exp as u32),
+        };
+        match scaler {
+            Some(scaler) => scaler.mul_pow5(digits, exp).unwrap(),
+            None => digits.pow(5, exp).unwrap(),
+        }
     }
     if binary_exp > 0 {
         theor_digits.pow(2, binary_exp as u32).unwrap();
@@ -163,21 +445,80 @@ pub fn negative_digit_comp<F: Float>(
         real_digits.pow(2, (-binary_exp) as u32).unwrap();
     }
 
-    // Compare our theoretical and real digits and round nearest, tie even.
-    let ord = real_digits.data.cmp(&theor_digits.data);
-    round::<F, _>(&mut fp, |f, s| {
-        round_nearest_tie_even(f, s, |is_odd, _, _| {
-            // Can ignore `is_halfway` and `is_above`, since those were
-            // calculates using less significant digits.
-            match ord {
-                cmp::Ordering::Greater => true,
-                cmp::Ordering::Less => false,
-                cmp::Ordering::Equal if is_odd => true,
-                cmp::Ordering::Equal => false,
-            }
-        });
-    });
-    fp
+    (real_digits, theor_digits)
+}
+
+// SCALER
+// ------
+
+/// Cache of the power of 5 used to scale the digits in the slow path.
+///
+/// Parsing many floats with the same decimal exponent and number of
+/// digits, such as a column of fixed-precision values, repeatedly scales
+/// the digits by the same power of 5 in the slow path. The scaler keeps
+/// the last power, so consecutive floats at the same exponent only need
+/// a single multiplication. Only a single power is kept, to minimize
+/// the memory used without the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::{parse_float_with_scaler, DecimalScaler};
+///
+/// // Floats exactly halfway between `1.0 + n * 2^-52` and the next float.
+/// let column: [&[u8]; 3] = [
+///     b"00000000000000011102230246251565404236316680908203125",
+///     b"00000000000000033306690738754696212708950042724609375",
+///     b"00000000000000055511151231257827021181583404541015625",
+/// ];
+/// let mut scaler = DecimalScaler::new();
+/// let floats: Vec<f64> = column
+///     .iter()
+///     .map(|fraction| parse_float_with_scaler(b"1".iter(), fraction.iter(), 0, &mut scaler))
+///     .collect();
+/// # #[cfg(not(feature = "no_slow_path"))]
+/// assert_eq!(floats, [1.0, 1.0000000000000004, 1.0000000000000004]);
+/// # #[cfg(not(feature = "no_slow_path"))]
+/// assert_eq!(scaler.exponent(), Some(53));
+/// ```
+#[derive(Clone, Default)]
+pub struct DecimalScaler {
+    /// The exponent and value of the last power of 5.
+    power: Option<(u32, Bigint)>,
+}
+
+impl DecimalScaler {
+    /// Create a scaler without any cached power.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the exponent of the cached power of 5, if any.
+    #[inline]
+    pub fn exponent(&self) -> Option<u32> {
+        self.power.as_ref().map(|&(exp, _)| exp)
+    }
+
+    /// Multiply and assign by `5^exp`, reusing the cached power if possible.
+    ///
+    /// The result must have at most `BIGINT_BITS` bits, otherwise,
+    /// this returns `None`, as by [`Bigint::mul_pow5`].
+    ///
+    /// [`Bigint::mul_pow5`]: ../bigint/struct.Bigint.html#method.mul_pow5
+    pub fn mul_pow5(&mut self, x: &mut Bigint, exp: u32) -> Option<()> {
+        if self.exponent() != Some(exp) {
+            let mut power = Bigint::from_u64(1);
+            power.mul_pow5(exp)?;
+            self.power = Some((exp, power));
+        }
+        if let Some((_, ref power)) = self.power {
+            // Each limb of `x` multiplies the entire power, and `x` is
+            // typically much smaller than the power.
+            large_mul(&mut x.data, &power.data)?;
+        }
+        Some(())
+    }
 }
 
 /// Add a digit to the temporary value.
@@ -261,16 +602,65 @@ macro_rules! round_up_nonzero {
 /// Parse the full mantissa into a big integer.
 ///
 /// Returns the parsed mantissa and the number of digits in the mantissa.
-/// The max digits is the maximum number of digits plus one.
-pub fn parse_mantissa<'a, Iter1, Iter2>(
-    mut integer: Iter1,
+/// The max digits is the maximum number of digits plus one. The big
+/// integer has space for the slow path algorithms for the float `F`.
+pub fn parse_mantissa<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    max_digits: usize,
+) -> (Bigint, usize)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    parse_mantissa_impl(integer, fraction, max_digits, F::BIGINT_BITS)
+}
+
+/// Parse up to `F::MAX_DIGITS` of the mantissa into a big integer with space for the slow path.
+///
+/// The capacity depends on the number of digits, which is only known
+/// after parsing them. The big integer starts with the capacity for the
+/// lower bound of the iterators' lengths, which is exact for slices,
+/// and then reserves the rest for the digits parsed, so the digits are
+/// never traversed twice.
+///
+/// * `integer`     - Cloneable, forward iterator over integer digits.
+/// * `fraction`    - Cloneable, forward iterator over fraction digits.
+/// * `sci_exp`     - Scientific exponent of the digits.
+#[inline]
+fn parse_mantissa_with_capacity<'a, F, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    sci_exp: i32,
+) -> (Bigint, usize)
+where
+    F: Float,
+    Iter1: Iterator<Item = &'a u8> + Clone,
+    Iter2: Iterator<Item = &'a u8> + Clone,
+{
+    let length = integer.size_hint().0.saturating_add(fraction.size_hint().0);
+    let bits = bigint_bits::<F>(length, sci_exp);
+    let (mut bigmant, digits) = parse_mantissa_impl(integer, fraction, F::MAX_DIGITS, bits);
+    bigmant.reserve(bigint_bits::<F>(digits, sci_exp));
+    (bigmant, digits)
+}
+
+/// Parse the full mantissa into a big integer with space for `bits`.
+#[inline]
+fn parse_mantissa_impl<'a, Iter1, Iter2>(
+    integer: Iter1,
     mut fraction: Iter2,
     max_digits: usize,
+    bits: usize,
 ) -> (Bigint, usize)
 where
     Iter1: Iterator<Item = &'a u8> + Clone,
     Iter2: Iterator<Item = &'a u8> + Clone,
 {
+    // Leading zeros would otherwise count towards the maximum digits.
+    let mut integer = skip_leading_zeros(integer);
+
     // Iteratively process all the data in the mantissa.
     // We do this via small, intermediate values which once we reach
     // the maximum number of digits we can process without overflow,
@@ -278,7 +668,7 @@ where
     let mut counter: usize = 0;
     let mut count: usize = 0;
     let mut value: Limb = 0;
-    let mut result = Bigint::new();
+    let mut result = Bigint::with_capacity(bits);
 
     // Now use our pre-computed small powers iteratively.
     // This is calculated as `⌊log(2^BITS - 1, 10)⌋`.
@@ -380,7 +770,75 @@ pub fn scientific_exponent(num: &Number) -> i32 {
         mantissa /= 10;
         exponent += 1;
     }
-    exponent as i32
+    exponent
+}
+
+/// Calculate the scientific exponent from the integer and fraction digits.
+#[inline]
+pub fn scientific_exponent_digits<'a, Iter1, Iter2>(
+    integer: Iter1,
+    fraction: Iter2,
+    exponent: i32,
+) -> i32
+where
+    Iter1: Iterator<Item = &'a u8>,
+    Iter2: Iterator<Item = &'a u8>,
+{
+    let integer_count = skip_leading_zeros(integer).count();
+    if integer_count != 0 {
+        exponent.saturating_add(into_i32(integer_count - 1))
+    } else {
+        let zeros = fraction.take_while(|&&c| c == b'0').count();
+        exponent.saturating_sub(into_i32(zeros)).saturating_sub(1)
+    }
+}
+
+/// Estimate the bits required by the big integers in the slow path.
+///
+/// The slow path compares the digits to `b+h`, scaling both to big
+/// integers of the same order, so the capacity can be reserved once
+/// rather than growing during the scaling. With `N` digits and a scientific
+/// exponent of `E`, this is the largest of the digits, `N * log2(10)`
+/// bits, the scaled digits for a positive exponent, `(E + 1) * log2(10)`
+/// bits, and the scaled `b+h` for a negative exponent, which needs
+/// `(N - E - 1) * log2(5)` bits more than its mantissa. The result is
+/// never more than `F::BIGINT_BITS`.
+///
+/// * `digits`      - Number of significant digits parsed.
+/// * `sci_exp`     - Scientific exponent of the digits.
+#[inline]
+pub fn bigint_bits<F: Float>(digits: usize, sci_exp: i32) -> usize {
+    // Add a limb for the carries and the bits shifted into the scaled digits.
+    cmp::min(scaled_bits::<F>(digits, sci_exp) + LIMB_BITS, F::BIGINT_BITS)
+}
+
+/// Get an upper bound on the number of limbs in the big integers for the slow path.
+///
+/// This is the number of limbs reserved from [`bigint_bits`] for
+/// `sig_digits` significant digits and a scientific exponent of `exponent`,
+/// but is not clamped to `F::BIGINT_BITS`. The slow path is only used
+/// for `F::MAX_DIGITS + 1` digits or less, and exponents of at least
+/// `F::SMALLEST_POWER_OF_TEN`, which is the worst case for the
+/// stack-allocated big integers.
+///
+/// [`bigint_bits`]: fn.bigint_bits.html
+#[inline]
+pub fn slow_path_limb_count<F: Float>(sig_digits: usize, exponent: i32) -> usize {
+    let bits = scaled_bits::<F>(sig_digits, exponent);
+    (bits + LIMB_BITS - 1) / LIMB_BITS + 1
+}
+
+/// Calculate the bits required by the scaled digits, without any carries.
+#[inline]
+fn scaled_bits<F: Float>(digits: usize, sci_exp: i32) -> usize {
+    let digits = cmp::min(digits, F::MAX_DIGITS + 1) as i64;
+    let sci_exp = sci_exp as i64;
+
+    // Use `10/3 > log2(10)` and `7/3 > log2(5)`, and round up.
+    let digit_bits = (10 * digits + 2) / 3;
+    let positive_bits = (10 * (sci_exp + 1) + 2) / 3;
+    let negative_bits = 64 + (7 * (digits - sci_exp - 1) + 2) / 3;
+    cmp::max(digit_bits, cmp::max(positive_bits, negative_bits)) as usize
 }
 
 /// Calculate `b` from a a representation of `b` as a float.
@@ -392,6 +850,24 @@ pub fn b<F: Float>(float: F) -> ExtendedFloat {
     }
 }
 
+/// Calculate the normalized, extended-precision representation of `b`.
+///
+/// This has the same, biased exponent as the moderate path algorithms.
+#[inline]
+pub fn normalized_b<F: Float>(float: F) -> ExtendedFloat {
+    let mut fp = ExtendedFloat::from_float(float);
+    if fp.mant == 0 {
+        // Literal 0, use the halfway point to the smallest denormal,
+        // which will round-down to 0.
+        return ExtendedFloat {
+            mant: 1 << 63,
+            exp: F::DENORMAL_EXPONENT + F::EXPONENT_BIAS - 64,
+        };
+    }
+    fp.exp += F::EXPONENT_BIAS;
+    fp
+}
+
 /// Calculate `b+h` from a a representation of `b` as a float.
 #[inline]
 pub fn bh<F: Float>(float: F) -> ExtendedFloat {
@@ -0,0 +1,116 @@
+//! Parse the special float values, `NaN`, `inf` and `infinity`.
+//!
+//! Special values are matched case-insensitively, with an optional sign,
+//! and the longest match is always used. A special value is never
+//! partially consumed, so `infi` is invalid, rather than `inf` followed
+//! by `i`.
+
+#![doc(hidden)]
+
+use crate::num::Float;
+
+/// Check if the buffer starts with an ASCII string, case-insensitively.
+#[inline]
+fn starts_with_ignore_case(bytes: &[u8], string: &[u8]) -> bool {
+    bytes.len() >= string.len() && bytes[..string.len()].eq_ignore_ascii_case(string)
+}
+
+/// Check if the buffer is a prefix of an ASCII string, case-insensitively.
+#[inline]
+fn is_prefix_ignore_case(bytes: &[u8], string: &[u8]) -> bool {
+    bytes.len() <= string.len() && bytes.eq_ignore_ascii_case(&string[..bytes.len()])
+}
+
+/// Parse the sign, and get the number of bytes consumed.
+#[inline]
+fn parse_sign(bytes: &[u8]) -> (bool, usize) {
+    match bytes.first() {
+        Some(&b'+') => (true, 1),
+        Some(&b'-') => (false, 1),
+        _ => (true, 0),
+    }
+}
+
+/// Get the length of the special value, without the sign, at the start of the buffer.
+///
+/// Returns `None` if the buffer does not start with a special value,
+/// or if it starts with a partial `infinity`, such as `infi`.
+#[inline]
+fn special_len(bytes: &[u8]) -> Option<usize> {
+    if starts_with_ignore_case(bytes, b"nan") {
+        Some(3)
+    } else if starts_with_ignore_case(bytes, b"infinity") {
+        Some(8)
+    } else if starts_with_ignore_case(bytes, b"inf") {
+        // A partial `infinity` is invalid, rather than `inf` followed by bytes.
+        match bytes.get(3) {
+            Some(&c) if c.eq_ignore_ascii_case(&b'i') => None,
+            _ => Some(3),
+        }
+    } else {
+        None
+    }
+}
+
+/// Parse a special value, with an optional sign.
+///
+/// Returns the float and the number of bytes consumed, or `None` if
+/// the buffer does not start with a special value. `NaN` is always the
+/// canonical NaN, [`Float::NAN_BITS`], with the sign bit set if negative.
+///
+/// * `bytes`       - Buffer leading with the special value.
+///
+/// # Examples
+///
+/// ```
+/// use minimal_lexical::special::parse_special;
+/// use std::f64;
+///
+/// assert_eq!(parse_special::<f64>(b"-Infinity"), Some((f64::NEG_INFINITY, 9)));
+/// assert_eq!(parse_special::<f64>(b"+inf,"), Some((f64::INFINITY, 4)));
+/// assert_eq!(parse_special::<f64>(b"infi"), None);
+/// ```
+///
+/// [`Float::NAN_BITS`]: ../num/trait.Float.html#associatedconstant.NAN_BITS
+#[inline]
+pub fn parse_special<F: Float>(bytes: &[u8]) -> Option<(F, usize)> {
+    let (is_positive, sign_count) = parse_sign(bytes);
+    let count = special_len(&bytes[sign_count..])?;
+    let float = match bytes[sign_count] {
+        b'n' | b'N' => F::from_bits(F::NAN_BITS),
+        _ => F::from_bits(F::INFINITY_BITS),
+    };
+    let float = if is_positive {
+        float
+    } else {
+        -float
+    };
+    Some((float, sign_count + count))
+}
+
+/// Check if the bytes, with an optional sign, are a prefix of a special value.
+///
+/// This includes complete special values, and an empty buffer.
+#[inline]
+pub fn is_special_prefix(bytes: &[u8]) -> bool {
+    let rest = &bytes[parse_sign(bytes).1..];
+    is_prefix_ignore_case(rest, b"infinity") || is_prefix_ignore_case(rest, b"nan")
+}
+
+/// Check if a parsed special value could continue past the end of the buffer.
+///
+/// Only `inf` can continue, into `infinity`. Returns `None` if the
+/// bytes, without the sign, are not a special value.
+///
+/// * `special`     - Parsed special value, without the sign.
+/// * `rest`        - Bytes after the special value.
+#[inline]
+pub fn is_continued_special(special: &[u8], rest: &[u8]) -> Option<bool> {
+    if special.eq_ignore_ascii_case(b"nan") || special.eq_ignore_ascii_case(b"infinity") {
+        Some(false)
+    } else if special.eq_ignore_ascii_case(b"inf") {
+        Some(is_prefix_ignore_case(rest, b"inity"))
+    } else {
+        None
+    }
+}
@@ -26,6 +26,16 @@ impl StackVec {
         }
     }
 
+    /// Construct an empty vector, with space for at least `capacity` items.
+    ///
+    /// The stack buffer is always the maximum size, so this is only
+    /// provided for parity with the heap-allocated vector.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        debug_assert!(capacity <= bigint::BIGINT_LIMBS);
+        Self::new()
+    }
+
     /// Construct a vector from an existing slice.
     #[inline]
     pub fn try_from(x: &[bigint::Limb]) -> Option<Self> {
@@ -66,7 +76,7 @@ impl StackVec {
     /// The number of items the vector can hold.
     #[inline]
     pub const fn capacity(&self) -> usize {
-        bigint::BIGINT_LIMBS as usize
+        bigint::BIGINT_LIMBS
     }
 
     /// Append an item to the vector, without bounds checking.
@@ -96,6 +106,15 @@ impl StackVec {
         }
     }
 
+    /// Check the vector has space for `additional` more items.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Option<()> {
+        match self.len() + additional <= self.capacity() {
+            true => Some(()),
+            false => None,
+        }
+    }
+
     /// Remove an item from the end of a vector, without bounds checking.
     ///
     /// # Safety
@@ -264,7 +283,7 @@ impl Eq for StackVec {
 impl cmp::PartialOrd for StackVec {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(bigint::compare(self, other))
+        Some(self.cmp(other))
     }
 }
 
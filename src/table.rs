@@ -3,9 +3,9 @@
 #![doc(hidden)]
 
 // Re-export all the feature-specific files.
-#[cfg(feature = "compact")]
+#[cfg(any(feature = "compact", feature = "bellerophon"))]
 pub use crate::table_bellerophon::*;
-#[cfg(not(feature = "compact"))]
+#[cfg(not(any(feature = "compact", feature = "bellerophon")))]
 pub use crate::table_lemire::*;
 #[cfg(not(feature = "compact"))]
 pub use crate::table_small::*;
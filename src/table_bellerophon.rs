@@ -8,9 +8,12 @@
 //! integer to calculate exact extended-representation of each value.
 //! These values are all normalized.
 //!
+//! With the `compact` feature, the normalized small powers are calculated
+//! from the small integer powers, since they are exact as 64-bit integers.
+//!
 //! DO NOT MODIFY: Generated by `etc/bellerophon_table.py`
 
-#![cfg(feature = "compact")]
+#![cfg(any(feature = "compact", feature = "bellerophon"))]
 #![doc(hidden)]
 
 use crate::bellerophon::BellerophonPowers;
@@ -18,6 +21,7 @@ use crate::bellerophon::BellerophonPowers;
 // HIGH LEVEL
 // ----------
 
+#[cfg(not(feature = "compact"))]
 pub const BASE10_POWERS: BellerophonPowers = BellerophonPowers {
     small: &BASE10_SMALL_MANTISSA,
     large: &BASE10_LARGE_MANTISSA,
@@ -28,9 +32,20 @@ pub const BASE10_POWERS: BellerophonPowers = BellerophonPowers {
     log2_shift: BASE10_LOG2_SHIFT,
 };
 
+#[cfg(feature = "compact")]
+pub const BASE10_POWERS: BellerophonPowers = BellerophonPowers {
+    large: &BASE10_LARGE_MANTISSA,
+    small_int: &BASE10_SMALL_INT_POWERS,
+    step: BASE10_STEP,
+    bias: BASE10_BIAS,
+    log2: BASE10_LOG2_MULT,
+    log2_shift: BASE10_LOG2_SHIFT,
+};
+
 // LOW-LEVEL
 // ---------
 
+#[cfg(not(feature = "compact"))]
 const BASE10_SMALL_MANTISSA: [u64; 10] = [
     9223372036854775808,  // 10^0
     11529215046068469760, // 10^1
@@ -10,7 +10,7 @@
 //! implementation, and is similarly subject to an Apache2.0/MIT license.
 
 #![doc(hidden)]
-#![cfg(not(feature = "compact"))]
+#![cfg(not(any(feature = "compact", feature = "bellerophon")))]
 
 pub const SMALLEST_POWER_OF_FIVE: i32 = -342;
 pub const LARGEST_POWER_OF_FIVE: i32 = 308;
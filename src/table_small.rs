@@ -70,14 +70,14 @@ pub const SMALL_F64_POW10: [f64; 32] = [
 ];
 
 /// Pre-computed large power-of-5 for 32-bit limbs.
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(limb_width_64))]
 pub const LARGE_POW5: [u32; 10] = [
     4279965485, 329373468, 4020270615, 2137533757, 4287402176, 1057042919, 1071430142, 2440757623,
     381945767, 46164893,
 ];
 
 /// Pre-computed large power-of-5 for 64-bit limbs.
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(limb_width_64)]
 pub const LARGE_POW5: [u64; 5] = [
     1414648277510068013,
     9180637584431281687,
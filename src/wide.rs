@@ -0,0 +1,71 @@
+//! Wide multiplication of 128-bit integers.
+//!
+//! This provides the 256-bit and 192-bit products required for
+//! extended-precision algorithms with 128-bit significant digits, and
+//! the 128-bit product of 64-bit integers. With the `widening_mul`
+//! feature, the latter uses the standard library's `carrying_mul`.
+
+#![doc(hidden)]
+
+/// Split a 128-bit integer into the low and high 64 bits.
+#[inline(always)]
+fn split(x: u128) -> (u128, u128) {
+    (x as u64 as u128, x >> 64)
+}
+
+/// Multiply two 64-bit integers, returning the low and high 64 bits.
+#[inline(always)]
+#[cfg(feature = "widening_mul")]
+// Requires Rust 1.91 for `carrying_mul`.
+#[clippy::msrv = "1.91"]
+pub fn mul_u64(x: u64, y: u64) -> (u64, u64) {
+    x.carrying_mul(y, 0)
+}
+
+/// Multiply two 64-bit integers, returning the low and high 64 bits.
+#[inline(always)]
+#[cfg(not(feature = "widening_mul"))]
+pub fn mul_u64(x: u64, y: u64) -> (u64, u64) {
+    let r = (x as u128) * (y as u128);
+    (r as u64, (r >> 64) as u64)
+}
+
+/// Multiply two 128-bit integers, returning the low and high 128 bits.
+#[inline]
+pub fn mul_wide(x: u128, y: u128) -> (u128, u128) {
+    // Schoolbook multiplication using 64-bit halves, each partial product
+    // fits in 128 bits, and the carries are propagated into the high bits.
+    let (x0, x1) = split(x);
+    let (y0, y1) = split(y);
+    let p00 = x0 * y0;
+    let p01 = x0 * y1;
+    let p10 = x1 * y0;
+    let p11 = x1 * y1;
+
+    // Cannot overflow: each term is at most `2^64 - 1`.
+    let (p00_lo, p00_hi) = split(p00);
+    let (p01_lo, p01_hi) = split(p01);
+    let (p10_lo, p10_hi) = split(p10);
+    let middle = p00_hi + p01_lo + p10_lo;
+    let (middle_lo, middle_hi) = split(middle);
+
+    let lo = (middle_lo << 64) | p00_lo;
+    let hi = p11 + p01_hi + p10_hi + middle_hi;
+    (lo, hi)
+}
+
+/// Multiply a 128-bit integer by a 64-bit integer, returning the low
+/// 128 bits and the high 64 bits of the 192-bit product.
+#[inline]
+pub fn mul_u128_u64(x: u128, y: u64) -> (u128, u64) {
+    let (x0, x1) = split(x);
+    let y = y as u128;
+    let p0 = x0 * y;
+    let p1 = x1 * y;
+
+    // Cannot overflow: `(2^64 - 1)^2 + 2^64 - 1 < 2^128`.
+    let (p0_lo, p0_hi) = split(p0);
+    let middle = p1 + p0_hi;
+    let (middle_lo, middle_hi) = split(middle);
+    ((middle_lo << 64) | p0_lo, middle_hi as u64)
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "compact")]
+#![cfg(any(feature = "compact", feature = "bellerophon"))]
 #![allow(dead_code)]
 
 use minimal_lexical::bellerophon::bellerophon;
@@ -1,9 +1,13 @@
-#![cfg(feature = "compact")]
+#![cfg(any(feature = "compact", feature = "bellerophon"))]
 
 mod bellerophon;
 
 use bellerophon::{bellerophon_test, compute_float32, compute_float64};
+use minimal_lexical::bellerophon::bellerophon;
+use minimal_lexical::extended_float::ExtendedFloat;
 use minimal_lexical::num::Float;
+use minimal_lexical::number::Number;
+use minimal_lexical::table::BASE10_POWERS;
 
 #[test]
 fn halfway_round_down_test() {
@@ -229,3 +233,55 @@ fn compute_float_f64_test() {
     );
     assert_eq!(compute_float64(-3, 9007199254740996000), (1076, 2));
 }
+
+#[test]
+fn small_powers_test() {
+    // The small powers are exact and normalized, whether stored or calculated.
+    for index in 0..BASE10_POWERS.step as usize {
+        let int = BASE10_POWERS.get_small_int(index);
+        assert_eq!(int, 10_u64.pow(index as u32));
+        let mut expected = ExtendedFloat {
+            mant: int,
+            exp: 0,
+        };
+        expected.normalize();
+        let power = BASE10_POWERS.get_small(index);
+        assert_eq!((power.mant, power.exp), (expected.mant, expected.exp));
+    }
+}
+
+#[test]
+fn truncated_error_test() {
+    // Just above the halfway point of `1.0968515666640148e178`, which
+    // truncates to 11 units of the normalized mantissa below halfway.
+    let halfway = "1096851566664014872957506940275401379570551198352378739888707790313725351341\
+                   9265403168467702030089457998233563377385761596659835380535261785958494164150\
+                   429779322051355195721383936";
+    let above = format!("{}0001", halfway);
+    let float: f64 = minimal_lexical::parse_float(above.as_bytes().iter(), b"".iter(), -4);
+    #[cfg(not(feature = "no_slow_path"))]
+    assert_eq!(float, 1.096851566664015e178);
+    #[cfg(feature = "no_slow_path")]
+    assert!(float == 1.096851566664015e178 || float == 1.0968515666640148e178);
+
+    // The moderate path cannot round the truncated digits.
+    bellerophon_test::<f64>(
+        1096851566664014872,
+        160,
+        true,
+        12482746453446931445,
+        1603 + f64::INVALID_FP,
+    );
+
+    // Any small, truncated mantissa spans many floats, including denormals.
+    let truncated = |mantissa: u64, exponent: i32| Number {
+        exponent,
+        mantissa,
+        many_digits: true,
+    };
+    for &(mantissa, exponent) in &[(1, 0), (1234, 0), (u32::max_value() as u64, 10), (1, -320)] {
+        assert!(bellerophon::<f64>(&truncated(mantissa, exponent)).exp < 0);
+    }
+    assert!(bellerophon::<f32>(&truncated(1234, 0)).exp < 0);
+    assert!(bellerophon::<f32>(&truncated(1, -44)).exp < 0);
+}
@@ -0,0 +1,78 @@
+#![cfg(all(feature = "decimal", any(feature = "std", feature = "alloc")))]
+
+use minimal_lexical::Decimal;
+use std::{f32, f64};
+
+fn decimal(integer: &str, fraction: &str, exponent: i32) -> Decimal {
+    Decimal::from_parts(integer.as_bytes().iter(), fraction.as_bytes().iter(), exponent)
+}
+
+#[test]
+fn from_parts_test() {
+    let value = decimal("1", "2345", 0);
+    assert_eq!(value.digits(), b"12345");
+    assert_eq!(value.exponent(), -4);
+
+    let value = decimal("00012300", "", 5);
+    assert_eq!(value.digits(), b"123");
+    assert_eq!(value.exponent(), 7);
+
+    let value = decimal("", "000123000", -3);
+    assert_eq!(value.digits(), b"123");
+    assert_eq!(value.exponent(), -9);
+
+    let value = decimal("1", "0000000001", i32::min_value());
+    assert_eq!(value.digits(), b"10000000001");
+    assert_eq!(value.exponent(), i32::min_value() as i64 - 10);
+
+    // Zero has a single representation, for any exponent.
+    for &(integer, fraction) in &[("", ""), ("0", ""), ("", "0"), ("000", "000")] {
+        for &exponent in &[0, 400, -400, i32::max_value(), i32::min_value()] {
+            let value = decimal(integer, fraction, exponent);
+            assert!(value.is_zero());
+            assert_eq!(value, Decimal::default());
+        }
+    }
+}
+
+#[test]
+fn eq_test() {
+    // Equal values have the same digits and exponent.
+    assert_eq!(decimal("1", "25", 0), decimal("", "125", 1));
+    assert_eq!(decimal("1", "25", 0), decimal("000125", "000", -2));
+    assert_eq!(decimal("100", "", 0), decimal("1", "", 2));
+    assert_ne!(decimal("1", "25", 0), decimal("1", "26", 0));
+    assert_ne!(decimal("1", "25", 0), decimal("1", "25", 1));
+}
+
+#[test]
+fn display_test() {
+    assert_eq!(decimal("", "", 0).to_string(), "0");
+    assert_eq!(decimal("1", "", 0).to_string(), "1");
+    assert_eq!(decimal("1", "2345", 0).to_string(), "12345e-4");
+    assert_eq!(decimal("12", "", 300).to_string(), "12e300");
+    assert_eq!(decimal("0", "5", i32::min_value()).to_string(), "5e-2147483649");
+}
+
+#[test]
+fn to_float_test() {
+    assert_eq!(decimal("1", "2345", 0).to_float::<f64>(), 1.2345);
+    assert_eq!(decimal("1", "2345", 0).to_float::<f32>(), 1.2345);
+    assert_eq!(decimal("", "", 0).to_float::<f64>(), 0.0);
+    assert_eq!(decimal("1", "", 309).to_float::<f64>(), f64::INFINITY);
+    assert_eq!(decimal("1", "", 39).to_float::<f32>(), f32::INFINITY);
+    assert_eq!(decimal("1", "", -400).to_float::<f64>(), 0.0);
+
+    // Exponents outside an `i32` saturate to 0 or infinity.
+    assert_eq!(decimal("", "5", i32::min_value()).to_float::<f64>(), 0.0);
+    assert_eq!(decimal("50", "", i32::max_value()).to_float::<f64>(), f64::INFINITY);
+
+    // Halfway between 1 and the next float, and just above it.
+    #[cfg(not(feature = "no_slow_path"))]
+    {
+        let halfway = decimal("9007199254740993", "", 0);
+        assert_eq!(halfway.to_float::<f64>(), 9007199254740992.0);
+        let above = decimal("9007199254740993", "0000000000000000000000001", 0);
+        assert_eq!(above.to_float::<f64>(), 9007199254740994.0);
+    }
+}
@@ -0,0 +1,98 @@
+mod rng;
+
+use minimal_lexical::digits;
+use minimal_lexical::parse;
+use rng::xorshift;
+
+/// Parse the digits one at a time, if they're all valid.
+fn parse_scalar(bytes: &[u8]) -> Option<u64> {
+    let mut value: u64 = 0;
+    for &c in bytes {
+        let digit = (c as char).to_digit(10)?;
+        value = value * 10 + digit as u64;
+    }
+    Some(value)
+}
+
+#[test]
+fn is_8digits_test() {
+    assert!(digits::is_8digits(u64::from_le_bytes(*b"01234567")));
+    assert!(digits::is_8digits(u64::from_le_bytes(*b"99999999")));
+    assert!(!digits::is_8digits(u64::from_le_bytes(*b"0123456/")));
+    assert!(!digits::is_8digits(u64::from_le_bytes(*b":1234567")));
+    assert!(!digits::is_8digits(u64::from_le_bytes(*b"0123\xb0567")));
+}
+
+#[test]
+fn parse_8digits_test() {
+    assert_eq!(digits::try_parse_8digits(b"01234567"), Some(1234567));
+    assert_eq!(digits::try_parse_8digits(b"98765432"), Some(98765432));
+    assert_eq!(digits::try_parse_8digits(b"98765432.1"), Some(98765432));
+    assert_eq!(digits::try_parse_8digits(b"9876543."), None);
+}
+
+#[test]
+fn parse_16digits_test() {
+    let bytes = b"1234567890123456";
+    assert_eq!(digits::try_parse_16digits(bytes), Some(1234567890123456));
+    assert_eq!(digits::try_parse_16digits_swar(bytes), Some(1234567890123456));
+    let bytes = b"9999999999999999";
+    assert_eq!(digits::try_parse_16digits(bytes), Some(9999999999999999));
+    assert_eq!(digits::try_parse_16digits_swar(bytes), Some(9999999999999999));
+    let bytes = b"123456789012345e";
+    assert_eq!(digits::try_parse_16digits(bytes), None);
+    assert_eq!(digits::try_parse_16digits_swar(bytes), None);
+}
+
+#[test]
+fn parse_16digits_cross_test() {
+    // Cross-validate the vectorized and SWAR algorithms against the
+    // scalar algorithm, including bytes just outside the digit range.
+    let alphabet = b"0123456789/:\x00\x7f\x80\xb0\xff";
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..20000 {
+        let mut bytes = [0u8; 16];
+        for byte in bytes.iter_mut() {
+            *byte = b'0' + (xorshift(&mut state) % 10) as u8;
+        }
+        // Only make some inputs invalid.
+        if xorshift(&mut state) % 2 == 0 {
+            let index = (xorshift(&mut state) % 16) as usize;
+            bytes[index] = alphabet[(xorshift(&mut state) % alphabet.len() as u64) as usize];
+        }
+
+        let expected = parse_scalar(&bytes);
+        assert_eq!(digits::try_parse_16digits(&bytes), expected);
+        assert_eq!(digits::try_parse_16digits_swar(&bytes), expected);
+        assert_eq!(digits::try_parse_8digits(&bytes), parse_scalar(&bytes[..8]));
+    }
+}
+
+#[test]
+fn parse_digits_test() {
+    for count in 0..20 {
+        let digits: Vec<u8> = (0..count).map(|i| b'1' + (i % 9) as u8).collect();
+        let expected = parse_scalar(&digits).unwrap();
+        assert_eq!(digits::parse_digits(0, &digits), expected);
+    }
+    assert_eq!(digits::parse_digits(123, b"4567890123456789"), 1234567890123456789);
+}
+
+#[test]
+fn parse_number_slice_test() {
+    let cases: &[(&str, &str, i32)] = &[
+        ("", "", 0),
+        ("1", "2345", 0),
+        ("0001", "2345", 10),
+        ("", "00012345678901234567", -5),
+        ("1234567890123456789", "", 0),
+        ("12345678901234567890", "", 0),
+        ("1234567890", "1234567890", 3),
+        ("1234567890123456", "789", 0),
+    ];
+    for &(integer, fraction, exponent) in cases.iter() {
+        let (integer, fraction) = (integer.as_bytes(), fraction.as_bytes());
+        let expected = parse::parse_number(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(parse::parse_number_slice(integer, fraction, exponent), expected);
+    }
+}
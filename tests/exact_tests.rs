@@ -0,0 +1,65 @@
+#![cfg(feature = "const_fn")]
+
+use minimal_lexical::exact::{parse_float_exact, parse_float_exact_f32};
+
+const HALF: f64 = match parse_float_exact("0.5") {
+    Some(value) => value,
+    None => panic!("inexact literal"),
+};
+
+#[test]
+fn const_test() {
+    assert_eq!(HALF, 0.5);
+}
+
+#[test]
+fn parse_float_exact_test() {
+    assert_eq!(parse_float_exact("1.5"), Some(1.5));
+    assert_eq!(parse_float_exact("-2.25"), Some(-2.25));
+    assert_eq!(parse_float_exact("+100"), Some(100.0));
+    assert_eq!(parse_float_exact("2.5e-1"), Some(0.25));
+    assert_eq!(parse_float_exact("1."), Some(1.0));
+    assert_eq!(parse_float_exact(".5"), Some(0.5));
+    assert_eq!(parse_float_exact("1e22"), Some(1e22));
+    assert_eq!(parse_float_exact("9007199254740992"), Some(9007199254740992.0));
+    assert_eq!(parse_float_exact("0"), Some(0.0));
+    assert!(parse_float_exact("-0.0").unwrap().is_sign_negative());
+
+    // Powers of 2 are removed before adding the powers of 5.
+    assert_eq!(parse_float_exact("5.312512e24"), Some(5.312512e24));
+    assert_eq!(parse_float_exact("2733603609953435648e2"), Some(2.7336036099534356e20));
+
+    // Trailing zeros are not significant digits.
+    assert_eq!(parse_float_exact("1.0000000000000000000000"), Some(1.0));
+    assert_eq!(parse_float_exact("100000000000000000000000e-2"), Some(1e21));
+    assert_eq!(parse_float_exact("0.00000000000000000000005e22"), Some(0.5));
+
+    // Inexact values.
+    assert_eq!(parse_float_exact("0.1"), None);
+    assert_eq!(parse_float_exact("1e23"), None);
+    assert_eq!(parse_float_exact("1e308"), None);
+    assert_eq!(parse_float_exact("9007199254740993"), None);
+    assert_eq!(parse_float_exact("18446744073709551616"), None);
+
+    // Invalid literals.
+    assert_eq!(parse_float_exact(""), None);
+    assert_eq!(parse_float_exact("."), None);
+    assert_eq!(parse_float_exact("-"), None);
+    assert_eq!(parse_float_exact("e5"), None);
+    assert_eq!(parse_float_exact("1.5e"), None);
+    assert_eq!(parse_float_exact("1.5e+"), None);
+    assert_eq!(parse_float_exact("1.2.3"), None);
+    assert_eq!(parse_float_exact("1,5"), None);
+    assert_eq!(parse_float_exact("inf"), None);
+}
+
+#[test]
+fn parse_float_exact_f32_test() {
+    assert_eq!(parse_float_exact_f32("1.5"), Some(1.5));
+    assert_eq!(parse_float_exact_f32("-0.5"), Some(-0.5));
+    assert_eq!(parse_float_exact_f32("16777216"), Some(16777216.0));
+    assert_eq!(parse_float_exact_f32("1e10"), Some(1e10));
+    assert_eq!(parse_float_exact_f32("16777217"), None);
+    assert_eq!(parse_float_exact_f32("1e11"), None);
+    assert_eq!(parse_float_exact_f32("0.1"), None);
+}
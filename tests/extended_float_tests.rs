@@ -0,0 +1,376 @@
+use core::f64;
+use minimal_lexical::extended_float::ExtendedFloat;
+use minimal_lexical::num::Float;
+
+fn check_from_float<F: Float + core::fmt::Debug>(float: F, mant: u64, exp: i32) {
+    let fp = ExtendedFloat::from_float(float);
+    let expected = ExtendedFloat {
+        mant,
+        exp,
+    };
+    assert_eq!(fp, expected);
+    assert_eq!(fp.into_float::<F>(), float);
+}
+
+#[test]
+fn from_float_test() {
+    check_from_float(0.0_f64, 0, 0);
+    check_from_float(1.0_f64, 1 << 63, -63);
+    check_from_float(1.5_f64, 3 << 62, -63);
+    check_from_float(2.0_f64, 1 << 63, -62);
+    check_from_float(5e-324_f64, 1 << 63, -1137);
+    check_from_float(1.7976931348623157e308_f64, 0xFFFFFFFFFFFFF800, 960);
+    check_from_float(1.0_f32, 1 << 63, -63);
+    check_from_float(1e-45_f32, 1 << 63, -212);
+    check_from_float(3.4028235e38_f32, 0xFFFFFF0000000000, 64);
+
+    // The sign is ignored.
+    assert_eq!(ExtendedFloat::from_float(-1.5_f64), ExtendedFloat::from_float(1.5_f64));
+}
+
+#[test]
+fn from_float_roundtrip_test() {
+    let values = [
+        1.2345_f64,
+        1e-300,
+        2.2250738585072014e-308,
+        2.225073858507201e-308,
+        123456789.0,
+        9007199254740993.0,
+        1.7976931348623157e308,
+    ];
+    for &value in values.iter() {
+        let fp = ExtendedFloat::from_float(value);
+        assert_eq!(fp.mant.leading_zeros(), 0);
+        assert_eq!(fp.into_float::<f64>(), value);
+    }
+}
+
+/// Compare the exact values of two normalized extended-precision floats.
+fn cmp_exact(x: ExtendedFloat, y: ExtendedFloat) -> core::cmp::Ordering {
+    match (x.mant, y.mant) {
+        (0, 0) => core::cmp::Ordering::Equal,
+        (0, _) => core::cmp::Ordering::Less,
+        (_, 0) => core::cmp::Ordering::Greater,
+        _ => (x.exp, x.mant).cmp(&(y.exp, y.mant)),
+    }
+}
+
+fn check_directed<F: Float + core::fmt::Debug>(fp: ExtendedFloat) {
+    let down = fp.into_downward_float::<F>();
+    let up = fp.into_upward_float::<F>();
+    let nearest = fp.into_float::<F>();
+    assert!(nearest == down || nearest == up);
+    assert!(cmp_exact(ExtendedFloat::from_float(down), fp) != core::cmp::Ordering::Greater);
+    if up.to_bits() == F::EXPONENT_MASK {
+        // Overflow to infinity, and the largest finite float is below.
+        assert_eq!(down.to_bits(), F::EXPONENT_MASK - 1);
+    } else {
+        assert!(cmp_exact(ExtendedFloat::from_float(up), fp) != core::cmp::Ordering::Less);
+    }
+    match cmp_exact(ExtendedFloat::from_float(down), fp) {
+        core::cmp::Ordering::Equal => assert_eq!(down, up),
+        _ => assert_eq!(down.to_bits() + 1, up.to_bits()),
+    }
+}
+
+#[test]
+fn directed_rounding_test() {
+    // Exactly representable.
+    check_directed::<f64>(ExtendedFloat::from_float(1.2345_f64));
+    check_directed::<f64>(ExtendedFloat::from_float(5e-324_f64));
+    check_directed::<f32>(ExtendedFloat::from_float(16777216.0_f32));
+
+    // Hard decimal integers, which are halfway or near-halfway.
+    let integers: [u64; 6] = [
+        9007199254740993,
+        9007199254740995,
+        9223372036854776833,
+        18014398509481990,
+        16777217,
+        33554435,
+    ];
+    for &integer in integers.iter() {
+        let shift = integer.leading_zeros() as i32;
+        let fp = ExtendedFloat {
+            mant: integer << shift,
+            exp: -shift,
+        };
+        check_directed::<f64>(fp);
+        check_directed::<f32>(fp);
+    }
+
+    // Denormal, underflow and overflow boundaries.
+    let bits: [u64; 4] =
+        [1 << 63, 0x8000_0000_0000_0001, 0xFFFF_FFFF_FFFF_FFFF, 0xC000_0000_0000_0000];
+    let exps: [i32; 8] = [-1140, -1138, -1137, -1100, -1050, -200, 960, 961];
+    for &mant in bits.iter() {
+        for &exp in exps.iter() {
+            let fp = ExtendedFloat {
+                mant,
+                exp,
+            };
+            check_directed::<f64>(fp);
+        }
+    }
+
+    // Known values.
+    let fp = ExtendedFloat {
+        mant: 9007199254740993 << 10,
+        exp: -10,
+    };
+    assert_eq!(fp.into_downward_float::<f64>(), 9007199254740992.0);
+    assert_eq!(fp.into_upward_float::<f64>(), 9007199254740994.0);
+    assert_eq!(fp.into_float::<f64>(), 9007199254740992.0);
+    let tiny = ExtendedFloat {
+        mant: 1 << 63,
+        exp: -1200,
+    };
+    assert_eq!(tiny.into_downward_float::<f64>(), 0.0);
+    assert_eq!(tiny.into_upward_float::<f64>(), 5e-324);
+    assert_eq!(tiny.into_float::<f64>(), 0.0);
+    let huge = ExtendedFloat {
+        mant: 1 << 63,
+        exp: 961,
+    };
+    assert_eq!(huge.into_downward_float::<f64>(), 1.7976931348623157e308);
+    assert_eq!(huge.into_upward_float::<f64>(), f64::INFINITY);
+    assert_eq!(huge.into_float::<f64>(), f64::INFINITY);
+}
+
+fn fp(mant: u64, exp: i32) -> ExtendedFloat {
+    ExtendedFloat {
+        mant,
+        exp,
+    }
+}
+
+#[test]
+fn normalize_test() {
+    let mut x = fp(1, 0);
+    assert_eq!(x.normalize(), 63);
+    assert_eq!(x, fp(1 << 63, -63));
+    assert_eq!(x.normalize(), 0);
+
+    let mut x = fp(0, 5);
+    assert_eq!(x.normalize(), 0);
+    assert_eq!(x, fp(0, 5));
+}
+
+#[test]
+fn mul_test() {
+    // 1.5 * 1.5 == 2.25
+    let x = ExtendedFloat::from_float(1.5_f64);
+    let mut y = x.mul(&x);
+    assert_eq!(y, fp(9 << 60, -62));
+    y.normalize();
+    assert_eq!(y.into_float::<f64>(), 2.25);
+
+    // Rounds to nearest, with ties up.
+    let x = fp(0xFFFF_FFFF_FFFF_FFFF, 0);
+    assert_eq!(x.mul(&x), fp(0xFFFF_FFFF_FFFF_FFFE, 64));
+
+    let mut z = ExtendedFloat::from_float(1e100_f64);
+    z.imul(&ExtendedFloat::from_float(1e-100_f64));
+    z.normalize();
+    assert_eq!(z.into_float::<f64>(), 1.0);
+}
+
+#[test]
+fn mul_unnormalized_test() {
+    // 3 * 20 == 60
+    let mut y = fp(3, 0).mul_unnormalized(&fp(5, 2));
+    assert_eq!(y, fp(60, 0));
+    assert_eq!(y.normalize(), 1);
+    assert_eq!(y.into_float::<f64>(), 60.0);
+
+    // The smallest denormal, squared, is exact.
+    let x = fp(1, -1074);
+    assert_eq!(x.mul_unnormalized(&x), fp(1, -2148));
+
+    // Identical to `mul` for normalized floats.
+    let x = ExtendedFloat::from_float(1.5_f64);
+    assert_eq!(x.mul_unnormalized(&x), x.mul(&x));
+
+    // A literal 0 has no high bits to normalize.
+    assert_eq!(fp(0, 5).mul_unnormalized(&fp(7, 0)), fp(0, 0));
+    assert_eq!(fp(7, 0).mul_unnormalized(&fp(0, 5)).mant, 0);
+}
+
+#[test]
+fn add_test() {
+    let x = ExtendedFloat::from_float(1.5_f64);
+    let y = ExtendedFloat::from_float(0.25_f64);
+    assert_eq!(x.add(&y).into_float::<f64>(), 1.75);
+    assert_eq!(y.add(&x).into_float::<f64>(), 1.75);
+    assert_eq!(x.add(&fp(0, 0)), x);
+    assert_eq!(fp(0, 0).add(&x), x);
+
+    // Carry is shifted back in, and remains normalized.
+    assert_eq!(x.add(&x), fp(3 << 62, -62));
+
+    // Truncated bit is halfway: ties to even.
+    assert_eq!(fp(1 << 63, 0).add(&fp(1 << 63 | 1, 0)), fp(1 << 63, 1));
+    assert_eq!(fp(1 << 63 | 1, 0).add(&fp(1 << 63 | 2, 0)), fp(1 << 63 | 2, 1));
+    assert_eq!(fp(!0, 0).add(&fp(!0, 0)), fp(!0, 1));
+    assert_eq!(fp(!0, 0).add(&fp(1, 0)), fp(1 << 63, 1));
+
+    // Small values are rounded away.
+    assert_eq!(fp(1 << 63, 0).add(&fp(1 << 63, -200)), fp(1 << 63, 0));
+}
+
+/// Check the mantissa, exponent, and sticky bit, not just the value.
+fn check_truncated(result: (ExtendedFloat, bool), mant: u64, exp: i32, is_truncated: bool) {
+    assert_eq!((result.0.mant, result.0.exp, result.1), (mant, exp, is_truncated));
+}
+
+#[test]
+fn add_truncated_test() {
+    let x = ExtendedFloat::from_float(1.5_f64);
+    let y = ExtendedFloat::from_float(0.25_f64);
+    check_truncated(x.add_truncated(&y), 7 << 61, -63, false);
+    check_truncated(y.add_truncated(&x), 7 << 61, -63, false);
+    check_truncated(fp(3, 0).add_truncated(&fp(5, 2)), 23 << 59, -59, false);
+    check_truncated(x.add_truncated(&fp(0, 100)), x.mant, x.exp, false);
+    check_truncated(fp(0, 100).add_truncated(&x), x.mant, x.exp, false);
+    check_truncated(fp(0, 100).add_truncated(&fp(0, 5)), 0, 0, false);
+
+    // The carry is shifted into the mantissa, truncating the low bit.
+    check_truncated(fp(!0, 0).add_truncated(&fp(1, 0)), 1 << 63, 1, false);
+    check_truncated(fp(!0, 0).add_truncated(&fp(!0, 0)), !0, 1, false);
+    check_truncated(fp(!0, 0).add_truncated(&fp(2, 0)), 1 << 63, 1, true);
+
+    // Exponents within 64 bits are aligned exactly, and only the
+    // normalized sum is truncated.
+    let one = fp(1 << 63, 0);
+    check_truncated(one.add_truncated(&fp(1 << 63, -63)), (1 << 63) | 1, 0, false);
+    check_truncated(one.add_truncated(&fp(1 << 63, -64)), 1 << 63, 0, true);
+    check_truncated(one.add_truncated(&fp(1 << 63, -127)), 1 << 63, 0, true);
+
+    // Exponents differing widely are truncated, even past 128 bits.
+    check_truncated(one.add_truncated(&fp(1 << 63, -128)), 1 << 63, 0, true);
+    check_truncated(one.add_truncated(&fp(1, -200)), 1 << 63, 0, true);
+    check_truncated(fp(1, -200).add_truncated(&one), 1 << 63, 0, true);
+    check_truncated(one.add_truncated(&fp(1, -2000)), 1 << 63, 0, true);
+}
+
+#[test]
+fn sub_truncated_test() {
+    let x = ExtendedFloat::from_float(1.5_f64);
+    let y = ExtendedFloat::from_float(0.25_f64);
+    check_truncated(x.sub_truncated(&y), 5 << 61, -63, false);
+    check_truncated(x.sub_truncated(&x), 0, 0, false);
+    check_truncated(x.sub_truncated(&fp(0, 100)), x.mant, x.exp, false);
+    check_truncated(fp(0, 100).sub_truncated(&fp(0, 5)), 0, 0, false);
+
+    // The leading bits cancel, and the difference is normalized.
+    let one = fp(1 << 63, 0);
+    check_truncated(fp((1 << 63) | 1, 0).sub_truncated(&one), 1 << 63, -63, false);
+    check_truncated(one.sub_truncated(&fp(!0, -1)), 1 << 63, -64, false);
+
+    // The bits below the smaller float are kept in 128 bits.
+    check_truncated(one.sub_truncated(&fp(1 << 63, -64)), !0, -1, false);
+    check_truncated(one.sub_truncated(&fp(1 << 63, -127)), !0, -1, true);
+
+    // Exponents differing widely borrow from the truncated difference.
+    check_truncated(one.sub_truncated(&fp(1 << 63, -128)), !0, -1, true);
+    check_truncated(one.sub_truncated(&fp(1, -200)), !0, -1, true);
+    check_truncated(one.sub_truncated(&fp(1, -2000)), !0, -1, true);
+    check_truncated(fp(3 << 62, 0).sub_truncated(&fp(1, -200)), (3 << 62) - 1, 0, true);
+}
+
+#[test]
+fn shl_test() {
+    let mut x = fp(3, 0);
+    x.shl(62);
+    assert_eq!(x, fp(3 << 62, -62));
+    x.shl(0);
+    assert_eq!(x, fp(3 << 62, -62));
+}
+
+#[test]
+fn shr_test() {
+    let mut x = fp(0b1011, 0);
+    x.shr(0);
+    assert_eq!(x, fp(0b1011, 0));
+    x.shr(1);
+    assert_eq!(x, fp(0b110, 1));
+
+    // Ties to even.
+    let mut x = fp(0b1010, 0);
+    x.shr(2);
+    assert_eq!(x, fp(0b10, 2));
+    let mut x = fp(0b1110, 0);
+    x.shr(2);
+    assert_eq!(x, fp(0b100, 2));
+
+    // Large shifts.
+    let mut x = fp(1 << 63 | 1, 0);
+    x.shr(64);
+    assert_eq!(x, fp(1, 64));
+    let mut x = fp(!0, 0);
+    x.shr(65);
+    assert_eq!(x, fp(0, 65));
+}
+
+#[test]
+fn shl_shr_roundtrip_test() {
+    // Compare the fields, since equality only compares the values.
+    fn check(mant: u64, exp: i32, shift: i32) {
+        let mut x = fp(mant, exp);
+        x.shl(shift);
+        x.shr(shift);
+        assert_eq!((x.mant, x.exp), (mant, exp));
+    }
+
+    let mants: [u64; 7] = [1, 3, 0b1011, 0xdead_beef, 0x1234_5678_9abc_def0, 1 << 62, !0 >> 1];
+    for &mant in mants.iter() {
+        for shift in 0..=mant.leading_zeros() as i32 {
+            check(mant, 0, shift);
+            check(mant, -1074, shift);
+        }
+    }
+
+    // Normalized floats round-trip when the truncated bits are 0.
+    let mants: [u64; 4] = [1 << 63, 3 << 62, 0xdead_beef << 32, 0x1234_5678_9abc_def0 << 3];
+    for &mant in mants.iter() {
+        for shift in 0..=mant.trailing_zeros() as i32 {
+            let mut x = fp(mant, 10);
+            x.shr(shift);
+            x.shl(shift);
+            assert_eq!((x.mant, x.exp), (mant, 10));
+        }
+    }
+}
+
+#[test]
+fn ord_test() {
+    use core::cmp::Ordering;
+
+    // Same value, different representations.
+    assert_eq!(fp(1, 1), fp(2, 0));
+    assert_eq!(fp(1 << 63, -63), fp(1, 0));
+    assert_eq!(fp(3, -1), fp(0xC000000000000000, -63));
+    assert_eq!(fp(0, 0), fp(0, 100));
+    assert_eq!(fp(1, 1).cmp(&fp(4, -1)), Ordering::Equal);
+
+    // Different values, different exponents.
+    assert!(fp(0, 100) < fp(1, -1000));
+    assert!(fp(1, 0) < fp(3, 0));
+    assert!(fp(3, -1) < fp(1, 1));
+    assert!(fp(u64::max_value(), 0) < fp(1, 64));
+    assert!(fp(u64::max_value(), -64) < fp(1, 0));
+    assert!(fp(1 << 63, i32::min_value()) < fp(1, i32::min_value() + 64));
+    assert!(fp(1, i32::max_value()) > fp(u64::max_value(), i32::max_value() - 64));
+    assert!(fp(5, 2) > fp(9, 1));
+    assert!(fp(5, 2) < fp(11, 1));
+
+    // Ordering matches the native floats.
+    let values = [0.0, 5e-324, 1e-300, 0.5, 1.0, 1.5, 1e300, f64::MAX];
+    for pair in values.windows(2) {
+        let x = ExtendedFloat::from_float(pair[0]);
+        let y = ExtendedFloat::from_float(pair[1]);
+        assert!(x < y);
+        assert_eq!(x.max(y), y);
+    }
+}
@@ -1,6 +1,6 @@
 //! These tests are adapted from the Rust core library's unittests.
 
-#![cfg(not(feature = "compact"))]
+#![cfg(not(any(feature = "compact", feature = "bellerophon")))]
 
 use minimal_lexical::lemire;
 use minimal_lexical::num::Float;
@@ -376,3 +376,213 @@ fn compute_float_f64_rounding() {
     assert_eq!(compute_float64(-3, 9007199254740995000), (1076, 2));
     assert_eq!(compute_float64(-3, 9007199254740996000), (1076, 2));
 }
+
+#[test]
+fn compute_float_min_subnormal_test() {
+    // Just above and below halfway between 0 and the smallest subnormal,
+    // which is `2.4703282292062327208...e-324` for `f64`.
+    assert_eq!(compute_float64(-342, 2470328229206232721), (0, 1));
+    assert_eq!(compute_float64(-342, 2470328229206232720), (0, 0));
+    assert_eq!(compute_float64(-343, 9999999999999999999), (0, 0));
+    assert_eq!(compute_float64(-324, 3), (0, 1));
+    assert_eq!(compute_float64(-324, 2), (0, 0));
+
+    // The same for `f32`, at `7.0064923216240853546...e-46`.
+    assert_eq!(compute_float32(-64, 7006492321624085355), (0, 1));
+    assert_eq!(compute_float32(-64, 7006492321624085354), (0, 0));
+    assert_eq!(compute_float32(-66, 9999999999999999999), (0, 0));
+    assert_eq!(compute_float32(-46, 8), (0, 1));
+    assert_eq!(compute_float32(-46, 7), (0, 0));
+}
+
+#[cfg(feature = "wide_mantissa")]
+fn compute_float_wide64(q: i32, w: u128) -> Option<(i32, u64)> {
+    lemire::compute_float_wide::<f64>(q, w).map(|fp| (fp.exp, fp.mant))
+}
+
+#[test]
+#[cfg(feature = "wide_mantissa")]
+fn compute_float_wide64_test() {
+    // These match the 64-bit algorithm for small mantissas.
+    assert_eq!(compute_float_wide64(0, 9007199254740992), Some((1076, 0)));
+    assert_eq!(compute_float_wide64(0, 9007199254740993), Some((1076, 0)));
+    assert_eq!(compute_float_wide64(0, 9007199254740994), Some((1076, 1)));
+    assert_eq!(compute_float_wide64(0, 9007199254740995), Some((1076, 2)));
+    assert_eq!(compute_float_wide64(-42, 9123456727292927), Some((936, 1854521741541368)));
+
+    // Near-halfway cases with more than 19 digits. Exact halfway cases
+    // with a negative exponent cannot be resolved, since the power of 5
+    // is not exact.
+    assert_eq!(compute_float_wide64(-10, 90071992547409930000000000), None);
+    assert_eq!(compute_float_wide64(-10, 90071992547409950000000000), None);
+    assert_eq!(compute_float_wide64(-10, 90071992547409929999999999), Some((1076, 0)));
+    assert_eq!(compute_float_wide64(-10, 90071992547409930000000001), Some((1076, 1)));
+    assert_eq!(compute_float_wide64(-10, 90071992547409949999999999), Some((1076, 1)));
+    assert_eq!(compute_float_wide64(-10, 90071992547409950000000001), Some((1076, 2)));
+    assert_eq!(compute_float_wide64(0, 90071992547409930000000000), Some((1109, 739280372629505)));
+
+    // Denormal, zero and infinite values cannot be resolved.
+    assert_eq!(compute_float_wide64(-340, 12345678901234567890123), None);
+    assert_eq!(compute_float_wide64(0, 0), None);
+    assert_eq!(compute_float_wide64(-343, 1), None);
+    assert_eq!(compute_float_wide64(300, 12345678901234567890123), Some((2047, 0)));
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn compute_float_diagnostic_test() {
+    use minimal_lexical::lemire::LemireStatus;
+
+    fn status64(q: i32, w: u64) -> LemireStatus {
+        let (fp, status) = lemire::compute_float_diagnostic::<f64>(q, w);
+        assert_eq!(fp, lemire::compute_float::<f64>(q, w));
+        status
+    }
+
+    assert_eq!(status64(0, 1), LemireStatus::Accurate);
+    assert_eq!(status64(-1, 15), LemireStatus::Accurate);
+    assert_eq!(status64(22, 9007199254740993), LemireStatus::Accurate);
+    assert_eq!(status64(0, 0), LemireStatus::OutOfRange);
+    assert_eq!(status64(-400, 1), LemireStatus::OutOfRange);
+    assert_eq!(status64(400, 1), LemireStatus::OutOfRange);
+    assert_eq!(status64(309, 1), LemireStatus::OutOfRange);
+    assert_eq!(status64(-343, 1), LemireStatus::OutOfRange);
+    assert_eq!(status64(-324, 2), LemireStatus::Accurate);
+    assert_eq!(status64(-342, 9999999999999999999), LemireStatus::Accurate);
+
+    // Denormal halfway cases only occur for narrow floats.
+    let (fp, status) = lemire::compute_float_diagnostic::<f32>(-45, 7);
+    assert_eq!(fp, lemire::compute_float::<f32>(-45, 7));
+    assert_eq!(status, LemireStatus::Accurate);
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn lemire_diagnostic_test() {
+    use minimal_lexical::lemire::LemireStatus;
+    use minimal_lexical::number::Number;
+
+    // 9007199254740993 is halfway between 2^53 and 2^53 + 2, so any
+    // truncated digits determine the rounding.
+    let num = Number {
+        exponent: 0,
+        mantissa: 9007199254740993,
+        many_digits: true,
+    };
+    let (fp, status) = lemire::lemire_diagnostic::<f64>(&num);
+    assert_eq!(fp, lemire::lemire::<f64>(&num));
+    assert_eq!(status, LemireStatus::TruncatedDigits);
+    assert!(fp.exp < 0);
+
+    let num = Number {
+        exponent: 0,
+        mantissa: 15,
+        many_digits: false,
+    };
+    let (fp, status) = lemire::lemire_diagnostic::<f64>(&num);
+    assert_eq!(fp, lemire::lemire::<f64>(&num));
+    assert_eq!(status, LemireStatus::Accurate);
+}
+
+#[test]
+fn lemire_max_mantissa_test() {
+    use minimal_lexical::number::Number;
+
+    // Truncated digits never have a mantissa of `u64::MAX`, but checking
+    // `mantissa + 1` must not overflow, and uses the slow path instead.
+    let num = Number {
+        exponent: 0,
+        mantissa: u64::max_value(),
+        many_digits: true,
+    };
+    let fp = lemire::lemire::<f64>(&num);
+    assert_eq!(fp, lemire::compute_error::<f64>(0, u64::max_value()));
+    assert!(fp.exp < 0);
+}
+
+/// Create a big integer from a 128-bit power in the table.
+fn bigint_from_power((hi, lo): (u64, u64)) -> minimal_lexical::bigint::Bigint {
+    use minimal_lexical::bigint::{self, Bigint};
+
+    let mut x = Bigint::from_u64(hi);
+    x.shl(64).unwrap();
+    bigint::large_add(&mut x.data, &Bigint::from_u64(lo).data).unwrap();
+    x
+}
+
+/// Calculate the expected 128-bit power of 5, as generated by `etc/lemire_table.py`.
+///
+/// Positive powers are normalized and truncated. Negative powers
+/// are `⌊2^b / 5^-q⌋ + 1`, with `b` chosen so the value has at least
+/// 128 bits, and then truncated.
+fn expected_power(q: i32) -> minimal_lexical::bigint::Bigint {
+    use minimal_lexical::bigint::{self, Bigint};
+
+    let mut power5 = Bigint::from_u64(1);
+    power5.pow(5, q.abs() as u32).unwrap();
+    let mut value = if q >= 0 {
+        power5
+    } else {
+        let z = power5.bit_length() as usize;
+        let b = if q >= -27 {
+            z + 127
+        } else {
+            2 * z + 128
+        };
+        let mut numerator = Bigint::from_u64(1);
+        numerator.shl(b).unwrap();
+        let (quotient, _) = bigint::large_div(&numerator.data, &power5.data).unwrap();
+        let mut quotient = Bigint {
+            data: quotient,
+        };
+        bigint::small_add(&mut quotient.data, 1).unwrap();
+        quotient
+    };
+
+    // Normalize to 128 bits, truncating any low bits.
+    let bits = value.bit_length() as usize;
+    if bits < 128 {
+        value.shl(128 - bits).unwrap();
+    } else if bits > 128 {
+        let mut divisor = Bigint::from_u64(1);
+        divisor.shl(bits - 128).unwrap();
+        let (quotient, _) = bigint::large_div(&value.data, &divisor.data).unwrap();
+        value = Bigint {
+            data: quotient,
+        };
+    }
+    value
+}
+
+#[test]
+fn power_of_five_table_test() {
+    let powers = f64::powers();
+    let count = f64::LARGEST_POWER_OF_FIVE - f64::SMALLEST_POWER_OF_FIVE + 1;
+    assert_eq!(powers.len(), count as usize);
+    for (index, &power) in powers.iter().enumerate() {
+        let q = f64::SMALLEST_POWER_OF_FIVE + index as i32;
+        assert!(power.0 >> 63 == 1, "5^{} is not normalized", q);
+        assert!(bigint_from_power(power) == expected_power(q), "5^{} is incorrect", q);
+    }
+    // `f32` shares the table sized for `f64`.
+    assert_eq!(f32::powers(), powers);
+}
+
+#[test]
+fn lemire_magic_test() {
+    use minimal_lexical::bigint::Bigint;
+
+    // `⌊q * log2(10)⌋` is the largest `n` where `2^n <= 10^q`.
+    for q in f64::SMALLEST_POWER_OF_FIVE..=f64::LARGEST_POWER_OF_FIVE {
+        let mut power10 = Bigint::from_u64(1);
+        power10.pow(10, q.abs() as u32).unwrap();
+        let bits = power10.bit_length() as i32;
+        let expected = if q >= 0 {
+            bits - 1
+        } else {
+            -bits
+        };
+        assert_eq!((q * f64::LEMIRE_MAGIC) >> 16, expected, "q = {}", q);
+        assert_eq!((q * f32::LEMIRE_MAGIC) >> 16, expected, "q = {}", q);
+    }
+}
@@ -0,0 +1,451 @@
+mod rng;
+
+use core::{f32, f64, ops};
+use minimal_lexical::num::{self, Float};
+use minimal_lexical::parse_float;
+use rng::xorshift;
+
+/// Software float with the layout of an `f64`, using only the required items.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SoftF64(u64);
+
+impl ops::Neg for SoftF64 {
+    type Output = SoftF64;
+
+    fn neg(self) -> SoftF64 {
+        SoftF64(self.0 ^ f64::SIGN_MASK)
+    }
+}
+
+impl Float for SoftF64 {
+    const MAX_DIGITS: usize = f64::MAX_DIGITS;
+    const BIGINT_BITS: usize = f64::BIGINT_BITS;
+    const SIGN_MASK: u64 = f64::SIGN_MASK;
+    const EXPONENT_MASK: u64 = f64::EXPONENT_MASK;
+    const HIDDEN_BIT_MASK: u64 = f64::HIDDEN_BIT_MASK;
+    const MANTISSA_MASK: u64 = f64::MANTISSA_MASK;
+    const MANTISSA_SIZE: i32 = f64::MANTISSA_SIZE;
+    const EXPONENT_BIAS: i32 = f64::EXPONENT_BIAS;
+    const DENORMAL_EXPONENT: i32 = f64::DENORMAL_EXPONENT;
+    const MAX_EXPONENT: i32 = f64::MAX_EXPONENT;
+    const CARRY_MASK: u64 = f64::CARRY_MASK;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i32 = f64::MIN_EXPONENT_ROUND_TO_EVEN;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i32 = f64::MAX_EXPONENT_ROUND_TO_EVEN;
+    const MINIMUM_EXPONENT: i32 = f64::MINIMUM_EXPONENT;
+    const SMALLEST_POWER_OF_TEN: i32 = f64::SMALLEST_POWER_OF_TEN;
+    const LARGEST_POWER_OF_TEN: i32 = f64::LARGEST_POWER_OF_TEN;
+    const MIN_EXPONENT_FAST_PATH: i32 = f64::MIN_EXPONENT_FAST_PATH;
+    const MAX_EXPONENT_FAST_PATH: i32 = f64::MAX_EXPONENT_FAST_PATH;
+    const MAX_EXPONENT_DISGUISED_FAST_PATH: i32 = f64::MAX_EXPONENT_DISGUISED_FAST_PATH;
+
+    fn from_bits(u: u64) -> SoftF64 {
+        SoftF64(u)
+    }
+
+    fn to_bits(self) -> u64 {
+        self.0
+    }
+}
+
+fn check_exact<F: Float>(mantissa: u64, exponent: i32, expected: bool) {
+    assert_eq!(num::is_exactly_representable::<F>(mantissa, exponent), expected);
+}
+
+#[test]
+fn is_exactly_representable_test() {
+    // Zero and small integers.
+    check_exact::<f64>(0, 0, true);
+    check_exact::<f64>(0, 1000, true);
+    check_exact::<f64>(1, 0, true);
+    check_exact::<f64>(12345, 0, true);
+    check_exact::<f64>(12345, 10, true);
+    check_exact::<f64>(1, 22, true);
+    check_exact::<f64>(1, 23, false);
+    check_exact::<f64>(1, 24, false);
+    check_exact::<f32>(1, 10, true);
+    check_exact::<f32>(1, 11, false);
+
+    // Mantissa boundaries.
+    check_exact::<f64>(9007199254740992, 0, true);
+    check_exact::<f64>(9007199254740993, 0, false);
+    check_exact::<f64>(9007199254740994, 0, true);
+    check_exact::<f64>(18446744073709549568, 0, true);
+    check_exact::<f32>(16777216, 0, true);
+    check_exact::<f32>(16777217, 0, false);
+    check_exact::<f32>(16777218, 0, true);
+
+    // Negative exponents.
+    check_exact::<f64>(5, -1, true);
+    check_exact::<f64>(25, -2, true);
+    check_exact::<f64>(1, -1, false);
+    check_exact::<f64>(12345, -4, false);
+    check_exact::<f64>(15, -1, true);
+    check_exact::<f64>(7450580596923828125, -27, true);
+    check_exact::<f64>(1490116119384765625, -27, false);
+    check_exact::<f32>(5, -1, true);
+
+    // Trailing zeros are moved into the exponent.
+    check_exact::<f64>(10, -1, true);
+    check_exact::<f64>(1000000000000000000, -18, true);
+    check_exact::<f64>(1000000000000000000, -19, false);
+
+    // Powers-of-2 are removed before scaling, so the product cannot overflow.
+    check_exact::<f64>(5312512, 18, true);
+    check_exact::<f64>(2733603609953435648, 2, true);
+    check_exact::<f64>(2733603609953435649, 2, false);
+
+    // Exponent limits.
+    check_exact::<f64>(1, 1000, false);
+    check_exact::<f64>(1, -1000, false);
+    check_exact::<f64>(1, i32::max_value(), false);
+    check_exact::<f64>(1, i32::min_value(), false);
+    check_exact::<f64>(10, i32::max_value(), false);
+}
+
+#[test]
+fn is_exactly_representable_binary_test() {
+    // Check against powers-of-two, which have exact decimal representations.
+    // 2^-n == 5^n / 10^n, for n <= 27.
+    let mut power: u64 = 1;
+    for n in 0..28 {
+        check_exact::<f64>(power, -n, true);
+        check_exact::<f32>(power, -n, true);
+        power = power.wrapping_mul(5);
+    }
+    // 2^63 is exact, 2^64 - 1 is not.
+    check_exact::<f64>(1 << 63, 0, true);
+    check_exact::<f64>(0xFFFF_FFFF_FFFF_FFFF, 0, false);
+}
+
+#[test]
+fn from_mantissa_exp2_test() {
+    // Exact values.
+    assert_eq!(f64::from_mantissa_exp2(0, 100), 0.0);
+    assert_eq!(f64::from_mantissa_exp2(3, -1), 1.5);
+    assert_eq!(f64::from_mantissa_exp2(1, -1074), 5e-324);
+    assert_eq!(f64::from_mantissa_exp2(1, 1023), 8.98846567431158e307);
+    assert_eq!(f64::from_mantissa_exp2((1 << 53) - 1, 971), f64::MAX);
+    assert_eq!(f32::from_mantissa_exp2(3, -1), 1.5);
+    assert_eq!(f32::from_mantissa_exp2(1, -149), 1e-45);
+
+    // Round-nearest, tie-even.
+    assert_eq!(f64::from_mantissa_exp2((1 << 53) + 1, 0), 9007199254740992.0);
+    assert_eq!(f64::from_mantissa_exp2((1 << 53) + 3, 0), 9007199254740996.0);
+    assert_eq!(f64::from_mantissa_exp2(u64::max_value(), 0), 18446744073709551616.0);
+    assert_eq!(f32::from_mantissa_exp2((1 << 24) + 1, 0), 16777216.0);
+
+    // Denormal rounding and underflow.
+    assert_eq!(f64::from_mantissa_exp2(1, -1075), 0.0);
+    assert_eq!(f64::from_mantissa_exp2(3, -1075), 1e-323);
+    assert_eq!(f64::from_mantissa_exp2(3, -1076), 5e-324);
+    assert_eq!(f64::from_mantissa_exp2(1, -1076), 0.0);
+    assert_eq!(f64::from_mantissa_exp2(u64::max_value(), -1200), 0.0);
+    assert_eq!(f64::from_mantissa_exp2(1, i32::min_value()), 0.0);
+
+    // Overflow.
+    assert_eq!(f64::from_mantissa_exp2(1, 1024), f64::INFINITY);
+    assert_eq!(f64::from_mantissa_exp2(u64::max_value(), 960), f64::INFINITY);
+    assert_eq!(f64::from_mantissa_exp2(1, i32::max_value()), f64::INFINITY);
+    assert_eq!(f32::from_mantissa_exp2(1, 128), f32::INFINITY);
+
+    // Halfway between the largest float and `2^(MAX_EXPONENT + 1)`,
+    // where the largest float is odd, so the tie rounds to infinity.
+    assert_eq!(f64::from_mantissa_exp2((1 << 54) - 1, 970), f64::INFINITY);
+    assert_eq!(f64::from_mantissa_exp2((1 << 55) - 3, 969), f64::MAX);
+    assert_eq!(f64::from_mantissa_exp2((1 << 55) - 1, 969), f64::INFINITY);
+    assert_eq!(f32::from_mantissa_exp2((1 << 25) - 1, 103), f32::INFINITY);
+    assert_eq!(f32::from_mantissa_exp2((1 << 26) - 3, 102), f32::MAX);
+}
+
+#[test]
+fn ldexp_test() {
+    assert_eq!(Float::ldexp(1.5f64, 1), 3.0);
+    assert_eq!(Float::ldexp(-1.5f64, -1), -0.75);
+    assert_eq!(Float::ldexp(1.0f64, -1074), 5e-324);
+    assert_eq!(Float::ldexp(f64::MIN_POSITIVE, -52), 5e-324);
+    assert_eq!(Float::ldexp(5e-324f64, 1074), 1.0);
+    assert_eq!(Float::ldexp(5e-324f64, 2097), 8.98846567431158e307);
+    assert_eq!(Float::ldexp(1.5f32, -149), 3e-45);
+
+    // Denormal rounding and underflow, with ties to even.
+    assert_eq!(Float::ldexp(1.0f64, -1075), 0.0);
+    assert_eq!(Float::ldexp(1.5f64, -1075), 5e-324);
+    assert_eq!(Float::ldexp(3.0f64, -1075), 1e-323);
+    assert_eq!(Float::ldexp(-1.0f64, -1076).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(Float::ldexp(f64::MAX, i32::min_value()), 0.0);
+    assert_eq!(Float::ldexp(1.0f32, -150), 0.0);
+    assert_eq!(Float::ldexp(1.5f32, -150), 1e-45);
+
+    // Overflow.
+    assert_eq!(Float::ldexp(1.0f64, 1024), f64::INFINITY);
+    assert_eq!(Float::ldexp(-f64::MAX, 1), f64::NEG_INFINITY);
+    assert_eq!(Float::ldexp(5e-324f64, i32::max_value()), f64::INFINITY);
+    assert_eq!(Float::ldexp(1.0f32, 128), f32::INFINITY);
+
+    // Special values are unchanged.
+    assert_eq!(Float::ldexp(0.0f64, 100).to_bits(), 0);
+    assert_eq!(Float::ldexp(-0.0f64, -100).to_bits(), (-0.0f64).to_bits());
+    assert_eq!(Float::ldexp(f64::INFINITY, -2000), f64::INFINITY);
+    assert_eq!(Float::ldexp(f64::NEG_INFINITY, 0), f64::NEG_INFINITY);
+    assert!(Float::ldexp(f64::NAN, 1).is_nan());
+
+    // Multiplying by a normal power of two is also correctly rounded.
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..10_000 {
+        let x = f64::from_bits(xorshift(&mut state));
+        let exp2 = (xorshift(&mut state) % 2046) as i32 - 1022;
+        if !x.is_finite() {
+            continue;
+        }
+        let expected = x * 2f64.powi(exp2);
+        assert_eq!(Float::ldexp(x, exp2).to_bits(), expected.to_bits(), "{:e} {}", x, exp2);
+        assert_eq!(SoftF64(x.to_bits()).ldexp(exp2).0, expected.to_bits());
+
+        let x = f32::from_bits(xorshift(&mut state) as u32);
+        let exp2 = (xorshift(&mut state) % 254) as i32 - 126;
+        if x.is_finite() {
+            let expected = x * 2f32.powi(exp2);
+            assert_eq!(Float::ldexp(x, exp2).to_bits(), expected.to_bits());
+        }
+    }
+}
+
+#[test]
+fn narrow_test() {
+    // Round-nearest, tie-even, directly from the f64.
+    assert_eq!(16777217.0_f64.narrow::<f32>(), 16777216.0);
+    assert_eq!(16777219.0_f64.narrow::<f32>(), 16777220.0);
+    assert_eq!(16777217.000000002_f64.narrow::<f32>(), 16777218.0);
+    assert_eq!((-1.5_f64).narrow::<f32>(), -1.5);
+    assert_eq!(f64::MAX.narrow::<f32>(), f32::INFINITY);
+    assert_eq!(1e-50_f64.narrow::<f32>(), 0.0);
+    assert_eq!(f64::NEG_INFINITY.narrow::<f32>(), f32::NEG_INFINITY);
+    assert_eq!((-0.0_f64).narrow::<f32>().to_bits(), 0x8000_0000);
+    assert!(f64::NAN.narrow::<f32>().is_nan());
+    assert!((-f64::NAN).narrow::<f32>().is_sign_negative());
+    assert_eq!(1.5_f64.narrow::<f64>(), 1.5);
+
+    // Must match the hardware conversion, including denormals and halfway
+    // cases, for finite values near the f32 range.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..100_000 {
+        // Exponents from 2^-160 to 2^140, with random mantissas.
+        let random = xorshift(&mut state);
+        let exponent = (random >> 52) % 300 + 1023 - 160;
+        let bits = (random & !f64::EXPONENT_MASK) | (exponent << 52);
+        let double = f64::from_bits(bits);
+        assert_eq!(double.narrow::<f32>().to_bits(), (double as f32).to_bits());
+        let halfway = f64::from_bits(bits & !0x0FFF_FFFF | 0x1000_0000);
+        assert_eq!(halfway.narrow::<f32>().to_bits(), (halfway as f32).to_bits());
+    }
+}
+
+#[test]
+fn widen_test() {
+    assert_eq!(1.5_f32.widen::<f64>(), 1.5);
+    assert_eq!(f32::MAX.widen::<f64>(), f32::MAX as f64);
+    assert_eq!(f32::INFINITY.widen::<f64>(), f64::INFINITY);
+    assert_eq!((-0.0_f32).widen::<f64>().to_bits(), 0x8000_0000_0000_0000);
+    assert!(f32::NAN.widen::<f64>().is_nan());
+
+    // Must be exact for every finite f32, including denormals.
+    for bits in (0..0x7F80_0000_u32).step_by(997).chain(0..1000) {
+        let float = f32::from_bits(bits);
+        assert_eq!(float.widen::<f64>(), float as f64);
+        assert_eq!((-float).widen::<f64>().to_bits(), (-float as f64).to_bits());
+        assert_eq!(float.widen::<f64>().narrow::<f32>().to_bits(), bits);
+    }
+}
+
+#[test]
+fn next_up_test() {
+    // Called through the trait, since newer toolchains have inherent methods.
+    assert_eq!(Float::next_up(0.0_f64), 5e-324);
+    assert_eq!(Float::next_up(-0.0_f64), 5e-324);
+    assert_eq!(Float::next_up(-5e-324_f64).to_bits(), 0x8000_0000_0000_0000);
+    assert_eq!(Float::next_up(1.0_f64), 1.0 + f64::EPSILON);
+    assert_eq!(Float::next_up(-1.0_f64), -1.0 + f64::EPSILON / 2.0);
+    assert_eq!(Float::next_up(f64::MAX), f64::INFINITY);
+    assert_eq!(Float::next_up(f64::INFINITY), f64::INFINITY);
+    assert_eq!(Float::next_up(f64::NEG_INFINITY), f64::MIN);
+    assert!(Float::next_up(f64::NAN).is_nan());
+    assert_eq!(Float::next_up(0.0_f32).to_bits(), 1);
+    assert_eq!(Float::next_up(f32::MAX), f32::INFINITY);
+}
+
+#[test]
+fn next_down_test() {
+    // Crosses from the smallest normal into the denormals.
+    let largest_denormal = f64::from_bits(0x000F_FFFF_FFFF_FFFF);
+    assert_eq!(Float::next_down(f64::MIN_POSITIVE), largest_denormal);
+    assert_eq!(Float::next_up(largest_denormal), f64::MIN_POSITIVE);
+    assert_eq!(Float::next_down(0.0_f64), -5e-324);
+    assert_eq!(Float::next_down(-0.0_f64), -5e-324);
+    assert_eq!(Float::next_down(5e-324_f64).to_bits(), 0);
+    assert_eq!(Float::next_down(1.0_f64), 1.0 - f64::EPSILON / 2.0);
+    assert_eq!(Float::next_down(f64::MIN), f64::NEG_INFINITY);
+    assert_eq!(Float::next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(Float::next_down(f64::INFINITY), f64::MAX);
+    assert!(Float::next_down(f64::NAN).is_nan());
+    assert_eq!(Float::next_down(f32::MIN_POSITIVE).to_bits(), 0x007F_FFFF);
+
+    // Must round-trip for every finite f32, except the extremes.
+    for bits in (0..0x7F7F_FFFF_u32).step_by(997) {
+        let float = f32::from_bits(bits);
+        assert_eq!(Float::next_down(Float::next_up(float)).to_bits(), bits);
+        assert_eq!(Float::next_up(Float::next_down(-float)).to_bits(), (-float).to_bits());
+    }
+}
+
+#[test]
+fn ulp_test() {
+    assert_eq!(Float::ulp(1.0_f64), f64::EPSILON);
+    assert_eq!(Float::ulp(-1.0_f64), f64::EPSILON);
+    assert_eq!(Float::ulp(1.5_f64), f64::EPSILON);
+    assert_eq!(Float::ulp(0.0_f64), 5e-324);
+    assert_eq!(Float::ulp(-0.0_f64), 5e-324);
+    assert_eq!(Float::ulp(5e-324_f64), 5e-324);
+    assert_eq!(Float::ulp(f64::MIN_POSITIVE), 5e-324);
+    assert_eq!(Float::ulp(f64::MIN_POSITIVE * 2.0), 1e-323);
+    assert_eq!(Float::ulp(f64::MAX), 2.0_f64.powi(971));
+    assert_eq!(Float::ulp(f64::INFINITY), f64::INFINITY);
+    assert!(Float::ulp(f64::NAN).is_nan());
+    assert_eq!(Float::ulp(1.0_f32), f32::EPSILON);
+    assert_eq!(Float::ulp(f32::MAX), 2.0_f32.powi(104));
+
+    // Must match the gap to the next float, away from zero.
+    for bits in (0..0x7F7F_FFFF_u32).step_by(997) {
+        let float = f32::from_bits(bits);
+        assert_eq!(Float::ulp(float), Float::next_up(float) - float);
+        assert_eq!(Float::ulp(-float), Float::next_up(float) - float);
+    }
+}
+
+#[test]
+fn is_special_test() {
+    // Called through the trait, since the primitives have inherent methods.
+    fn check<F: Float>(float: F, is_inf: bool, is_nan: bool) {
+        assert_eq!(Float::is_special(float), is_inf || is_nan);
+        assert_eq!(Float::is_inf(float), is_inf);
+        assert_eq!(Float::is_nan(float), is_nan);
+        assert_eq!(Float::is_finite(float), !is_inf && !is_nan);
+    }
+
+    for &float in [0.0, -0.0, 5e-324, 1.0, -1.5, f64::MIN_POSITIVE, f64::MAX, f64::MIN].iter() {
+        check(float, false, false);
+    }
+    check(f64::INFINITY, true, false);
+    check(f64::NEG_INFINITY, true, false);
+    check(f64::NAN, false, true);
+    check(-f64::NAN, false, true);
+    check(f64::from_bits(0x7FF0_0000_0000_0001), false, true);
+    check(1e-45_f32, false, false);
+    check(f32::MAX, false, false);
+    check(f32::INFINITY, true, false);
+    check(f32::NAN, false, true);
+    check(SoftF64(1.0_f64.to_bits()), false, false);
+    check(SoftF64(f64::NEG_INFINITY.to_bits()), true, false);
+    check(SoftF64(f64::NAN.to_bits()), false, true);
+
+    // Must match the inherent methods.
+    for bits in (0..u32::max_value()).step_by(9973) {
+        let float = f32::from_bits(bits);
+        assert_eq!(Float::is_inf(float), float.is_infinite());
+        assert_eq!(Float::is_nan(float), float.is_nan());
+        assert_eq!(Float::is_finite(float), float.is_finite());
+    }
+}
+
+#[test]
+fn special_bits_test() {
+    assert_eq!(f32::INFINITY_BITS, f32::INFINITY.to_bits() as u64);
+    assert_eq!(f32::NEG_INFINITY_BITS, f32::NEG_INFINITY.to_bits() as u64);
+    assert_eq!(f32::NAN_BITS, f32::NAN.to_bits() as u64);
+    assert_eq!(f64::INFINITY_BITS, f64::INFINITY.to_bits());
+    assert_eq!(f64::NEG_INFINITY_BITS, f64::NEG_INFINITY.to_bits());
+    assert_eq!(f64::NAN_BITS, f64::NAN.to_bits());
+
+    // The defaults only depend on the masks.
+    assert_eq!(SoftF64::INFINITY_BITS, f64::INFINITY_BITS);
+    assert_eq!(SoftF64::NEG_INFINITY_BITS, f64::NEG_INFINITY_BITS);
+    assert_eq!(SoftF64::NAN_BITS, f64::NAN_BITS);
+    assert!(Float::is_nan(SoftF64(SoftF64::NAN_BITS)));
+    assert!(Float::is_inf(SoftF64(SoftF64::NEG_INFINITY_BITS)));
+}
+
+#[test]
+fn nan_bits_test() {
+    // NaNs are never equal, so compare the bits, through the trait.
+    fn check<F: Float>(bits: u64) {
+        let float: F = Float::from_bits(bits);
+        assert!(Float::is_nan(float));
+        assert_eq!(Float::to_bits(float), bits);
+        assert_eq!(Float::to_bits(-float), bits ^ F::SIGN_MASK);
+    }
+
+    // Quiet and signaling NaNs, with the smallest and largest payloads.
+    for &bits in &[0x7FF8_0000_0000_0000, 0x7FF8_0000_0000_0001, 0x7FFF_FFFF_FFFF_FFFF] {
+        check::<f64>(bits);
+        check::<f64>(bits | f64::SIGN_MASK);
+    }
+    for &bits in &[0x7FC0_0000, 0x7FC0_0001, 0x7FFF_FFFF] {
+        check::<f32>(bits);
+        check::<f32>(bits | f32::SIGN_MASK);
+    }
+    // Signaling NaNs may be quieted when returned through x87 registers.
+    #[cfg(not(all(target_arch = "x86", not(target_feature = "sse2"))))]
+    {
+        for &bits in &[0x7FF0_0000_0000_0001, 0x7FF4_0000_0000_0000] {
+            check::<f64>(bits);
+        }
+        for &bits in &[0x7F80_0001, 0x7FA0_0000] {
+            check::<f32>(bits);
+        }
+    }
+    check::<SoftF64>(0x7FF0_0000_0000_0001);
+}
+
+#[test]
+fn soft_float_test() {
+    // The default implementations must match the native arithmetic.
+    for &value in &[0, 1, 10, 12345, 9007199254740993, u64::max_value()] {
+        assert_eq!(SoftF64::from_u64(value).0, (value as f64).to_bits());
+    }
+    for exponent in 0..=22 {
+        let power = unsafe { SoftF64::pow_fast_path(exponent) };
+        assert_eq!(power.0, unsafe { f64::pow_fast_path(exponent) }.to_bits());
+    }
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..10_000 {
+        let x = f64::from_bits(xorshift(&mut state) >> (xorshift(&mut state) % 4));
+        let y = f64::from_bits(xorshift(&mut state) >> (xorshift(&mut state) % 4));
+        if !x.is_finite() || !y.is_finite() || y == 0.0 {
+            continue;
+        }
+        let (soft_x, soft_y) = (SoftF64(x.to_bits()), SoftF64(y.to_bits()));
+        assert_eq!(soft_x.mul_round(soft_y).0, (x * y).to_bits());
+        assert_eq!(soft_x.div_round(soft_y).0, (x / y).to_bits());
+    }
+
+    // Parsing must match the native float, through every algorithm.
+    let cases: &[(&str, &str, i32)] = &[
+        ("0", "", 0),
+        ("1", "5", 10),
+        ("123456789", "", -22),
+        ("9007199254740993", "", 0),
+        ("1", "", 30),
+        ("2", "2250738585072014", -308),
+        ("4", "9406564584124654", -324),
+        ("1", "7976931348623157", 308),
+        ("9007199254740993", "000000000000000000000000000001", 0),
+        ("1", "", 400),
+    ];
+    for &(integer, fraction, exponent) in cases {
+        let (integer, fraction) = (integer.as_bytes(), fraction.as_bytes());
+        let soft: SoftF64 = parse_float(integer.iter(), fraction.iter(), exponent);
+        let native: f64 = parse_float(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(soft.0, native.to_bits());
+    }
+    let soft: SoftF64 = parse_float(b"1".iter(), b"".iter(), -400);
+    assert_eq!(soft, SoftF64(0));
+}
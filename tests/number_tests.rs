@@ -1,3 +1,4 @@
+use core::{f32, f64};
 use minimal_lexical::number::Number;
 
 #[test]
@@ -48,6 +49,40 @@ fn is_fast_path_test() {
     assert_eq!(number.is_fast_path::<f64>(), false);
 }
 
+#[test]
+fn many_digits_test() {
+    use minimal_lexical::parse::moderate_path;
+
+    // Truncated digits never use the fast path.
+    let mut number = Number {
+        exponent: 0,
+        mantissa: 1234,
+        many_digits: false,
+    };
+    assert_eq!(number.try_fast_path::<f64>(), Some(1234.0));
+    number.many_digits = true;
+    assert_eq!(number.try_fast_path::<f64>(), None);
+
+    // The moderate path requires the slow path if the truncated digits
+    // could round to a different float, which is signaled with a
+    // negative exponent.
+    assert!(moderate_path::<f64>(&number).exp < 0);
+    assert!(moderate_path::<f32>(&number).exp < 0);
+    number.many_digits = false;
+    assert!(moderate_path::<f64>(&number).exp >= 0);
+    assert!(moderate_path::<f32>(&number).exp >= 0);
+
+    // Both bounds round to the same float, so the digits are not required.
+    let number = Number {
+        exponent: -18,
+        mantissa: 1234567890123456789,
+        many_digits: true,
+    };
+    assert_eq!(number.try_fast_path::<f64>(), None);
+    assert!(moderate_path::<f64>(&number).exp >= 0);
+    assert!(moderate_path::<f32>(&number).exp >= 0);
+}
+
 #[test]
 fn try_fast_path_test() {
     let mut number = Number {
@@ -85,4 +120,67 @@ fn try_fast_path_test() {
     number.exponent = 36;
     assert_eq!(number.try_fast_path::<f32>(), None);
     assert_eq!(number.try_fast_path::<f64>(), None);
+
+    // Disguised fast path, dividing trailing zeros out of the mantissa.
+    number.mantissa = 1234500000;
+    number.exponent = -15;
+    assert_eq!(number.try_fast_path::<f32>(), Some(1.2345e-6));
+    assert_eq!(number.try_fast_path::<f64>(), Some(1.2345e-6));
+
+    number.exponent = -26;
+    assert_eq!(number.try_fast_path::<f32>(), None);
+    assert_eq!(number.try_fast_path::<f64>(), Some(1.2345e-17));
+
+    number.mantissa = 90071992547409920;
+    number.exponent = 0;
+    assert_eq!(number.try_fast_path::<f64>(), Some(90071992547409920.0));
+
+    number.mantissa = 1000000;
+    number.exponent = -40;
+    assert_eq!(number.try_fast_path::<f64>(), None);
+
+    // Truncated digits are never trailing zeros.
+    number.mantissa = 1234500000;
+    number.exponent = -26;
+    number.many_digits = true;
+    assert_eq!(number.try_fast_path::<f64>(), None);
+}
+
+#[test]
+fn try_zero_or_infinity_test() {
+    let mut number = Number {
+        exponent: -4,
+        mantissa: 12345,
+        many_digits: false,
+    };
+    assert_eq!(number.try_zero_or_infinity::<f32>(), None);
+    assert_eq!(number.try_zero_or_infinity::<f64>(), None);
+
+    number.exponent = -66;
+    assert_eq!(number.try_zero_or_infinity::<f32>(), Some(0.0));
+    assert_eq!(number.try_zero_or_infinity::<f64>(), None);
+
+    number.exponent = -343;
+    assert_eq!(number.try_zero_or_infinity::<f64>(), Some(0.0));
+
+    number.exponent = 39;
+    assert_eq!(number.try_zero_or_infinity::<f32>(), Some(f32::INFINITY));
+    assert_eq!(number.try_zero_or_infinity::<f64>(), None);
+
+    number.exponent = 309;
+    assert_eq!(number.try_zero_or_infinity::<f64>(), Some(f64::INFINITY));
+
+    number.mantissa = 0;
+    number.exponent = 0;
+    assert_eq!(number.try_zero_or_infinity::<f32>(), Some(0.0));
+    assert_eq!(number.try_zero_or_infinity::<f64>(), Some(0.0));
+
+    // Truncated digits cannot change the result at the boundaries.
+    number.mantissa = 9999999999999999999;
+    number.many_digits = true;
+    number.exponent = -343;
+    assert_eq!(number.try_zero_or_infinity::<f64>(), Some(0.0));
+    number.mantissa = 1;
+    number.exponent = 309;
+    assert_eq!(number.try_zero_or_infinity::<f64>(), Some(f64::INFINITY));
 }
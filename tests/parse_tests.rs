@@ -1,10 +1,71 @@
-use core::f64;
+mod rng;
+
+#[cfg(not(feature = "no_slow_path"))]
+use core::cmp::Ordering;
+use core::{f32, f64};
+use minimal_lexical::number::Number;
 use minimal_lexical::{num, parse};
+use rng::xorshift;
+
+/// Check if the parsed float is the expected float.
+///
+/// Without the slow path, near-halfway cases may be rounded to an
+/// adjacent float, so only check the float is within 1 ULP.
+fn is_expected<F: num::Float>(expected: F, actual: F) -> bool {
+    #[cfg(not(feature = "no_slow_path"))]
+    return expected == actual;
+
+    #[cfg(feature = "no_slow_path")]
+    {
+        let (x, y) = (expected.to_bits(), actual.to_bits());
+        x.max(y) - x.min(y) <= 1
+    }
+}
 
 fn check_parse_float<F: num::Float>(integer: &str, fraction: &str, exponent: i32, expected: F) {
-    let integer = integer.as_bytes().iter();
-    let fraction = fraction.as_bytes().iter();
-    assert!(expected == parse::parse_float::<F, _, _>(integer, fraction, exponent));
+    let integer = integer.as_bytes();
+    let fraction = fraction.as_bytes();
+    assert!(is_expected(expected, parse::parse_float_slice::<F>(integer, fraction, exponent)));
+    let integer = integer.iter();
+    let fraction = fraction.iter();
+    let actual = parse::parse_float::<F, _, _>(integer.clone(), fraction.clone(), exponent);
+    assert!(is_expected(expected, actual));
+    assert!(expected == parse::parse_float_slow::<F, _, _>(integer, fraction, exponent));
+}
+
+#[test]
+fn parse_float_slice_small_test() {
+    // Few digits with small exponents use a dedicated path for slices,
+    // which must match the iterators at the boundaries of the fast path.
+    fn check<F: num::Float + core::fmt::Debug>(integer: &str, fraction: &str, exponent: i32) {
+        let (integer, fraction) = (integer.as_bytes(), fraction.as_bytes());
+        let expected = parse::parse_float::<F, _, _>(integer.iter(), fraction.iter(), exponent);
+        let actual = parse::parse_float_slice::<F>(integer, fraction, exponent);
+        assert_eq!(actual.to_bits(), expected.to_bits());
+    }
+
+    let digits = [
+        ("", ""),
+        ("0", ""),
+        ("1", "5"),
+        ("", "25"),
+        ("16777217", ""),
+        ("1677721", "7"),
+        ("9007199254740", "99"),
+        ("123456789012345", ""),
+        ("12345678901234", "56"),
+        ("1", "00000000000001"),
+    ];
+    for &(integer, fraction) in digits.iter() {
+        for exponent in -40..40 {
+            check::<f32>(integer, fraction, exponent);
+            check::<f64>(integer, fraction, exponent);
+        }
+        for &exponent in &[i32::max_value(), i32::min_value(), i32::min_value() + 1] {
+            check::<f32>(integer, fraction, exponent);
+            check::<f64>(integer, fraction, exponent);
+        }
+    }
 }
 
 #[test]
@@ -15,6 +76,10 @@ fn parse_f32_test() {
     check_parse_float("12345", "6789", 0, 12345.6789_f32);
     check_parse_float("1", "2345", 10, 1.2345e10_f32);
     check_parse_float("1", "2345", -38, 1.2345e-38_f32);
+    check_parse_float("1", "", 999999999, f32::INFINITY);
+    check_parse_float("1", "", -999999999, 0.0_f32);
+    check_parse_float("1", "", i32::max_value(), f32::INFINITY);
+    check_parse_float("1", "", i32::min_value(), 0.0_f32);
 
     // Check expected rounding, using borderline cases.
     // Round-down, halfway
@@ -53,6 +118,66 @@ fn parse_f32_test() {
     check_parse_float("", "000000000000000000000000000000000000011754943508222875079687365372222456778186655567720875215087517062784172594547271728515625", 0, 1.1754943508222875e-38f32);
 }
 
+#[test]
+fn parse_f32_double_rounding_test() {
+    // Decimals that are incorrectly rounded if first parsed to f64 and
+    // then converted to f32, since the f64 is exactly halfway between
+    // two f32 values, and ties are then rounded to even.
+    let cases: &[(&str, &str, u32)] = &[
+        // Above halfway, with an even lower float.
+        ("", "0000000000000000000000000000000000000000000035032461608120426773093239582247903282006548546912894293926707097244777067146515037165954709053039550781250000000000000000000000000000001", 0x00000003),
+        ("", "0000000000000000000000000000000000000000000063058430894616768191567831248046225907611787384443209729068072775040598720863727066898718476295471191406250000000000000000000000000000001", 0x00000005),
+        ("", "0000000000000000000000000000000000000058774724547606697022522181479760200340513934239914085458016444099262282421691772071881132433190941810607910156250000000000000000000000000000001", 0x00400001),
+        ("", "0000000000000000000000000000000000000117549414062751785924617589866280818433124586473279624003138594271817467598606476997247227700427174568176269531250000000000000000000000000000001", 0x007fffff),
+        ("", "0000000000000000000000000000000000000117549442088721072420959008340872484231447212078518461533454029413183145394428130714459257433190941810607910156250000000000000000000000000000001", 0x00800001),
+        ("1", "0000000596046447753906250000000000000000000000000000001", 0x3f800001),
+        ("16777217", "0000000000000000000000000000001", 0x4b800001),
+        ("2147483776", "0000000000000000000000000000001", 0x4f000001),
+        ("340282336497324057985868971510891282432", "0000000000000000000000000000001", 0x7f7fffff),
+        ("28306728475818768063439151562752", "0000000000000000000000000000001", 0x73b2a3fd),
+        ("46327634", "0000000000000000000000000000001", 0x4c30b9d5),
+        ("905931058619873761085755918123008", "0000000000000000000000000000001", 0x7632a9d5),
+        ("", "0000000132488460202750957250827923417091369628906250000000000000000000000000000001", 0x32639d0b),
+        ("", "000000000000000000000000000032413721450816987595398281370033835044057851220442381667830034075450384989380836486816406250000000000000000000000000000001", 0x10245b6b),
+        ("", "000000000000000000000000000000011592095296305755583876377735347869327354723779814494596463250730700167423492530360817909240722656250000000000000000000000000000001", 0x0a70c227),
+        ("", "055228980258107185363769531250000000000000000000000000000001", 0x3d6237c9),
+        ("6223721", "250000000000000000000000000000001", 0x4abdeed3),
+        ("", "039535025134682655334472656250000000000000000000000000000001", 0x3d21ef7b),
+        ("5", "12385964393615722656250000000000000000000000000000001", 0x40a3f6a9),
+        ("", "000000000000000000000000000000000000027425149958230897175962404293396521475073645007593282409358576837463518718895905124099954264238476753234863281250000000000000000000000000000001", 0x01155115),
+        ("62321207296", "0000000000000000000000000000001", 0x51682a23),
+        ("37452106763898791657472", "0000000000000000000000000000001", 0x64fdc90d),
+        ("", "000000000000000015038144363394655682189884893240616747789317741990089416503906250000000000000000000000000000001", 0x238ab3d1),
+        ("33389765959590135276124384321922400256", "0000000000000000000000000000001", 0x7dc8f515),
+        // Below halfway, with an odd lower float.
+        ("", "000000000000000000000000000000000000000000002101947696487225606385594374934874196920392912814773657635602425834686624028790902229957282543182373046874999999999999999999999999999999", 0x00000001),
+        ("", "000000000000000000000000000000000000000000004904544625136859748233053541514706459480916796567805201149738993614268789400512105203233659267425537109374999999999999999999999999999999", 0x00000003),
+        ("", "000000000000000000000000000000000000011754942807573642917278829910357665133228589927589904276829631184250030649651730385585324256680905818939208984374999999999999999999999999999999", 0x007fffff),
+        ("1", "000000178813934326171874999999999999999999999999999999", 0x3f800001),
+        ("16777218", "999999999999999999999999999999", 0x4b800001),
+        ("340282316214914454334198547563639996415", "999999999999999999999999999999", 0x7f7ffffd),
+        ("", "0000003185521535442603635601699352264404296874999999999999999999999999999999", 0x34ab0579),
+        ("", "000000000000000013653034149987367329242247959708578264326206408441066741943359374999999999999999999999999999999", 0x237bdaa1),
+        ("", "000006871976211186847649514675140380859374999999999999999999999999999999", 0x36e695d3),
+        ("", "000000000000000000000000000000000009811048287947621019928331591766525554352021499814544841372646716802508048971276366501115262508392333984374999999999999999999999999999999", 0x0550a86d),
+        ("", "00000017419181830291563528589904308319091796874999999999999999999999999999999", 0x343b097b),
+        ("", "000000000000000000002987763750767052425446641608099661302500038573271012865006923675537109374999999999999999999999999999999", 0x1d61bfc1),
+        ("487027333433995435802326590291967", "999999999999999999999999999999", 0x75c01931),
+        ("", "000000000000000013856474258561109167710821699071033208383596502244472503662109374999999999999999999999999999999", 0x237f9b59),
+        ("", "00081812028656713664531707763671874999999999999999999999999999999", 0x3a56771f),
+        ("", "0000000000000000000000000000000174128174892373547705189459835135186326889352555908542399647209730773056435282342135906219482421874999999999999999999999999999999", 0x0ab4d335),
+    ];
+    for &(integer, fraction, bits) in cases.iter() {
+        check_parse_float(integer, fraction, 0, f32::from_bits(bits));
+        let integer = integer.as_bytes().iter();
+        let fraction = fraction.as_bytes().iter();
+        let double = parse::parse_float::<f64, _, _>(integer, fraction, 0);
+        assert_ne!((double as f32).to_bits(), bits);
+        // Narrowing is a single rounding of the f64, not of the decimal.
+        assert_eq!(num::Float::narrow::<f32>(double).to_bits(), (double as f32).to_bits());
+    }
+}
+
 #[test]
 fn parse_f64_test() {
     check_parse_float("", "", 0, 0.0_f64);
@@ -61,6 +186,11 @@ fn parse_f64_test() {
     check_parse_float("12345", "6789", 0, 12345.6789_f64);
     check_parse_float("1", "2345", 10, 1.2345e10_f64);
     check_parse_float("1", "2345", -308, 1.2345e-308_f64);
+    check_parse_float("1", "", 999999999, f64::INFINITY);
+    check_parse_float("1", "", -999999999, 0.0_f64);
+    check_parse_float("1", "", i32::max_value(), f64::INFINITY);
+    check_parse_float("1", "", i32::min_value(), 0.0_f64);
+    check_parse_float("", "0000000000000000000000000001", i32::min_value(), 0.0_f64);
 
     // Check expected rounding, using borderline cases.
     // Round-down, halfway
@@ -187,3 +317,1065 @@ fn parse_f64_test() {
     check_parse_float("126769393745745060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "0", 0, 126769393745745060000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0_f64);
     check_parse_float("38652960461239320000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "0", 0, 38652960461239320000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000.0_f64);
 }
+
+#[test]
+fn parse_boundary_exponent_test() {
+    // Saturated exponents must round to 0 or infinity for any significant
+    // digits, without overflowing when scaled to the digits.
+    let many = "12345678901234567890123456789012345678901234567890";
+    let digits = [
+        ("1", ""),
+        ("", "1"),
+        ("123", "456"),
+        ("0001", "0001"),
+        ("9007199254740993", ""),
+        (many, ""),
+        ("", many),
+        (many, many),
+    ];
+    let max = i32::max_value();
+    let min = i32::min_value();
+    for &(integer, fraction) in digits.iter() {
+        for &exponent in [max, max - 1, max - 100].iter() {
+            check_parse_float(integer, fraction, exponent, f64::INFINITY);
+            check_parse_float(integer, fraction, exponent, f32::INFINITY);
+        }
+        for &exponent in [min, min + 1, min + 100].iter() {
+            check_parse_float(integer, fraction, exponent, 0.0_f64);
+            check_parse_float(integer, fraction, exponent, 0.0_f32);
+        }
+    }
+
+    let num = |exponent| Number {
+        exponent,
+        mantissa: u64::max_value(),
+        many_digits: true,
+    };
+    assert_eq!(num(max).try_zero_or_infinity::<f64>(), Some(f64::INFINITY));
+    assert_eq!(num(min).try_zero_or_infinity::<f64>(), Some(0.0));
+}
+
+/// Check the halfway point between two subnormal floats, and its neighbors.
+///
+/// The digits must end with a `5`, and the float bits for the halfway
+/// point, just above it, and just below it are expected.
+#[test]
+fn parse_overflow_halfway_test() {
+    // Halfway between the largest float and `2^(MAX_EXPONENT + 1)`,
+    // where the largest float is odd, so the tie rounds to infinity.
+    let halfway = "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792";
+    let below = "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497791";
+    check_parse_float(halfway, "", 0, f64::INFINITY);
+    check_parse_float(halfway, "0000000000000000000000000001", 0, f64::INFINITY);
+    check_parse_float(below, "", 0, f64::MAX);
+    check_parse_float(below, "9999999999999999999999999999", 0, f64::MAX);
+    check_parse_float(&halfway[..1], &halfway[1..], 308, f64::INFINITY);
+    check_parse_float("1", "7976931348623158", 308, f64::MAX);
+    check_parse_float("1", "7976931348623159", 308, f64::INFINITY);
+
+    let halfway = "340282356779733661637539395458142568448";
+    let below = "340282356779733661637539395458142568447";
+    check_parse_float(halfway, "", 0, f32::INFINITY);
+    check_parse_float(halfway, "0000000000000000000000000001", 0, f32::INFINITY);
+    check_parse_float(below, "", 0, f32::MAX);
+    check_parse_float(below, "9999999999999999999999999999", 0, f32::MAX);
+    check_parse_float(&halfway[..1], &halfway[1..], 38, f32::INFINITY);
+    check_parse_float("3", "4028235677973366", 38, f32::MAX);
+    check_parse_float("3", "4028235677973367", 38, f32::INFINITY);
+}
+
+fn check_subnormal_halfway<F: num::Float>(
+    digits: &str,
+    exponent: i32,
+    halfway: u64,
+    above: u64,
+    below: u64,
+) {
+    check_parse_float(digits, "", exponent, F::from_bits(halfway));
+    let above_digits = format!("{}1", digits);
+    check_parse_float(&above_digits, "", exponent - 1, F::from_bits(above));
+    let below_digits = format!("{}49", &digits[..digits.len() - 1]);
+    check_parse_float(&below_digits, "", exponent - 1, F::from_bits(below));
+}
+
+#[test]
+fn parse_subnormal_halfway_test() {
+    // Halfway cases at the smallest subnormals, and between the largest
+    // subnormal and the smallest normal float, which must round-to-even.
+    let f32_cases: &[(&str, i32, u64, u64, u64)] = &[
+        ("700649232162408535461864791644958065640130970938257885878534141944895541342930300743319094181060791015625", -150, 0, 1, 0),
+        ("2101947696487225606385594374934874196920392912814773657635602425834686624028790902229957282543182373046875", -150, 2, 2, 1),
+        ("3503246160812042677309323958224790328200654854691289429392670709724477706714651503716595470905303955078125", -150, 2, 3, 2),
+        ("4904544625136859748233053541514706459480916796567805201149738993614268789400512105203233659267425537109375", -150, 4, 4, 3),
+        ("11754941406275178592461758986628081843312458647327962400313859427181746759860647699724722770042717456817626953125", -150, 8388606, 8388607, 8388606),
+        ("11754942807573642917278829910357665133228589927589904276829631184250030649651730385585324256680905818939208984375", -150, 8388608, 8388608, 8388607),
+    ];
+    for &(digits, exponent, halfway, above, below) in f32_cases.iter() {
+        check_subnormal_halfway::<f32>(digits, exponent, halfway, above, below);
+    }
+
+    let f64_cases: &[(&str, i32, u64, u64, u64)] = &[
+        ("24703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125", -1075, 0, 1, 0),
+        ("74109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375", -1075, 2, 2, 1),
+        ("123516411460311636044142198217055343091264950653581191106396420625168876817552187966324959090408998094949141173861429432731664177588984949099693699002695469531575178297577851131961454291962245525922179659014249682680762501596852288391246096828118349318292403785007928846349518531559641397792756664639171692046759890077656232986317897873113832326364136100281870032427499885482997352270104140831131189286967253681695039838809652887533700881623368004844756702677687292583305671118833393020810798402309572336459201502650287654245243826958556932958231197624563118269409398181196866402119455093361742488341175449316942939628141513779978287622277536275946568454181273895934743339974841620248529105142565927256981069188614130727188467062660492956638336181640625", -1075, 2, 3, 2),
+        ("172922976044436290461799077503877480327770930915013667548954988875236427544573063152854942726572597332928797643406001205824329848624578928739571178603773657344205249616608991584746036008747143736291051522619949555753067502235593203747744535559365689045609365299011100384889325944183497956909859330494840368865463846108718726180845057022359365256909790540394618045398499839676196293178145797163583665001754155154373055774333514042547181234272715206782659383748762209616627939566366750229135117763233401271042882103710402715943341357741979706141523676674388365577173157453675612962967237130706439483677645629043720115479398119291969602671188550786325195835853783454308640675964778268347940747199592298159773496864059783018063853887724690139293670654296875", -1075, 4, 4, 3),
+        ("222507385850720064199176395546258779936602667813027328296362349540005779643539444484102225369938322261431279727704724131030539099297686371887094685146802422296858397735918514102854036197547684430319581327346934820113042116530855453208314936760676083249201067093840472615434740825730172168377656439210106482391161721588524757602313035270771562002841775343298712758123539074213191978739083589771549597066404661620550578925994422322342444472859570416955675758542375241712413480599907313780801813381104948904668664894425583448890100825972149614710420439919855653569753100552319354486638980954850896040660352681852824502078615102443513620912377597978521535770387775045705684361475530270683064113556748943345076587312006145811358486831521563686919762403704226016998291015625", -1075, 4503599627370494, 4503599627370495, 4503599627370494),
+        ("222507385850720113605740979670913197593481954635164564802342610972482222202107694551652952390813508791414915891303962110687008643869459464552765720740782062174337998814106326732925355228688137214901298112245145188984905722230728525513315575501591439747639798341180199932396254828901710708185069063066665599493827577257201576306269066333264756530000924588831643303777979186961204949739037782970490505108060994073026293712895895000358379996720725430436028407889577179615094551674824347103070260914462157228988025818254518032570701886087211312807951223342628836862232150377566662250398253433597456888442390026549819838548794829220689472168983109969836584681402285424333066033985088644580400103493397042756718644338377048603786162277173854562306587467901408672332763671875", -1075, 4503599627370496, 4503599627370496, 4503599627370495),
+    ];
+    for &(digits, exponent, halfway, above, below) in f64_cases.iter() {
+        check_subnormal_halfway::<f64>(digits, exponent, halfway, above, below);
+    }
+
+    // Short decimals near the halfway points, resolved without the slow path.
+    check_parse_float("5", "", -324, 5e-324_f64);
+    check_parse_float("2", "4703282292062328", -324, 5e-324_f64);
+    check_parse_float("2", "4703282292062327", -324, 0.0_f64);
+    check_parse_float("7", "410984687618699", -324, 1e-323_f64);
+    check_parse_float("7", "410984687618698", -324, 5e-324_f64);
+    check_parse_float("7", "006492321624085355", -46, 1e-45_f32);
+    check_parse_float("7", "006492321624085354", -46, 0.0_f32);
+}
+
+/// Check truncating and rounding up the halfway point to the smallest subnormal.
+///
+/// Any truncated halfway point rounds to 0, and any value above it
+/// rounds to the smallest subnormal, for every number of digits.
+fn check_min_subnormal<F: num::Float>(halfway: &str, exponent: i32) {
+    let (zero, min) = (F::from_bits(0), F::from_bits(1));
+    for count in 1..20 {
+        let exponent = exponent + (halfway.len() - count) as i32;
+        let below: u64 = halfway[..count].parse().unwrap();
+        check_parse_float(&below.to_string(), "", exponent, zero);
+        check_parse_float(&(below + 1).to_string(), "", exponent, min);
+        check_parse_float(&format!("{}000", below + 1), "", exponent - 3, min);
+        check_parse_float(&halfway[..count], &halfway[count..], exponent, zero);
+        check_parse_float(&halfway[..count], &format!("{}1", &halfway[count..]), exponent, min);
+    }
+}
+
+#[test]
+fn parse_min_subnormal_test() {
+    let f32_halfway = "700649232162408535461864791644958065640130970938257885878534141944895541342930300743319094181060791015625";
+    check_min_subnormal::<f32>(f32_halfway, -150);
+    let f64_halfway = "24703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+    check_min_subnormal::<f64>(f64_halfway, -1075);
+
+    // Below the smallest power of 10, the float is always 0.
+    check_parse_float("9999999999999999999", "", -343, 0.0_f64);
+    check_parse_float("9999999999999999999", "", -66, 0.0_f32);
+    check_parse_float("2470328229206232721", "", -342, 5e-324_f64);
+    check_parse_float("2470328229206232720", "", -342, 0.0_f64);
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn parse_float_diagnostic_test() {
+    use minimal_lexical::parse::ParsePath;
+
+    fn check<F: num::Float + core::fmt::Debug>(
+        integer: &str,
+        fraction: &str,
+        exponent: i32,
+        expected: (F, ParsePath),
+    ) {
+        let integer = integer.as_bytes().iter();
+        let fraction = fraction.as_bytes().iter();
+        let result = parse::parse_float_diagnostic::<F, _, _>(integer, fraction, exponent);
+        assert_eq!(result, expected);
+    }
+
+    check("1", "2345", 0, (1.2345_f64, ParsePath::Fast));
+    check("1", "2345", 100, (1.2345e100_f64, ParsePath::Moderate));
+    check("100", "", -2, (1_f64, ParsePath::Fast));
+    check("12000", "", -3, (12_f64, ParsePath::Fast));
+    check("12300", "", -2, (123_f64, ParsePath::Fast));
+    check("100", "", -24, (1e-22_f64, ParsePath::Fast));
+    check("12000", "", -13, (1.2e-9_f32, ParsePath::Fast));
+    check("1000000", "", -25, (1e-19_f64, ParsePath::Fast));
+    check("90071992547409920", "", 0, (90071992547409920_f64, ParsePath::Fast));
+    check("1234500000", "", -15, (1.2345e-6_f32, ParsePath::Fast));
+    check("9007199254740993", "", 0, (9007199254740992_f64, ParsePath::Moderate));
+    #[cfg(not(feature = "no_slow_path"))]
+    {
+        let (f64_value, f32_value) = (9007199254740994_f64, 16777218_f32);
+        let fraction = "0000000000000000000000000000001";
+        check("9007199254740993", fraction, 0, (f64_value, ParsePath::Slow));
+        check("16777217", fraction, 0, (f32_value, ParsePath::Slow));
+    }
+    #[cfg(feature = "no_slow_path")]
+    {
+        // Without the slow path, these round to the adjacent float.
+        let (f64_value, f32_value) = (9007199254740992_f64, 16777216_f32);
+        let fraction = "0000000000000000000000000000001";
+        check("9007199254740993", fraction, 0, (f64_value, ParsePath::Moderate));
+        check("16777217", fraction, 0, (f32_value, ParsePath::Moderate));
+    }
+    #[cfg(all(feature = "wide_mantissa", not(any(feature = "compact", feature = "bellerophon"))))]
+    {
+        // At most 38 significant digits never need the slow path,
+        // unless they're exactly halfway with a negative exponent.
+        let (f64_value, f32_value) = (9007199254740994_f64, 16777218_f32);
+        check("9007199254740993", "0000000000000000001", 0, (f64_value, ParsePath::Moderate));
+        check("16777217", "0000000000000000001", 0, (f32_value, ParsePath::Moderate));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_float_slow_test() {
+    // Cross-validate the fast and moderate paths against the slow path,
+    // using a simple xorshift generator for reproducible digits.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || xorshift(&mut state);
+    for _ in 0..2000 {
+        let count = (next() % 25) as usize + 1;
+        let digits: Vec<u8> = (0..count).map(|_| b'0' + (next() % 10) as u8).collect();
+        let integer = &digits[..(next() as usize % count)];
+        let fraction = &digits[integer.len()..];
+        let integer = &integer[integer.iter().take_while(|&&c| c == b'0').count()..];
+        let exponent = (next() % 700) as i32 - 350;
+
+        let f64_value = parse::parse_float::<f64, _, _>(integer.iter(), fraction.iter(), exponent);
+        let f64_slow =
+            parse::parse_float_slow::<f64, _, _>(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(f64_value.to_bits(), f64_slow.to_bits());
+
+        let f32_value = parse::parse_float::<f32, _, _>(integer.iter(), fraction.iter(), exponent);
+        let f32_slow =
+            parse::parse_float_slow::<f32, _, _>(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(f32_value.to_bits(), f32_slow.to_bits());
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_float_many_digits_test() {
+    // Cross-validate inputs with 20 to 40 significant digits, which
+    // use the wide mantissa algorithm with the `wide_mantissa` feature.
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut next = || xorshift(&mut state);
+    for _ in 0..2000 {
+        let count = (next() % 21) as usize + 20;
+        let mut digits: Vec<u8> = (0..count).map(|_| b'0' + (next() % 10) as u8).collect();
+        digits[0] = b'1' + (next() % 9) as u8;
+        let (integer, fraction) = digits.split_at(next() as usize % count);
+        let exponent = (next() % 700) as i32 - 350;
+
+        let f64_value = parse::parse_float::<f64, _, _>(integer.iter(), fraction.iter(), exponent);
+        let f64_slow =
+            parse::parse_float_slow::<f64, _, _>(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(f64_value.to_bits(), f64_slow.to_bits());
+
+        let f32_value = parse::parse_float::<f32, _, _>(integer.iter(), fraction.iter(), exponent);
+        let f32_slow =
+            parse::parse_float_slow::<f32, _, _>(integer.iter(), fraction.iter(), exponent);
+        assert_eq!(f32_value.to_bits(), f32_slow.to_bits());
+    }
+}
+
+#[test]
+#[cfg(feature = "no_slow_path")]
+fn parse_float_no_slow_path_test() {
+    // Anything the moderate path can resolve is still correctly rounded.
+    let parse = |i: &str, f: &str, e: i32| -> f64 {
+        parse::parse_float::<f64, _, _>(i.as_bytes().iter(), f.as_bytes().iter(), e)
+    };
+    assert_eq!(parse("1", "2345", 100), 1.2345e100);
+    assert_eq!(parse("9007199254740993", "", 0), 9007199254740992.0);
+    assert_eq!(parse("2", "2250738585072014", -308), 2.2250738585072014e-308);
+
+    // Near-halfway cases round to the moderate path approximation.
+    let value = parse("9007199254740993", "0000000000000000000000000000001", 0);
+    assert_eq!(value, 9007199254740992.0);
+}
+
+#[test]
+fn parse_empty_digits_test() {
+    // Empty integer, as in ".5".
+    check_parse_float("", "5", 0, 0.5_f64);
+    check_parse_float("", "5", 0, 0.5_f32);
+    check_parse_float("", "5", -400, 0.0_f64);
+    check_parse_float("", "5", 400, f64::INFINITY);
+    check_parse_float("", "0000000000000000000000000000001", 31, 1.0_f64);
+    check_parse_float("", "12345678901234567890123", 23, 12345678901234567890123.0_f64);
+    check_parse_float("", "90071992547409930000000000000001", 16, 9007199254740994_f64);
+
+    // Empty fraction, as in "5.".
+    check_parse_float("5", "", 0, 5.0_f64);
+    check_parse_float("5", "", 0, 5.0_f32);
+    check_parse_float("5", "", -400, 0.0_f64);
+    check_parse_float("5", "", 400, f64::INFINITY);
+    check_parse_float("12345678901234567890123", "", -23, 0.12345678901234568_f64);
+    check_parse_float("90071992547409930000000000000001", "", -16, 9007199254740994_f64);
+
+    // Both empty, which is always 0.
+    for &exponent in [0, 1, -1, 400, -400, i32::max_value(), i32::min_value()].iter() {
+        check_parse_float("", "", exponent, 0.0_f64);
+        check_parse_float("", "", exponent, 0.0_f32);
+    }
+    check_parse_number("", "", i32::min_value(), (0, i32::min_value(), false));
+    check_parse_number("", "5", i32::min_value(), (5, i32::min_value(), false));
+    check_parse_number("5", "", i32::max_value(), (5, i32::max_value(), false));
+}
+
+fn check_parse_integer_float<F: num::Float + core::fmt::Debug>(integer: &str) {
+    let integer = integer.as_bytes();
+    let expected = parse::parse_float::<F, _, _>(integer.iter(), [].iter(), 0);
+    assert_eq!(parse::parse_integer_float::<F, _>(integer.iter()), expected);
+    for &exponent in &[0, 1, -1, 22, -22, 300, -330, i32::max_value(), i32::min_value()] {
+        let expected = parse::parse_float::<F, _, _>(integer.iter(), [].iter(), exponent);
+        assert_eq!(parse::parse_integer_mantissa::<F, _>(integer.iter(), exponent), expected);
+    }
+}
+
+#[test]
+fn parse_float_chars_test() {
+    fn check<F: num::Float + core::fmt::Debug>(integer: &str, fraction: &str, exponent: i32) {
+        let (bytes1, bytes2) = (integer.as_bytes(), fraction.as_bytes());
+        let expected = parse::parse_float::<F, _, _>(bytes1.iter(), bytes2.iter(), exponent);
+        let (chars1, chars2) = (integer.chars(), fraction.chars());
+        let actual = parse::parse_float_chars::<F, _, _>(chars1, chars2, exponent);
+        assert_eq!(actual.to_bits(), expected.to_bits());
+    }
+
+    let digits = [
+        ("", ""),
+        ("1", "2345"),
+        ("9007199254740993", ""),
+        ("9007199254740993", "0000000000000000000000000000001"),
+        ("123456789012345678901234567890", "123"),
+        ("", "000000000000000000000000000000012345"),
+    ];
+    for &(integer, fraction) in digits.iter() {
+        for &exponent in &[0, 10, -10, 300, -330, i32::max_value(), i32::min_value()] {
+            check::<f32>(integer, fraction, exponent);
+            check::<f64>(integer, fraction, exponent);
+        }
+    }
+}
+
+#[test]
+fn saturate_exponent_test() {
+    assert_eq!(parse::saturate_exponent(0), 0);
+    assert_eq!(parse::saturate_exponent(-400), -400);
+    assert_eq!(parse::saturate_exponent(i32::max_value() as i64), i32::max_value());
+    assert_eq!(parse::saturate_exponent(i32::min_value() as i64), i32::min_value());
+    assert_eq!(parse::saturate_exponent(i32::max_value() as i64 + 1), i32::max_value());
+    assert_eq!(parse::saturate_exponent(i32::min_value() as i64 - 1), i32::min_value());
+    assert_eq!(parse::saturate_exponent(i64::max_value()), i32::max_value());
+    assert_eq!(parse::saturate_exponent(i64::min_value()), i32::min_value());
+}
+
+#[test]
+fn parse_float_exp64_test() {
+    fn parse<F: num::Float>(integer: &str, fraction: &str, exponent: i64) -> F {
+        let (integer, fraction) = (integer.as_bytes(), fraction.as_bytes());
+        parse::parse_float_exp64::<F, _, _>(integer.iter(), fraction.iter(), exponent)
+    }
+
+    // Exponents within an `i32` are identical to `parse_float`.
+    for &exponent in &[0, 1, -1, 40, -40, 300, 400, -330, i32::max_value(), i32::min_value()] {
+        let expected = parse::parse_float::<f64, _, _>(b"12".iter(), b"5".iter(), exponent);
+        assert_eq!(parse::<f64>("12", "5", exponent as i64), expected);
+    }
+    assert_eq!(parse::<f32>("1", "", 38), 1e38);
+    assert_eq!(parse::<f32>("1", "", 40), f32::INFINITY);
+    assert_eq!(parse::<f64>("1", "", 40), 1e40);
+    assert_eq!(parse::<f64>("1", "", 400), f64::INFINITY);
+
+    // Exponents beyond an `i32` are 0 or infinity.
+    let huge = i32::max_value() as i64 + 1;
+    assert_eq!(parse::<f64>("1", "", huge), f64::INFINITY);
+    assert_eq!(parse::<f64>("", "1", 1 << 40), f64::INFINITY);
+    assert_eq!(parse::<f64>("1", "", i64::max_value()), f64::INFINITY);
+    assert_eq!(parse::<f32>("1", "", i64::max_value()), f32::INFINITY);
+    assert_eq!(parse::<f64>("1", "", -huge), 0.0);
+    assert_eq!(parse::<f64>("12345678901234567890123", "", -1 << 40), 0.0);
+    assert_eq!(parse::<f64>("1", "", i64::min_value()), 0.0);
+    assert_eq!(parse::<f32>("1", "", i64::min_value()), 0.0);
+
+    // Zero is always 0, for any exponent.
+    assert_eq!(parse::<f64>("", "", i64::max_value()), 0.0);
+    assert_eq!(parse::<f64>("0", "", i64::max_value()), 0.0);
+}
+
+#[test]
+fn parse_integer_float_test() {
+    let integers = [
+        "",
+        "1",
+        "12345",
+        "16777216",
+        "16777217",
+        "16777218",
+        "9007199254740992",
+        "9007199254740993",
+        "9007199254740994",
+        "18014398509481984",
+        "9999999999999999999",
+        "18446744073709551615",
+        "18446744073709551616",
+        "11417981541647680316116887983825362587765178369",
+    ];
+    for integer in integers.iter() {
+        check_parse_integer_float::<f32>(integer);
+        check_parse_integer_float::<f64>(integer);
+    }
+
+    let float: f64 = parse::parse_integer_float(b"9007199254740992".iter());
+    assert_eq!(float, 9007199254740992.0);
+    let float: f64 = parse::parse_integer_float(b"9007199254740993".iter());
+    assert_eq!(float, 9007199254740992.0);
+    let float: f32 = parse::parse_integer_float(b"16777217".iter());
+    assert_eq!(float, 16777216.0);
+}
+
+fn check_parse_number(integer: &str, fraction: &str, exponent: i32, expected: (u64, i32, bool)) {
+    let integer = integer.as_bytes().iter();
+    let fraction = fraction.as_bytes().iter();
+    let expected = Number {
+        mantissa: expected.0,
+        exponent: expected.1,
+        many_digits: expected.2,
+    };
+    assert_eq!(parse::parse_number(integer, fraction, exponent), expected);
+}
+
+#[test]
+fn parse_number_test() {
+    check_parse_number("", "", 0, (0, 0, false));
+    check_parse_number("1", "2345", 0, (12345, -4, false));
+    check_parse_number("1234567890123456789", "", 5, (1234567890123456789, 5, false));
+    check_parse_number("123456789", "0123456789", 0, (1234567890123456789, -10, false));
+    check_parse_number("", "0001", 0, (1, -4, false));
+
+    // Truncated integer digits.
+    check_parse_number("12345678901234567891", "", 0, (1234567890123456789, 1, true));
+    check_parse_number("123456789012345678901234", "5", 0, (1234567890123456789, 5, true));
+    check_parse_number("123456789012345678900001", "", 0, (1234567890123456789, 5, true));
+
+    // Truncated integer zeros do not lose any significant digits.
+    check_parse_number("12345678901234567890", "", 0, (1234567890123456789, 1, false));
+    check_parse_number("123456789012345678900000", "", -3, (1234567890123456789, 2, false));
+    check_parse_number("123456789012345678900000", "5", 0, (1234567890123456789, 5, true));
+
+    // Truncated fraction digits.
+    check_parse_number("1", "2345678901234567890", 0, (1234567890123456789, -18, true));
+    check_parse_number("", "00012345678901234567890", 0, (1234567890123456789, -22, true));
+    let fraction = format!("2{}1", "0".repeat(1000));
+    check_parse_number("1", &fraction, 0, (1200000000000000000, -18, true));
+}
+
+/// Digit iterator which counts the number of digits visited.
+#[derive(Clone)]
+struct CountingIter<'a> {
+    iter: core::slice::Iter<'a, u8>,
+    count: std::rc::Rc<core::cell::Cell<usize>>,
+}
+
+impl<'a> Iterator for CountingIter<'a> {
+    type Item = &'a u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if item.is_some() {
+            self.count.set(self.count.get() + 1);
+        }
+        item
+    }
+}
+
+#[test]
+fn parse_number_single_pass_test() {
+    // Long fractions are only visited up to the truncated digits.
+    let fraction = format!("2{}1", "0".repeat(1000));
+    let count = std::rc::Rc::new(core::cell::Cell::new(0));
+    let iter = CountingIter {
+        iter: fraction.as_bytes().iter(),
+        count: count.clone(),
+    };
+    let num = parse::parse_number(b"1".iter(), iter, 0);
+    assert!(num.many_digits);
+    assert!(count.get() <= 40);
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_trailing_integer_zeros_test() {
+    // Halfway cases, and just above and below, with exact, truncated zeros.
+    let integers = [
+        "90071992547409930000",
+        "900719925474099300000000",
+        "90071992547409930001",
+        "90071992547409929999",
+        "167772170000000000000",
+        "167772170000000000000000000",
+        "123456789012345678900000000000",
+    ];
+    for integer in integers.iter() {
+        let digits = integer.as_bytes();
+        let float32 = parse::parse_float::<f32, _, _>(digits.iter(), [].iter(), 0);
+        let float64 = parse::parse_float::<f64, _, _>(digits.iter(), [].iter(), 0);
+        assert_eq!(float32, integer.parse::<f32>().unwrap());
+        assert_eq!(float64, integer.parse::<f64>().unwrap());
+    }
+}
+
+#[test]
+fn to_digit_test() {
+    assert_eq!(parse::to_digit(b'0'), Some(0));
+    assert_eq!(parse::to_digit(b'9'), Some(9));
+    assert_eq!(parse::to_digit(b'a'), None);
+    assert_eq!(parse::to_digit(b'.'), None);
+    assert_eq!(parse::to_digit(b'/'), None);
+    assert_eq!(parse::to_digit(b':'), None);
+
+    assert_eq!(parse::to_digit_radix(b'1', 2), Some(1));
+    assert_eq!(parse::to_digit_radix(b'2', 2), None);
+    assert_eq!(parse::to_digit_radix(b'9', 16), Some(9));
+    assert_eq!(parse::to_digit_radix(b'a', 16), Some(10));
+    assert_eq!(parse::to_digit_radix(b'F', 16), Some(15));
+    assert_eq!(parse::to_digit_radix(b'g', 16), None);
+    assert_eq!(parse::to_digit_radix(b'G', 16), None);
+    assert_eq!(parse::to_digit_radix(b'z', 36), Some(35));
+    assert_eq!(parse::to_digit_radix(b'@', 36), None);
+    assert_eq!(parse::to_digit_radix(b'[', 36), None);
+}
+
+#[test]
+fn add_digit_test() {
+    assert_eq!(parse::add_digit(0, 1), Some(1));
+    assert_eq!(parse::add_digit(12, 3), Some(123));
+    assert_eq!(parse::add_digit(1844674407370955161, 5), Some(18446744073709551615));
+    assert_eq!(parse::add_digit(1844674407370955161, 6), None);
+    assert_eq!(parse::add_digit(1844674407370955162, 0), None);
+
+    assert_eq!(parse::add_digit_radix(0xF, 0xF, 16), Some(0xFF));
+    assert_eq!(parse::add_digit_radix(0x0FFF_FFFF_FFFF_FFFF, 0xF, 16), Some(0xFFFF_FFFF_FFFF_FFFF));
+    assert_eq!(parse::add_digit_radix(0x1000_0000_0000_0000, 0, 16), None);
+    assert_eq!(parse::add_digit_radix(1, 1, 2), Some(3));
+}
+
+fn parse_truncated(integer: &str, fraction: &str, exponent: i32, max_digits: usize) -> f64 {
+    let integer = integer.as_bytes().iter();
+    let fraction = fraction.as_bytes().iter();
+    parse::parse_float_truncated::<f64, _, _>(integer, fraction, exponent, max_digits)
+}
+
+#[test]
+fn parse_leading_zeros_test() {
+    // Leading zeros in the integer are skipped, and give identical results.
+    let zeros = "0".repeat(30);
+    let cases: &[(&str, &str, i32)] = &[
+        ("1", "0", 0),
+        ("1", "", 0),
+        ("", "5", 0),
+        ("", "", 0),
+        ("9007199254740993", "", 0),
+        ("9007199254740993", "0000000000000000001", 0),
+        ("12345678901234567890123", "456", -10),
+        ("2", "4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125", -308),
+    ];
+    for &(integer, fraction, exponent) in cases.iter() {
+        let padded = format!("{}{}", zeros, integer);
+        let parse = |integer: &str| -> (f64, f32, f64) {
+            let integer = integer.as_bytes().iter();
+            let fraction = fraction.as_bytes().iter();
+            (
+                parse::parse_float(integer.clone(), fraction.clone(), exponent),
+                parse::parse_float(integer.clone(), fraction.clone(), exponent),
+                parse::parse_float_truncated(integer, fraction, exponent, 17),
+            )
+        };
+        assert_eq!(parse(&padded), parse(integer));
+        assert_eq!(parse(&padded[26..]), parse(integer));
+
+        #[cfg(not(feature = "no_slow_path"))]
+        {
+            let parse_slow = |integer: &str| -> f64 {
+                let integer = integer.as_bytes().iter();
+                parse::parse_float_slow(integer, fraction.as_bytes().iter(), exponent)
+            };
+            assert_eq!(parse_slow(&padded), parse_slow(integer));
+        }
+    }
+    check_parse_number("00001", "0", 0, (10, -1, false));
+    check_parse_number("00000000000000000000001", "", 0, (1, 0, false));
+}
+
+#[test]
+fn parse_float_truncated_test() {
+    // No truncated digits.
+    assert_eq!(parse_truncated("1", "2345", 0, 17), 1.2345);
+    assert_eq!(parse_truncated("1", "2345", 0, 5), 1.2345);
+    assert_eq!(parse_truncated("", "", 0, 5), 0.0);
+
+    // Truncated integer and fraction digits.
+    assert_eq!(parse_truncated("1", "23456", 0, 3), 1.23);
+    assert_eq!(parse_truncated("123456", "", 0, 3), 123000.0);
+    assert_eq!(parse_truncated("123456", "789", 0, 3), 123000.0);
+    assert_eq!(parse_truncated("", "000123456", 0, 3), 0.000123);
+    assert_eq!(parse_truncated("", "000123456", 10, 3), 1230000.0);
+
+    // The discarded digits are a sticky bit for halfway cases.
+    assert_eq!(parse_truncated("90071992547409930", "", -1, 16), 9007199254740992.0);
+    #[cfg(not(feature = "no_slow_path"))]
+    {
+        let value = 9007199254740994.0;
+        assert_eq!(parse_truncated("90071992547409931", "", -1, 16), value);
+        assert_eq!(parse_truncated("9007199254740993", "000000000000001", 0, 16), value);
+        assert_eq!(parse_truncated("", "9007199254740993000001", 16, 16), value);
+    }
+
+    // 17 significant digits recovers the float from its exact digits.
+    let values = [
+        0.1_f64,
+        0.2,
+        0.3,
+        1.0 / 3.0,
+        f64::consts::PI,
+        f64::consts::E,
+        f64::consts::SQRT_2,
+        123.456,
+        6.02214076e23,
+        2.2250738585072014e-308,
+        5e-324,
+        1.7976931348623157e308,
+    ];
+    for &value in values.iter() {
+        let digits = format!("{:.60e}", value);
+        let (mantissa, exponent) = digits.split_at(digits.find('e').unwrap());
+        let exponent: i32 = exponent[1..].parse().unwrap();
+        let fraction = mantissa[2..].trim_end_matches('0');
+        assert_eq!(parse_truncated(&mantissa[..1], fraction, exponent, 17), value);
+    }
+}
+
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_parts<F: num::Float>(
+    integer: &str,
+    fraction: &str,
+    exponent: i32,
+) -> (u64, i32, Ordering) {
+    let parts = parse::NumberParts {
+        is_positive: true,
+        integer: integer.as_bytes(),
+        fraction: fraction.as_bytes(),
+        exponent,
+    };
+    let fp = parse::parse_to_parts::<F>(parts);
+    (fp.mant, fp.exp, fp.rounding)
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_to_parts_test() {
+    use Ordering::{Equal, Greater, Less};
+
+    assert_eq!(parse_parts::<f64>("0", "", 0), (0, -1074, Equal));
+    assert_eq!(parse_parts::<f64>("000", "000", 500), (0, -1074, Equal));
+    assert_eq!(parse_parts::<f64>("1", "5", 0), (0x18000000000000, -52, Equal));
+    assert_eq!(parse_parts::<f64>("0", "1", 0), (0x1999999999999A, -56, Greater));
+    assert_eq!(parse_parts::<f64>("9007199254740993", "", 0), (1 << 52, 1, Less));
+    assert_eq!(parse_parts::<f64>("9007199254740995", "", 0), ((1 << 52) + 2, 1, Greater));
+    assert_eq!(parse_parts::<f64>("18446744073709551616", "", 0), (1 << 52, 12, Equal));
+    assert_eq!(parse_parts::<f64>("5", "", -324), (1, -1074, Less));
+    assert_eq!(parse_parts::<f64>("2", "", -400), (0, -1074, Less));
+    assert_eq!(parse_parts::<f64>("1", "", 400), (1 << 52, 972, Greater));
+    assert_eq!(parse_parts::<f32>("16777217", "", 0), (1 << 23, 1, Less));
+    assert_eq!(parse_parts::<f32>("1", "", 40), (1 << 23, 105, Greater));
+
+    // More significant digits than are compared exactly.
+    let nines = "9".repeat(800);
+    assert_eq!(parse_parts::<f64>("9007199254740991", &nines, 0), (1 << 52, 1, Greater));
+    let halfway = format!("{}1", "0".repeat(800));
+    assert_eq!(parse_parts::<f64>("9007199254740993", &halfway, 0), ((1 << 52) + 1, 1, Greater));
+
+    let parts = parse::NumberParts {
+        is_positive: false,
+        integer: b"1",
+        fraction: b"",
+        exponent: 0,
+    };
+    let expected = parse::FloatParts {
+        is_positive: false,
+        mant: 1 << 52,
+        exp: -52,
+        rounding: Equal,
+    };
+    assert_eq!(parse::parse_to_parts::<f64>(parts), expected);
+}
+
+fn try_parse_exact<F: num::Float>(integer: &str, fraction: &str, exponent: i32) -> Option<F> {
+    let integer = integer.as_bytes();
+    let fraction = fraction.as_bytes();
+    parse::try_parse_exact::<F, _, _>(integer.iter(), fraction.iter(), exponent)
+}
+
+#[test]
+fn try_parse_exact_test() {
+    assert!(try_parse_exact::<f64>("0", "5", 0) == Some(0.5));
+    assert!(try_parse_exact::<f64>("0", "25", 0) == Some(0.25));
+    assert!(try_parse_exact::<f64>("0", "1", 0).is_none());
+    assert!(try_parse_exact::<f32>("0", "1", 0).is_none());
+    assert!(try_parse_exact::<f64>("", "", 0) == Some(0.0));
+    assert!(try_parse_exact::<f64>("0", "", 400) == Some(0.0));
+    assert!(try_parse_exact::<f64>("1", "", 400).is_none());
+    assert!(try_parse_exact::<f64>("1", "", -400).is_none());
+    assert!(try_parse_exact::<f64>("1", "", 22) == Some(1e22));
+    assert!(try_parse_exact::<f64>("1", "", 23).is_none());
+    assert!(try_parse_exact::<f64>("9007199254740992", "", 0) == Some(9007199254740992.0));
+    assert!(try_parse_exact::<f64>("9007199254740993", "", 0).is_none());
+    assert!(try_parse_exact::<f32>("16777216", "", 0) == Some(16777216.0));
+    assert!(try_parse_exact::<f32>("16777217", "", 0).is_none());
+    assert!(try_parse_exact::<f32>("5", "", -45).is_none());
+    assert!(try_parse_exact::<f64>("5", "312512", 24) == Some(5.312512e24));
+    assert!(try_parse_exact::<f64>("2733603609953435648", "", 2) == Some(273360360995343564800.0));
+
+    // More than 19 significant digits, which require the slow path.
+    let is_exact = cfg!(not(feature = "no_slow_path"));
+    let float = try_parse_exact::<f64>("18446744073709551616", "", 0);
+    assert!(float == Some(18446744073709551616.0).filter(|_| is_exact));
+    assert!(try_parse_exact::<f64>("18446744073709551617", "", 0).is_none());
+    let float =
+        try_parse_exact::<f64>("0", "1000000000000000055511151231257827021181583404541015625", 0);
+    assert!(float == Some(0.1).filter(|_| is_exact));
+    let float =
+        try_parse_exact::<f64>("0", "10000000000000000555111512312578270211815834045410156251", 0);
+    assert!(float.is_none());
+    let denormal = "40129846432481707092372958328991613128026194187651577175706828388979108268586060148663818836212158203125";
+    let float = try_parse_exact::<f32>("1", denormal, -45);
+    assert!(float == Some(1e-45).filter(|_| is_exact));
+    assert!(try_parse_exact::<f32>("1", &denormal[..100], -45).is_none());
+
+    // Many digits are rounded-up when truncated, which must not be exact.
+    let nines = "9".repeat(1000);
+    assert!(try_parse_exact::<f64>("0", &format!("4{}", nines), 0).is_none());
+    assert!(try_parse_exact::<f32>("0", &format!("4{}", nines), 0).is_none());
+}
+
+fn parse_float_parts<F: num::Float>(sign: bool, integer: &str, fraction: &str, exponent: i32) -> F {
+    parse::parse_float_parts(parse::NumberParts {
+        is_positive: sign,
+        integer: integer.as_bytes(),
+        fraction: fraction.as_bytes(),
+        exponent,
+    })
+}
+
+#[test]
+fn parse_float_parts_test() {
+    assert_eq!(parse_float_parts::<f64>(true, "1", "2345", 0), 1.2345);
+    assert_eq!(parse_float_parts::<f64>(false, "1", "2345", 0), -1.2345);
+    assert_eq!(parse_float_parts::<f64>(true, "000123", "4500000", -2), 1.2345);
+    assert_eq!(parse_float_parts::<f32>(false, "0", "0015", 3), -1.5);
+    assert_eq!(parse_float_parts::<f64>(true, "", "5", 0), 0.5);
+    assert_eq!(parse_float_parts::<f64>(true, "5", "", 0), 5.0);
+    assert_eq!(parse_float_parts::<f64>(true, "1", "", 400), f64::INFINITY);
+    assert_eq!(parse_float_parts::<f64>(false, "1", "", 400), f64::NEG_INFINITY);
+
+    // Many zeros must be trimmed, and not count as significant digits.
+    let zeros = "0".repeat(1000);
+    let integer = format!("{}9007199254740993", zeros);
+    let float = parse_float_parts::<f64>(true, &integer, &zeros, 0);
+    assert_eq!(float, 9007199254740992.0);
+
+    // Empty or zero digits are zero, with the sign, for any exponent.
+    for &exponent in &[0, 400, -400, i32::max_value(), i32::min_value()] {
+        for &(integer, fraction) in &[("", ""), ("0", ""), ("", "0"), ("000", "000")] {
+            let float = parse_float_parts::<f64>(true, integer, fraction, exponent);
+            assert_eq!(float.to_bits(), 0);
+            let float = parse_float_parts::<f64>(false, integer, fraction, exponent);
+            assert_eq!(float.to_bits(), 0x8000_0000_0000_0000);
+            let float = parse_float_parts::<f32>(false, integer, fraction, exponent);
+            assert_eq!(float.to_bits(), 0x8000_0000);
+        }
+    }
+}
+
+#[test]
+fn parse_float_total_test() {
+    assert_eq!(parse::parse_float_total::<f64>(b"0012", b"5000", 0), 12.5);
+    assert_eq!(parse::parse_float_total::<f64>(b"12", b"5e3", 0), 12.5);
+    assert_eq!(parse::parse_float_total::<f64>(b"12.5", b"5", 1), 120.0);
+    assert_eq!(parse::parse_float_total::<f64>(b"", b"", 0), 0.0);
+    assert_eq!(parse::parse_float_total::<f64>(b"\xff1", b"5", 0), 0.0);
+    assert_eq!(parse::parse_float_total::<f32>(b"1", b"5\x00", 400), f32::INFINITY);
+    assert_eq!(parse::parse_float_total::<f64>(b"1", b"", i32::min_value()), 0.0);
+
+    // Arbitrary bytes, which must never panic.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || xorshift(&mut state);
+    for _ in 0..1_000 {
+        let length = (next() % 1_000) as usize;
+        let bytes: Vec<u8> = (0..length)
+            .map(|_| match next() % 64 {
+                0 => next() as u8,
+                digit => b'0' + (digit % 10) as u8,
+            })
+            .collect();
+        let exponent = next() as i32 >> (next() % 32);
+        let (integer, fraction) = bytes.split_at(length / 3);
+        let _ = parse::parse_float_total::<f32>(integer, fraction, exponent);
+        let _ = parse::parse_float_total::<f64>(integer, fraction, exponent);
+    }
+}
+
+#[test]
+fn parse_floats_into_test() {
+    // Build a comma-separated column, and the spans for each float.
+    let column = [
+        "1.5",
+        "-20",
+        ".25",
+        "+0.",
+        "-0",
+        "0001.2345000",
+        "9007199254740993",
+        "9007199254740992.5",
+        "1.00000000000000011102230246251565404236316680908203125",
+        "1.00000000000000033306690738754696212708950042724609375",
+        "",
+        ".",
+        "-",
+        "1.2.3",
+        "1e5",
+        "+-1",
+        "1 ",
+    ];
+    let mut buf = Vec::new();
+    let mut spans = Vec::new();
+    for string in column.iter() {
+        let start = buf.len();
+        buf.extend_from_slice(string.as_bytes());
+        let point = string.find('.').unwrap_or(string.len());
+        spans.push((start..buf.len(), point));
+        buf.push(b',');
+    }
+
+    let mut out = vec![f64::NAN; spans.len()];
+    let mut valid = vec![true; spans.len()];
+    assert_eq!(parse::parse_floats_into(&spans, &buf, &mut out, &mut valid), 10);
+    for (index, string) in column.iter().enumerate() {
+        assert_eq!(valid[index], index < 10, "{}", string);
+        if !valid[index] {
+            assert!(out[index].is_nan());
+        } else if index < 6 || cfg!(not(feature = "no_slow_path")) {
+            // Near-halfway floats are only correctly rounded with the slow path.
+            let expected: f64 = string.parse().unwrap();
+            assert_eq!(out[index].to_bits(), expected.to_bits(), "{}", string);
+        }
+    }
+
+    let mut out = vec![0f32; spans.len()];
+    assert_eq!(parse::parse_floats_into(&spans, &buf, &mut out, &mut valid), 10);
+    assert_eq!(out[..6], [1.5, -20.0, 0.25, 0.0, -0.0, 1.2345]);
+
+    // Out of bounds ranges and decimal points are invalid.
+    let spans = [(0..3, 4), (0..3, 0), (buf.len()..buf.len() + 1, 1), (3..buf.len() + 1, 0)];
+    let mut out = [0f64; 4];
+    let mut valid = [true; 4];
+    assert_eq!(parse::parse_floats_into(&spans, &buf, &mut out, &mut valid), 0);
+    assert_eq!(valid, [false; 4]);
+}
+
+#[test]
+fn into_i32_test() {
+    let max = i32::max_value();
+    assert_eq!(parse::into_i32(0), 0);
+    assert_eq!(parse::into_i32(19), 19);
+    assert_eq!(parse::into_i32(max as usize), max);
+    assert_eq!(parse::into_i32(max as usize + 1), max);
+    assert_eq!(parse::into_i32(usize::max_value()), max);
+
+    // Simulate a fraction with more digits than fit in an `i32`.
+    let fraction_count = max as usize + 10;
+    assert_eq!(100_i32.saturating_sub(parse::into_i32(fraction_count)), 100 - max);
+    assert_eq!((-100_i32).saturating_sub(parse::into_i32(fraction_count)), i32::min_value());
+    assert_eq!(100_i32.saturating_add(parse::into_i32(fraction_count)), max);
+}
+
+#[test]
+fn mantissa_exponent_test() {
+    let max = i32::max_value();
+    let min = i32::min_value();
+
+    // More fraction digits than truncated digits.
+    assert_eq!(parse::mantissa_exponent(10, 4, 0), 6);
+    assert_eq!(parse::mantissa_exponent(0, 6, 3), -3);
+    assert_eq!(parse::mantissa_exponent(-5, 20, 1), -24);
+    assert_eq!(parse::mantissa_exponent(min + 5, 10, 0), min);
+    assert_eq!(parse::mantissa_exponent(0, usize::max_value(), 0), -max);
+
+    // Fewer or equal fraction digits than truncated digits.
+    assert_eq!(parse::mantissa_exponent(10, 0, 0), 10);
+    assert_eq!(parse::mantissa_exponent(0, 1, 3), 2);
+    assert_eq!(parse::mantissa_exponent(5, 3, 3), 5);
+    assert_eq!(parse::mantissa_exponent(max - 5, 0, 10), max);
+    assert_eq!(parse::mantissa_exponent(0, 0, usize::max_value()), max);
+}
+
+fn digits(integer: &str, fraction: &str) -> usize {
+    parse::significant_digits(integer.as_bytes().iter(), fraction.as_bytes().iter())
+}
+
+#[test]
+fn significant_digits_test() {
+    assert_eq!(digits("", ""), 0);
+    assert_eq!(digits("0", ""), 0);
+    assert_eq!(digits("000", "000"), 0);
+    assert_eq!(digits("1", ""), 1);
+    assert_eq!(digits("1000", ""), 1);
+    assert_eq!(digits("1001", ""), 4);
+    assert_eq!(digits("001", "5"), 2);
+    assert_eq!(digits("", "0012"), 2);
+    assert_eq!(digits("0", "00120"), 2);
+    assert_eq!(digits("10", "01"), 4);
+    assert_eq!(digits("123456789012345678901234567890", ""), 29);
+}
+
+#[test]
+fn parse_float_with_digits_test() {
+    let parse = |integer: &str, fraction: &str, exponent: i32| {
+        let integer = integer.as_bytes().iter();
+        let fraction = fraction.as_bytes().iter();
+        parse::parse_float_with_digits::<f64, _, _>(integer, fraction, exponent)
+    };
+    assert_eq!(parse("1", "5", 0), (1.5, 2));
+    assert_eq!(parse("", "001", 5), (100.0, 1));
+    assert_eq!(parse("9007199254740993", "", 0), (9007199254740992.0, 16));
+    assert_eq!(parse("1", "00000000000000000001", 0), (1.0, 21));
+}
+
+#[test]
+fn parse_float_with_exponent_test() {
+    let parse = |integer: &str, fraction: &str, exponent: i32| {
+        let integer = integer.as_bytes().iter();
+        let fraction = fraction.as_bytes().iter();
+        parse::parse_float_with_exponent::<f64, _, _>(integer, fraction, exponent)
+    };
+    assert_eq!(parse("1", "2345", 10), (1.2345e10, 6));
+    assert_eq!(parse("12345", "", 0), (12345.0, 0));
+    assert_eq!(parse("", "001", 5), (100.0, 2));
+    assert_eq!(parse("1", "5", -400), (0.0, -401));
+
+    // Truncated digits are moved into the exponent.
+    assert_eq!(parse("123456789012345678901234567890", "", 0), (1.2345678901234568e29, 11));
+    assert_eq!(parse("1", "00000000000000000001", 0), (1.0, -18));
+    assert_eq!(parse("", "1", i32::min_value()), (0.0, i32::min_value()));
+}
+
+#[test]
+fn parse_float_with_confidence_test() {
+    let parse = |integer: &str, fraction: &str, exponent: i32| {
+        let integer = integer.as_bytes().iter();
+        let fraction = fraction.as_bytes().iter();
+        parse::parse_float_with_confidence::<f64, _, _>(integer, fraction, exponent)
+    };
+    assert_eq!(parse("1", "5", 0), (1.5, true));
+    assert_eq!(parse("1", "2345", 100), (1.2345e100, true));
+    assert_eq!(parse("", "5", -400), (0.0, true));
+
+    // Near-halfway cases are only approximated without the slow path.
+    let fraction = "0000000000000000000000000000001";
+    #[cfg(not(feature = "no_slow_path"))]
+    assert_eq!(parse("9007199254740993", fraction, 0), (9007199254740994.0, true));
+    #[cfg(feature = "no_slow_path")]
+    assert_eq!(parse("9007199254740993", fraction, 0), (9007199254740992.0, false));
+}
+
+#[test]
+fn parse_float_huge_test() {
+    // The number of digits in the slow path is bounded, so a huge number
+    // of digits must match the input truncated to a sticky digit.
+    fn check<F: num::Float>(integer: &[u8], fill: u8, tail: &[u8]) {
+        let mut fraction = vec![fill; 10_000_000];
+        fraction.extend_from_slice(tail);
+        let mut truncated = vec![fill; 1000];
+        truncated.push(b'1');
+        let actual = parse::parse_float::<F, _, _>(integer.iter(), fraction.iter(), 0);
+        let expected = parse::parse_float::<F, _, _>(integer.iter(), truncated.iter(), 0);
+        assert!(actual == expected);
+    }
+
+    check::<f32>(b"1", b'9', b"");
+    check::<f64>(b"1", b'9', b"");
+    check::<f32>(b"16777217", b'0', b"1");
+    check::<f64>(b"9007199254740993", b'0', b"1");
+    assert_eq!(
+        parse::parse_float::<f64, _, _>(b"9007199254740993".iter(), [b'0'; 2000].iter(), 0),
+        9007199254740992.0
+    );
+}
+
+fn fixed_point(integer: &str, fraction: &str, exponent: i32, scale: i32) -> Option<i64> {
+    let integer = integer.as_bytes().iter();
+    let fraction = fraction.as_bytes().iter();
+    parse::parse_fixed_point(integer, fraction, exponent, scale)
+}
+
+#[test]
+fn parse_fixed_point_test() {
+    assert_eq!(fixed_point("12", "340", 0, 3), Some(12340));
+    assert_eq!(fixed_point("12", "340", 0, 2), Some(1234));
+    assert_eq!(fixed_point("12", "340", 0, 1), None);
+    assert_eq!(fixed_point("12", "340", 0, 5), Some(1234000));
+    assert_eq!(fixed_point("12", "", 0, 0), Some(12));
+    assert_eq!(fixed_point("1", "5", 2, 0), Some(150));
+    assert_eq!(fixed_point("1", "5", -1, 2), Some(15));
+    assert_eq!(fixed_point("1", "5", -2, 2), None);
+    assert_eq!(fixed_point("1200", "", -2, 0), Some(12));
+    assert_eq!(fixed_point("1200", "", -3, 0), None);
+    assert_eq!(fixed_point("", "25", 0, 2), Some(25));
+    assert_eq!(fixed_point("0", "025", 0, 2), None);
+
+    // Zeros are always representable.
+    assert_eq!(fixed_point("0", "", 0, 0), Some(0));
+    assert_eq!(fixed_point("000", "000", 0, -5), Some(0));
+    assert_eq!(fixed_point("0", "0", i32::max_value(), i32::max_value()), Some(0));
+
+    // Trailing zeros do not overflow.
+    let zeros = "0".repeat(1000);
+    assert_eq!(fixed_point("1", &zeros, 0, 2), Some(100));
+    assert_eq!(fixed_point(&format!("1{}", zeros), "", -1000, 0), Some(1));
+    assert_eq!(fixed_point(&format!("000{}", zeros), "5", 0, 1), Some(5));
+
+    // Overflow.
+    assert_eq!(fixed_point("9223372036854775807", "", 0, 0), Some(i64::max_value()));
+    assert_eq!(fixed_point("9223372036854775808", "", 0, 0), None);
+    assert_eq!(fixed_point("922337203685477580", "7", 0, 1), Some(i64::max_value()));
+    assert_eq!(fixed_point("18446744073709551616", "", 0, 0), None);
+    assert_eq!(fixed_point("1", "", 19, 0), None);
+    assert_eq!(fixed_point("1", "", 0, i32::max_value()), None);
+    assert_eq!(fixed_point("1", "", i32::max_value(), i32::max_value()), None);
+    assert_eq!(fixed_point("1", "", i32::min_value(), 0), None);
+    assert_eq!(fixed_point("1", "1", 0, 0), None);
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn moderate_path_max_mantissa_test() {
+    use minimal_lexical::extended_float::extended_to_float;
+    use minimal_lexical::num::Float;
+
+    fn parse<F: Float>(num: Number, integer: &[u8]) -> F {
+        let mut fp = parse::moderate_path::<F>(&num);
+        if fp.exp < 0 {
+            fp = parse::fallback_path::<F, _, _>(num, fp, integer.iter(), b"".iter());
+        }
+        extended_to_float::<F>(fp)
+    }
+
+    // A truncated mantissa of `u64::MAX` cannot check `mantissa + 1`,
+    // so it must fall back to the slow path without overflowing.
+    let num = Number {
+        exponent: 1,
+        mantissa: u64::max_value(),
+        many_digits: true,
+    };
+    let integer = b"184467440737095516151";
+    #[cfg(not(any(feature = "compact", feature = "bellerophon")))]
+    assert!(parse::moderate_path::<f64>(&num).exp < 0);
+    assert_eq!(parse::<f64>(num, integer), 184467440737095516151.0);
+    assert_eq!(parse::<f32>(num, integer), 184467440737095516151.0);
+}
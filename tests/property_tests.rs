@@ -0,0 +1,244 @@
+//! Compare random decimals to the standard library's parser.
+//!
+//! Unlike the round-trip tests, the decimals are arbitrary, so most
+//! are not the shortest representation of any float, and many are
+//! near the halfway point between two floats. Any mismatch is shrunk
+//! to a smaller decimal that still fails before it is reported.
+
+mod rng;
+
+use minimal_lexical::num::Float;
+use minimal_lexical::parse;
+use rng::xorshift;
+use std::{fmt, str};
+
+/// Decimal float, with the value `integer.fraction * 10^exponent`.
+#[derive(Clone, Debug, PartialEq)]
+struct Decimal {
+    integer: String,
+    fraction: String,
+    exponent: i32,
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The standard library requires at least 1 digit.
+        if self.integer.is_empty() && self.fraction.is_empty() {
+            write!(f, "0e{}", self.exponent)
+        } else {
+            write!(f, "{}.{}e{}", self.integer, self.fraction, self.exponent)
+        }
+    }
+}
+
+/// Check if the parsed float is the expected float.
+///
+/// Without the slow path, near-halfway cases may be rounded to an
+/// adjacent float, so only check the float is within 1 ULP.
+fn is_match<F: Float + str::FromStr>(decimal: &Decimal) -> bool {
+    let expected: F = match decimal.to_string().parse() {
+        Ok(float) => float,
+        Err(_) => panic!("invalid decimal {}", decimal),
+    };
+    let integer = parse::ltrim_zero(decimal.integer.as_bytes());
+    let fraction = parse::rtrim_zero(decimal.fraction.as_bytes());
+    let actual: F = parse::parse_float(integer.iter(), fraction.iter(), decimal.exponent);
+    let (x, y) = (expected.to_bits(), actual.to_bits());
+    if cfg!(feature = "no_slow_path") {
+        x.max(y) - x.min(y) <= 1
+    } else {
+        x == y
+    }
+}
+
+/// Get smaller decimals, by removing digits, zeroing digits, or reducing the exponent.
+fn shrink_candidates(decimal: &Decimal) -> Vec<Decimal> {
+    let mut candidates = vec![];
+    let mut push = |integer: &str, fraction: &str, exponent: i32| {
+        candidates.push(Decimal {
+            integer: integer.to_string(),
+            fraction: fraction.to_string(),
+            exponent,
+        });
+    };
+    let (integer, fraction, exponent) = (&decimal.integer, &decimal.fraction, decimal.exponent);
+    if !fraction.is_empty() {
+        // Removing half the fraction first shrinks long decimals quickly.
+        push(integer, &fraction[..fraction.len() / 2], exponent);
+        push(integer, &fraction[..fraction.len() - 1], exponent);
+    }
+    if !integer.is_empty() {
+        push(&integer[1..], fraction, exponent);
+        // Move the last integer digit into the exponent, without changing the value.
+        let (head, tail) = integer.split_at(integer.len() - 1);
+        let moved = format!("{}{}", tail, fraction);
+        push(head, &moved, exponent.saturating_add(1));
+    }
+    if exponent != 0 {
+        push(integer, fraction, exponent - exponent.signum());
+    }
+    let digits = format!("{}{}", integer, fraction);
+    for index in (0..digits.len()).filter(|&index| &digits[index..index + 1] != "0") {
+        let mut zeroed = digits.clone();
+        zeroed.replace_range(index..index + 1, "0");
+        let (head, tail) = zeroed.split_at(integer.len());
+        push(head, tail, exponent);
+    }
+    candidates
+}
+
+/// Shrink a decimal that fails to parse to a smaller decimal that also fails.
+fn shrink<F: Float + str::FromStr>(mut decimal: Decimal) -> Decimal {
+    while let Some(smaller) = shrink_candidates(&decimal).into_iter().find(|x| !is_match::<F>(x)) {
+        decimal = smaller;
+    }
+    decimal
+}
+
+fn check<F: Float + str::FromStr>(decimal: Decimal) {
+    if !is_match::<F>(&decimal) {
+        let shrunk = shrink::<F>(decimal.clone());
+        panic!("parsing {} does not match the standard library, shrunk to {}", decimal, shrunk);
+    }
+}
+
+/// Generate random digits.
+fn random_digits(state: &mut u64, count: usize) -> String {
+    (0..count).map(|_| (b'0' + (xorshift(state) % 10) as u8) as char).collect()
+}
+
+/// Generate a random decimal, with a value near the range of the float.
+fn random_decimal<F: Float>(state: &mut u64) -> Decimal {
+    let count = match xorshift(state) % 8 {
+        0..=4 => 1 + xorshift(state) % 20,
+        5 | 6 => 20 + xorshift(state) % 40,
+        _ => 60 + xorshift(state) % 800,
+    } as usize;
+    let mut digits = random_digits(state, count);
+    // Runs of zeros or nines are common near halfway points.
+    if xorshift(state) % 4 == 0 {
+        let start = (xorshift(state) as usize) % count;
+        let run = if xorshift(state) % 2 == 0 {
+            "0"
+        } else {
+            "9"
+        };
+        let end = digits.len();
+        digits.replace_range(start.., &run.repeat(end - start));
+    }
+    let split = (xorshift(state) as usize) % (count + 1);
+    let (integer, fraction) = digits.split_at(split);
+
+    // Scale the value to the range of the float, with some overflow and underflow.
+    let smallest = F::SMALLEST_POWER_OF_TEN - 5;
+    let range = (F::LARGEST_POWER_OF_TEN - smallest + 10) as u64;
+    let scientific = smallest + (xorshift(state) % range) as i32;
+    Decimal {
+        integer: integer.to_string(),
+        fraction: fraction.to_string(),
+        exponent: scientific - integer.len() as i32,
+    }
+}
+
+/// Format the exact value of `mantissa * 2^exp2` as decimal digits and an exponent.
+fn to_exact_decimal(mantissa: u64, exp2: i32) -> (String, i32) {
+    // Little-endian limbs of 9 decimal digits.
+    const BASE: u64 = 1_000_000_000;
+    let mut limbs = vec![mantissa % BASE, mantissa / BASE % BASE, mantissa / BASE / BASE];
+    let multiplier = if exp2 >= 0 {
+        2
+    } else {
+        5
+    };
+    for _ in 0..exp2.abs() {
+        let mut carry = 0;
+        for limb in limbs.iter_mut() {
+            let product = *limb * multiplier + carry;
+            *limb = product % BASE;
+            carry = product / BASE;
+        }
+        if carry != 0 {
+            limbs.push(carry);
+        }
+    }
+    let digits: String = limbs.iter().rev().map(|limb| format!("{:09}", limb)).collect();
+    (digits.trim_start_matches('0').to_string(), exp2.min(0))
+}
+
+/// Generate the halfway point above a random float, or a decimal just above or below it.
+fn random_halfway<F: Float>(state: &mut u64) -> Decimal {
+    let bits = xorshift(state) % F::EXPONENT_MASK;
+    let float = F::from_bits(bits);
+    let (mut digits, mut exponent) =
+        to_exact_decimal(2 * float.mantissa() + 1, float.exponent() - 1);
+    let count = digits.len();
+    match xorshift(state) % 3 {
+        0 => (),
+        1 => {
+            // Just above the halfway point.
+            digits.push_str(&"0".repeat((xorshift(state) % 30) as usize));
+            digits.push('1');
+        },
+        _ => {
+            // Just below the halfway point: subtract 1, and append nines.
+            let mut bytes = digits.into_bytes();
+            let index = bytes.iter().rposition(|&c| c != b'0').unwrap();
+            bytes[index] -= 1;
+            for c in bytes[index + 1..].iter_mut() {
+                *c = b'9';
+            }
+            digits = String::from_utf8(bytes).unwrap();
+            digits.push_str(&"9".repeat(1 + (xorshift(state) % 30) as usize));
+        },
+    }
+    exponent -= (digits.len() - count) as i32;
+
+    // Move a random number of digits into the fraction.
+    let split = (xorshift(state) as usize) % (digits.len() + 1);
+    let (integer, fraction) = digits.split_at(split);
+    Decimal {
+        integer: integer.to_string(),
+        fraction: fraction.to_string(),
+        exponent: exponent + fraction.len() as i32,
+    }
+}
+
+#[test]
+fn shrink_test() {
+    // Shrink a fake failure, all values with a `7` digit.
+    fn shrink_sevens(mut decimal: Decimal) -> Decimal {
+        let fails = |x: &Decimal| x.integer.contains('7') || x.fraction.contains('7');
+        while let Some(smaller) = shrink_candidates(&decimal).into_iter().find(fails) {
+            decimal = smaller;
+        }
+        decimal
+    }
+
+    let decimal = Decimal {
+        integer: "123456789".to_string(),
+        fraction: "98765".to_string(),
+        exponent: 12,
+    };
+    let shrunk = shrink_sevens(decimal);
+    assert_eq!(shrunk.to_string(), ".7e0");
+}
+
+#[test]
+fn random_decimal_test() {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for _ in 0..20_000 {
+        check::<f64>(random_decimal::<f64>(&mut state));
+        check::<f32>(random_decimal::<f32>(&mut state));
+    }
+}
+
+#[test]
+fn random_halfway_test() {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for _ in 0..300 {
+        check::<f64>(random_halfway::<f64>(&mut state));
+    }
+    for _ in 0..2_000 {
+        check::<f32>(random_halfway::<f32>(&mut state));
+    }
+}
@@ -0,0 +1,243 @@
+#![cfg(feature = "radix")]
+
+mod rng;
+
+use minimal_lexical::num::Float;
+use minimal_lexical::parse_float_radix;
+use rng::xorshift;
+use std::{cmp, f32, f64};
+
+/// Parse a float from the digits, split at the optional `.`.
+fn parse<F: Float>(digits: &str, exponent: i32, radix: u32) -> F {
+    let mut parts = digits.splitn(2, '.');
+    let integer = parts.next().unwrap().as_bytes();
+    let fraction = parts.next().unwrap_or("").as_bytes();
+    parse_float_radix(integer.iter(), fraction.iter(), exponent, radix)
+}
+
+/// Get the character for a digit in the radix.
+fn to_char(digit: u32) -> u8 {
+    match digit {
+        0..=9 => b'0' + digit as u8,
+        _ => b'a' + (digit - 10) as u8,
+    }
+}
+
+/// Format an integer in the radix.
+#[cfg(not(feature = "no_slow_path"))]
+fn to_radix(mut value: u128, radix: u32) -> String {
+    let mut digits = vec![];
+    while value != 0 {
+        digits.push(to_char((value % radix as u128) as u32));
+        value /= radix as u128;
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+/// Format the exact value of `mantissa * 2^exp2` in an even radix.
+///
+/// Every binary float is exactly representable in an even radix, since
+/// `2^-n = (radix/2)^n / radix^n`.
+fn to_exact_radix(mantissa: u64, exp2: i32, radix: u32) -> String {
+    // Little-endian digits of `mantissa * 2^exp2 * radix^count`.
+    let mut digits = vec![];
+    let mut value = mantissa;
+    while value != 0 {
+        digits.push((value % radix as u64) as u32);
+        value /= radix as u64;
+    }
+    let (multiplier, count, fraction_count) = match exp2 >= 0 {
+        true => (2, exp2, 0),
+        false => (radix / 2, -exp2, -exp2 as usize),
+    };
+    // Multiply by up to 2^32 at a time.
+    let mut count = count as u32;
+    while count != 0 {
+        let step = cmp::min(count, 32 / (32 - multiplier.leading_zeros()));
+        let power = (multiplier as u64).pow(step);
+        let mut carry = 0;
+        for digit in digits.iter_mut() {
+            let product = *digit as u64 * power + carry;
+            *digit = (product % radix as u64) as u32;
+            carry = product / radix as u64;
+        }
+        while carry != 0 {
+            digits.push((carry % radix as u64) as u32);
+            carry /= radix as u64;
+        }
+        count -= step;
+    }
+    while digits.len() <= fraction_count {
+        digits.push(0);
+    }
+
+    let mut string: Vec<u8> = digits.iter().rev().map(|&d| to_char(d)).collect();
+    if fraction_count != 0 {
+        string.insert(string.len() - fraction_count, b'.');
+        while string.last() == Some(&b'0') {
+            string.pop();
+        }
+    }
+    String::from_utf8(string).unwrap()
+}
+
+#[test]
+fn exponent_limit_test() {
+    assert_eq!(f64::exponent_limit(2), (-1023, 1023));
+    assert_eq!(f64::exponent_limit(3), (-33, 33));
+    assert_eq!(f64::exponent_limit(10), (-22, 22));
+    assert_eq!(f64::exponent_limit(16), (-255, 255));
+    assert_eq!(f64::exponent_limit(36), (-16, 16));
+    assert_eq!(f32::exponent_limit(2), (-127, 127));
+    assert_eq!(f32::exponent_limit(3), (-15, 15));
+    assert_eq!(f32::exponent_limit(10), (-10, 10));
+}
+
+#[test]
+fn mantissa_limit_test() {
+    assert_eq!(f64::mantissa_limit(2), 53);
+    assert_eq!(f64::mantissa_limit(3), 33);
+    assert_eq!(f64::mantissa_limit(10), 15);
+    assert_eq!(f64::mantissa_limit(36), 10);
+    assert_eq!(f32::mantissa_limit(2), 24);
+    assert_eq!(f32::mantissa_limit(10), 7);
+}
+
+#[test]
+fn parse_float_radix_test() {
+    assert_eq!(parse::<f64>("1f.8", 0, 16), 31.5);
+    assert_eq!(parse::<f64>("1F.8", 0, 16), 31.5);
+    assert_eq!(parse::<f64>("101", 3, 2), 40.0);
+    assert_eq!(parse::<f64>(".0001", 0, 2), 0.0625);
+    assert_eq!(parse::<f64>("z", 0, 36), 35.0);
+    assert_eq!(parse::<f64>("0", 0, 7), 0.0);
+    assert_eq!(parse::<f64>("1", -1, 3), 1.0 / 3.0);
+    assert_eq!(parse::<f64>("1", 5, 3), 243.0);
+    assert_eq!(parse::<f32>("1", -1, 3), 1.0 / 3.0);
+    assert_eq!(parse::<f64>("1.5", 1, 10), 15.0);
+
+    // Overflow and underflow, including halfway to the smallest denormal.
+    assert_eq!(parse::<f64>("1", 1023, 2), 2.0f64.powi(1023));
+    assert_eq!(parse::<f64>("1", 1024, 2), f64::INFINITY);
+    assert_eq!(parse::<f64>("1", -1074, 2), 5e-324);
+    assert_eq!(parse::<f64>("1", -1075, 2), 0.0);
+    assert_eq!(parse::<f64>("1.1", -1075, 2), 5e-324);
+    assert_eq!(parse::<f64>("1", 647, 3), f64::INFINITY);
+    assert_eq!(parse::<f64>("1", -700, 3), 0.0);
+    assert_eq!(parse::<f64>("1", i32::max_value(), 3), f64::INFINITY);
+    assert_eq!(parse::<f64>("1", i32::min_value(), 3), 0.0);
+    assert_eq!(parse::<f32>("1", 128, 2), f32::INFINITY);
+}
+
+#[test]
+fn round_trip_test() {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    for &radix in &[2, 6, 8, 12, 16, 36] {
+        for _ in 0..1_000 {
+            let double = f64::from_bits(xorshift(&mut state) & !f64::SIGN_MASK);
+            if !double.is_finite() {
+                continue;
+            }
+            let string = to_exact_radix(double.mantissa(), double.exponent(), radix);
+            assert_eq!(parse::<f64>(&string, 0, radix).to_bits(), double.to_bits());
+
+            let single = f32::from_bits(xorshift(&mut state) as u32 & 0x7FFF_FFFF);
+            if !single.is_finite() {
+                continue;
+            }
+            let string = to_exact_radix(single.mantissa(), single.exponent(), radix);
+            assert_eq!(parse::<f32>(&string, 0, radix).to_bits(), single.to_bits());
+
+            // Move the radix point into the exponent.
+            let integer_count = string.find('.').unwrap_or(string.len());
+            let shifted = format!(".{}", string.replace('.', ""));
+            let float = parse::<f32>(&shifted, integer_count as i32, radix);
+            assert_eq!(float.to_bits(), single.to_bits());
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn halfway_test() {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    for &radix in &[2, 6, 8, 12, 16, 36] {
+        let max_digit = to_char(radix - 1) as char;
+        for _ in 0..200 {
+            let double = f64::from_bits(xorshift(&mut state) & !f64::SIGN_MASK);
+            if !double.is_finite() || double == f64::MAX {
+                continue;
+            }
+            let below = double.to_bits();
+            let above = below + 1;
+            let even = below + (below & 1);
+            let halfway = to_exact_radix(2 * double.mantissa() + 1, double.exponent() - 1, radix);
+            assert_eq!(parse::<f64>(&halfway, 0, radix).to_bits(), even);
+
+            // Just above the halfway point.
+            let mut string = halfway.clone();
+            if !string.contains('.') {
+                string.push('.');
+            }
+            string.push_str("000000000000000000000000000000001");
+            assert_eq!(parse::<f64>(&string, 0, radix).to_bits(), above);
+
+            // Just below the halfway point.
+            let mut string = halfway.into_bytes();
+            let index = string.iter().rposition(|&c| c != b'0' && c != b'.').unwrap();
+            string[index] = match string[index] {
+                b'a' => b'9',
+                c => c - 1,
+            };
+            for c in string[index + 1..].iter_mut().filter(|c| **c != b'.') {
+                *c = max_digit as u8;
+            }
+            let mut string = String::from_utf8(string).unwrap();
+            if !string.contains('.') {
+                string.push('.');
+            }
+            string.extend((0..40).map(|_| max_digit));
+            assert_eq!(parse::<f64>(&string, 0, radix).to_bits(), below);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn integer_test() {
+    // Rust's integer to float conversions are correctly rounded.
+    let mut state: u64 = 0xd1b5_4a32_d192_ed03;
+    for radix in 2..=36 {
+        for shift in 0..70 {
+            let halfway = ((1u128 << 53) + 1) << shift;
+            for &value in &[halfway - 1, halfway, halfway + 1] {
+                let string = to_radix(value, radix);
+                assert_eq!(parse::<f64>(&string, 0, radix), value as f64);
+            }
+        }
+        for _ in 0..200 {
+            let value = ((xorshift(&mut state) as u128) << 64) | xorshift(&mut state) as u128;
+            let value = value >> (xorshift(&mut state) % 128);
+            let string = to_radix(value, radix);
+            assert_eq!(parse::<f64>(&string, 0, radix), value as f64);
+            assert_eq!(parse::<f32>(&string, 0, radix), value as f32);
+        }
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn odd_radix_test() {
+    // `1/3` is `0.2222...` in base 7, and `1/4` is `0.0202...` in base 3,
+    // which never terminate, so all the digits are compared.
+    let mut string = String::from(".");
+    string.extend((0..100).map(|_| '2'));
+    assert_eq!(parse::<f64>(&string, 0, 7), 1.0 / 3.0);
+    assert_eq!(parse::<f32>(&string, 0, 7), 1.0 / 3.0);
+
+    let mut string = String::from(".");
+    string.extend((0..100).map(|_| "02"));
+    assert_eq!(parse::<f64>(&string, 0, 3), 0.25);
+    assert_eq!(parse::<f32>(&string, -20, 3), 0.25 / 3486784401.0);
+}
@@ -0,0 +1,7 @@
+/// Simple xorshift generator for reproducible inputs.
+pub fn xorshift(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
+}
@@ -0,0 +1,717 @@
+use core::{f32, f64};
+use minimal_lexical::scan;
+
+fn check_parse_complete<F: minimal_lexical::Float + core::fmt::Debug>(
+    bytes: &str,
+    expected: Option<(F, usize)>,
+) {
+    assert_eq!(scan::parse_complete::<F>(bytes.as_bytes()), expected);
+}
+
+#[test]
+fn parse_complete_test() {
+    check_parse_complete("1.2345", Some((1.2345_f64, 6)));
+    check_parse_complete("+1.2345", Some((1.2345_f64, 7)));
+    check_parse_complete("-1.2345", Some((-1.2345_f64, 7)));
+    check_parse_complete("0001.2345000", Some((1.2345_f64, 12)));
+    check_parse_complete("12345", Some((12345.0_f64, 5)));
+    check_parse_complete("1.", Some((1.0_f64, 2)));
+    check_parse_complete(".5", Some((0.5_f64, 2)));
+    check_parse_complete("1.2345e10", Some((1.2345e10_f64, 9)));
+    check_parse_complete("1.2345E+10", Some((1.2345e10_f64, 10)));
+    check_parse_complete("1.2345e-10", Some((1.2345e-10_f64, 10)));
+    check_parse_complete("0.0", Some((0.0_f64, 3)));
+    check_parse_complete("1.2345e10,1", Some((1.2345e10_f64, 9)));
+    check_parse_complete("1e999999999999", Some((f64::INFINITY, 14)));
+    check_parse_complete("1e-999999999999", Some((0.0_f64, 15)));
+    check_parse_complete("1.2345_f32", Some((1.2345_f32, 6)));
+
+    // Exponent symbol without digits is not consumed.
+    check_parse_complete("1e", Some((1.0_f64, 1)));
+    check_parse_complete("1e+", Some((1.0_f64, 1)));
+    check_parse_complete("1.5E-x", Some((1.5_f64, 3)));
+    check_parse_complete("1.5e", Some((1.5_f64, 3)));
+    check_parse_complete("1.5e+", Some((1.5_f64, 3)));
+    check_parse_complete("1.5eq", Some((1.5_f64, 3)));
+    check_parse_complete("1.5e foo", Some((1.5_f64, 3)));
+
+    // No significant digits.
+    check_parse_complete::<f64>("", None);
+    check_parse_complete::<f64>("-", None);
+    check_parse_complete::<f64>(".", None);
+    check_parse_complete::<f64>("+.e5", None);
+    check_parse_complete::<f64>("e5", None);
+    check_parse_complete::<f64>("in", None);
+}
+
+#[test]
+fn parse_complete_special_test() {
+    check_parse_complete("inf", Some((f64::INFINITY, 3)));
+    check_parse_complete("-INF", Some((f64::NEG_INFINITY, 4)));
+    check_parse_complete("Infinity", Some((f64::INFINITY, 8)));
+    check_parse_complete("+infinityx", Some((f64::INFINITY, 9)));
+    check_parse_complete::<f32>("infin", None);
+    check_parse_complete::<f32>("-infinit,", None);
+
+    let (nan, count) = scan::parse_complete::<f64>(b"NaN").unwrap();
+    assert!(nan.is_nan() && nan.is_sign_positive());
+    assert_eq!(count, 3);
+    let (nan, count) = scan::parse_complete::<f32>(b"-nan").unwrap();
+    assert!(nan.is_nan() && nan.is_sign_negative());
+    assert_eq!(count, 4);
+}
+
+fn check_scan_float(
+    bytes: &str,
+    sign: Option<core::ops::Range<usize>>,
+    integer: Option<core::ops::Range<usize>>,
+    fraction: Option<core::ops::Range<usize>>,
+    exponent: Option<core::ops::Range<usize>>,
+    len: usize,
+) {
+    let expected = scan::FloatSpans {
+        sign,
+        integer,
+        fraction,
+        exponent,
+        len,
+    };
+    assert_eq!(scan::scan_float(bytes.as_bytes()), expected);
+}
+
+#[test]
+fn scan_float_test() {
+    check_scan_float("1.2345", None, Some(0..1), Some(2..6), None, 6);
+    check_scan_float("-12.5e+10,", Some(0..1), Some(1..3), Some(4..5), Some(6..9), 9);
+    check_scan_float("+.5E3", Some(0..1), None, Some(2..3), Some(4..5), 5);
+    check_scan_float("7.e-2", None, Some(0..1), None, Some(3..5), 5);
+    check_scan_float("12e", None, Some(0..2), None, None, 2);
+    check_scan_float("12e-", None, Some(0..2), None, None, 2);
+
+    // No significant digits.
+    check_scan_float("", None, None, None, None, 0);
+    check_scan_float("-", None, None, None, None, 0);
+    check_scan_float("-.e5", None, None, None, None, 0);
+    check_scan_float("inf", None, None, None, None, 0);
+
+    // Spans can be fed into parse_float.
+    let bytes = b"-12.5e+10";
+    let spans = scan::scan_float(bytes);
+    let integer = &bytes[spans.integer.unwrap()];
+    let fraction = &bytes[spans.fraction.unwrap()];
+    let exponent = core::str::from_utf8(&bytes[spans.exponent.unwrap()]).unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let float: f64 = minimal_lexical::parse_float(integer.iter(), fraction.iter(), exponent);
+    assert_eq!(float, 12.5e10);
+}
+
+#[test]
+fn parse_options_test() {
+    let default = scan::ParseOptions::default();
+    let no_positive_mantissa_sign = scan::ParseOptions {
+        no_positive_mantissa_sign: true,
+        ..scan::ParseOptions::default()
+    };
+    let require_exponent_digits = scan::ParseOptions {
+        require_exponent_digits: true,
+        ..scan::ParseOptions::default()
+    };
+    let no_positive_exponent_sign = scan::ParseOptions {
+        no_positive_exponent_sign: true,
+        ..scan::ParseOptions::default()
+    };
+    let parse = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_complete_with_options::<f64>(bytes, options)
+    };
+
+    // Default options accept all forms.
+    assert_eq!(parse(b"+1.5", &default), Some((1.5, 4)));
+    assert_eq!(parse(b"1.5e", &default), Some((1.5, 3)));
+    assert_eq!(parse(b"1.5e+1", &default), Some((15.0, 6)));
+
+    // No positive mantissa sign.
+    assert_eq!(parse(b"+1.5", &no_positive_mantissa_sign), None);
+    assert_eq!(parse(b"+inf", &no_positive_mantissa_sign), None);
+    assert_eq!(parse(b"-1.5", &no_positive_mantissa_sign), Some((-1.5, 4)));
+    assert_eq!(parse(b"1.5e+1", &no_positive_mantissa_sign), Some((15.0, 6)));
+
+    // Require exponent digits.
+    assert_eq!(parse(b"1.5e", &require_exponent_digits), None);
+    assert_eq!(parse(b"1.5E-", &require_exponent_digits), None);
+    assert_eq!(parse(b"1.5e+x", &require_exponent_digits), None);
+    assert_eq!(parse(b"1.5e1", &require_exponent_digits), Some((15.0, 5)));
+    assert_eq!(parse(b"1.5", &require_exponent_digits), Some((1.5, 3)));
+
+    // No positive exponent sign.
+    assert_eq!(parse(b"1.5e+1", &no_positive_exponent_sign), None);
+    assert_eq!(parse(b"1.5e-1", &no_positive_exponent_sign), Some((0.15, 6)));
+    assert_eq!(parse(b"1.5e1", &no_positive_exponent_sign), Some((15.0, 5)));
+    assert_eq!(parse(b"+1.5e1", &no_positive_exponent_sign), Some((15.0, 6)));
+
+    // Rejected floats have empty spans.
+    let spans = scan::scan_float_with_options(b"+1.5", &no_positive_mantissa_sign);
+    assert_eq!(spans, scan::FloatSpans::default());
+    let spans = scan::scan_float_with_options(b"1.5e", &require_exponent_digits);
+    assert_eq!(spans, scan::FloatSpans::default());
+    let spans = scan::scan_float_with_options(b"1.5e+1", &no_positive_exponent_sign);
+    assert_eq!(spans, scan::FloatSpans::default());
+}
+
+#[test]
+fn fortran_exponent_test() {
+    let options = scan::ParseOptions {
+        allow_fortran_exponent: true,
+        ..scan::ParseOptions::default()
+    };
+    let parse = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_complete_with_options::<f64>(bytes, options)
+    };
+
+    assert_eq!(parse(b"1.5D3", &options), Some((1500.0, 5)));
+    assert_eq!(parse(b"1.5d-3", &options), Some((0.0015, 6)));
+    assert_eq!(parse(b"1.5e3", &options), Some((1500.0, 5)));
+    assert_eq!(parse(b"1.5E+3", &options), Some((1500.0, 6)));
+    assert_eq!(parse(b"15D", &options), Some((15.0, 2)));
+    assert_eq!(parse(b"D3", &options), None);
+    assert_eq!(parse(b"1.5D3", &scan::ParseOptions::default()), Some((1.5, 3)));
+
+    let spans = scan::scan_float_with_options(b"-12.5D+10", &options);
+    assert_eq!(spans.integer, Some(1..3));
+    assert_eq!(spans.fraction, Some(4..5));
+    assert_eq!(spans.exponent, Some(6..9));
+    assert_eq!(spans.len, 9);
+}
+
+#[test]
+fn custom_exponent_test() {
+    let caret = scan::ParseOptions::with_exponent(b'^').unwrap();
+    let x = scan::ParseOptions::with_exponent(b'X').unwrap();
+    let parse = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_complete_with_options::<f64>(bytes, options)
+    };
+
+    // Non-letters match exactly, and replace `e` and `E`.
+    assert_eq!(parse(b"1.5^3", &caret), Some((1500.0, 5)));
+    assert_eq!(parse(b"-1.5^-3", &caret), Some((-0.0015, 7)));
+    assert_eq!(parse(b"1.5^", &caret), Some((1.5, 3)));
+    assert_eq!(parse(b"1.5e3", &caret), Some((1.5, 3)));
+    assert_eq!(parse(b"^3", &caret), None);
+
+    // Letters match case-insensitively.
+    assert_eq!(parse(b"1.5x3", &x), Some((1500.0, 5)));
+    assert_eq!(parse(b"1.5X+3", &x), Some((1500.0, 6)));
+    assert_eq!(parse(b"1.5e3", &x), Some((1.5, 3)));
+
+    // Streaming and iteration use the same exponent symbol.
+    let progress = scan::parse_float_streaming_with_options::<f64>(b"1.5^", false, &caret);
+    assert_eq!(progress, scan::ParseProgress::Incomplete);
+    let floats: Vec<f64> =
+        scan::FloatIter::with_options(b"1^2, 2.5^-1", b',', caret).map(Result::unwrap).collect();
+    assert_eq!(floats, [100.0, 0.25]);
+
+    // Ambiguous exponent symbols are rejected.
+    for &c in b"0123456789+-.".iter() {
+        assert_eq!(scan::ParseOptions::with_exponent(c), None);
+    }
+    assert!(scan::ParseOptions::default().is_valid());
+    let options = scan::ParseOptions {
+        decimal_point: b'E',
+        ..scan::ParseOptions::default()
+    };
+    assert!(!options.is_valid());
+    let options = scan::ParseOptions {
+        decimal_point: b',',
+        group_separator: Some(b'.'),
+        ..caret
+    };
+    assert!(options.is_valid());
+    let options = scan::ParseOptions {
+        group_separator: Some(b'^'),
+        ..caret
+    };
+    assert!(!options.is_valid());
+}
+
+#[test]
+fn max_exponent_digits_test() {
+    let options = scan::ParseOptions {
+        max_exponent_digits: Some(10),
+        ..scan::ParseOptions::default()
+    };
+    let parse = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_complete_with_options::<f64>(bytes, options)
+    };
+
+    assert_eq!(parse(b"1.5e3", &options), Some((1500.0, 5)));
+    assert_eq!(parse(b"1.5e-0000000003", &options), Some((0.0015, 15)));
+    assert_eq!(parse(b"1.5e-00000000003", &options), Some((1.5, 15)));
+
+    // An absurdly long exponent is only consumed up to the cap.
+    let mut bytes = b"1e".to_vec();
+    bytes.extend(std::iter::repeat(b'0').take(1_000_000));
+    bytes.push(b'5');
+    assert_eq!(parse(&bytes, &options), Some((1.0, 12)));
+    assert_eq!(parse(&bytes, &scan::ParseOptions::default()), Some((1e5, bytes.len())));
+    let spans = scan::scan_float_with_options(&bytes, &options);
+    assert_eq!(spans.exponent, Some(2..12));
+    assert_eq!(spans.len, 12);
+
+    // The exponent still saturates within the cap.
+    let mut bytes = b"1e".to_vec();
+    bytes.extend(std::iter::repeat(b'9').take(1_000_000));
+    assert_eq!(parse(&bytes, &options), Some((f64::INFINITY, 12)));
+
+    // Without any exponent digits, the exponent is not consumed.
+    let options = scan::ParseOptions {
+        max_exponent_digits: Some(0),
+        ..scan::ParseOptions::default()
+    };
+    assert_eq!(parse(b"1.5e3", &options), Some((1.5, 3)));
+}
+
+#[test]
+fn parse_float_streaming_test() {
+    use minimal_lexical::scan::ParseProgress::{Complete, Incomplete, Invalid};
+
+    let streaming = |bytes: &str| scan::parse_float_streaming::<f64>(bytes.as_bytes(), false);
+    let last = |bytes: &str| scan::parse_float_streaming::<f64>(bytes.as_bytes(), true);
+
+    // Floats that could continue past the end of the buffer.
+    assert_eq!(streaming(""), Incomplete);
+    assert_eq!(streaming("-"), Incomplete);
+    assert_eq!(streaming("."), Incomplete);
+    assert_eq!(streaming("123"), Incomplete);
+    assert_eq!(streaming("123."), Incomplete);
+    assert_eq!(streaming("123.45"), Incomplete);
+    assert_eq!(streaming("123.45e"), Incomplete);
+    assert_eq!(streaming("123.45E-"), Incomplete);
+    assert_eq!(streaming("123.45e1"), Incomplete);
+    assert_eq!(streaming("in"), Incomplete);
+    assert_eq!(streaming("-inf"), Incomplete);
+    assert_eq!(streaming("infin"), Incomplete);
+    assert_eq!(streaming("Na"), Incomplete);
+
+    // Floats terminated within the buffer.
+    assert_eq!(streaming("123.45,"), Complete(123.45, 6));
+    assert_eq!(streaming("123.45e1 "), Complete(1234.5, 8));
+    assert_eq!(streaming("123.45ex"), Complete(123.45, 6));
+    assert_eq!(streaming("123.45e+x"), Complete(123.45, 6));
+    assert_eq!(streaming("infinity"), Complete(f64::INFINITY, 8));
+    assert_eq!(streaming("infx"), Complete(f64::INFINITY, 3));
+    assert!(match streaming("nan") {
+        Complete(float, 3) => float.is_nan(),
+        _ => false,
+    });
+
+    // Invalid floats.
+    assert_eq!(streaming("x"), Invalid);
+    assert_eq!(streaming("-x"), Invalid);
+    assert_eq!(streaming(".e"), Invalid);
+    assert_eq!(streaming("inx"), Invalid);
+
+    // The final buffer is never incomplete.
+    assert_eq!(last(""), Invalid);
+    assert_eq!(last("-"), Invalid);
+    assert_eq!(last("123"), Complete(123.0, 3));
+    assert_eq!(last("123.45e"), Complete(123.45, 6));
+    assert_eq!(last("inf"), Complete(f64::INFINITY, 3));
+
+    // Validation rules are respected.
+    let options = scan::ParseOptions {
+        require_exponent_digits: true,
+        no_positive_mantissa_sign: true,
+        ..scan::ParseOptions::default()
+    };
+    let streaming = |bytes: &str| {
+        scan::parse_float_streaming_with_options::<f64>(bytes.as_bytes(), false, &options)
+    };
+    assert_eq!(streaming("1.5e"), Incomplete);
+    assert_eq!(streaming("1.5e-"), Incomplete);
+    assert_eq!(streaming("1.5e,"), Invalid);
+    assert_eq!(streaming("+"), Invalid);
+    assert_eq!(streaming("+1"), Invalid);
+}
+
+#[test]
+fn decimal_point_test() {
+    let options = scan::ParseOptions {
+        decimal_point: b',',
+        ..scan::ParseOptions::default()
+    };
+    let parse = |bytes: &[u8]| scan::parse_complete_with_options::<f64>(bytes, &options);
+
+    assert_eq!(parse(b"1,5"), Some((1.5, 3)));
+    assert_eq!(parse(b"-1,5e3"), Some((-1500.0, 6)));
+    assert_eq!(parse(b",5"), Some((0.5, 2)));
+    assert_eq!(parse(b"1,"), Some((1.0, 2)));
+    assert_eq!(parse(b"1.5"), Some((1.0, 1)));
+
+    // The `.` is not a decimal point, so parsing stops before it.
+    assert_eq!(parse(b"1.234,5"), Some((1.0, 1)));
+
+    // A lone decimal point is not a float.
+    assert_eq!(parse(b","), None);
+    assert_eq!(parse(b"-,"), None);
+    assert_eq!(parse(b",e5"), None);
+    assert_eq!(scan::scan_float_with_options(b",", &options), scan::FloatSpans::default());
+
+    let spans = scan::scan_float_with_options(b"-12,5e+10", &options);
+    assert_eq!(spans.integer, Some(1..3));
+    assert_eq!(spans.fraction, Some(4..5));
+    assert_eq!(spans.exponent, Some(6..9));
+    assert_eq!(spans.len, 9);
+
+    // Streaming a lone decimal point is incomplete.
+    let streaming =
+        |bytes: &[u8]| scan::parse_float_streaming_with_options::<f64>(bytes, false, &options);
+    assert_eq!(streaming(b","), scan::ParseProgress::Incomplete);
+    assert_eq!(streaming(b"."), scan::ParseProgress::Invalid);
+}
+
+#[test]
+fn group_separator_test() {
+    use minimal_lexical::scan::ParseProgress::{Complete, Incomplete, Invalid};
+
+    let lenient = scan::ParseOptions {
+        group_separator: Some(b','),
+        ..scan::ParseOptions::default()
+    };
+    let strict = scan::ParseOptions {
+        strict_grouping: true,
+        ..lenient
+    };
+    let european = scan::ParseOptions {
+        decimal_point: b',',
+        group_separator: Some(b'.'),
+        strict_grouping: true,
+        ..scan::ParseOptions::default()
+    };
+    let parse = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_complete_with_options::<f64>(bytes, options)
+    };
+
+    // Lenient grouping allows separators anywhere between integer digits.
+    assert_eq!(parse(b"1,000,000.5", &lenient), Some((1000000.5, 11)));
+    assert_eq!(parse(b"-1,234e2", &lenient), Some((-123400.0, 8)));
+    assert_eq!(parse(b"1,0,00", &lenient), Some((1000.0, 6)));
+    assert_eq!(parse(b"1,,000", &lenient), Some((1000.0, 6)));
+    assert_eq!(parse(b"0,001.5", &lenient), Some((1.5, 7)));
+    assert_eq!(parse(b"1,000,", &lenient), Some((1000.0, 5)));
+    assert_eq!(parse(b"1,000.5,3", &lenient), Some((1000.5, 7)));
+    assert_eq!(parse(b",000", &lenient), None);
+    assert_eq!(parse(b"9,007,199,254,740,993", &lenient), Some((9007199254740992.0, 21)));
+    assert_eq!(parse(b"1,000", &scan::ParseOptions::default()), Some((1.0, 1)));
+
+    // Strict grouping requires groups of 3.
+    assert_eq!(parse(b"1,000,000.5", &strict), Some((1000000.5, 11)));
+    assert_eq!(parse(b"12,345", &strict), Some((12345.0, 6)));
+    assert_eq!(parse(b"1234", &strict), Some((1234.0, 4)));
+    assert_eq!(parse(b"1,000,", &strict), Some((1000.0, 5)));
+    assert_eq!(parse(b"1,00", &strict), None);
+    assert_eq!(parse(b"1,0000", &strict), None);
+    assert_eq!(parse(b"1234,567", &strict), None);
+    assert_eq!(parse(b"1,,000", &strict), None);
+
+    // Grouping and a decimal point.
+    assert_eq!(parse(b"1.234,5", &european), Some((1234.5, 7)));
+    assert_eq!(parse(b"1.234.567,89", &european), Some((1234567.89, 12)));
+    assert_eq!(parse(b"1.23,4", &european), None);
+
+    // The integer span includes the group separators.
+    let spans = scan::scan_float_with_options(b"-1,234.5", &strict);
+    assert_eq!(spans.integer, Some(1..6));
+    assert_eq!(spans.fraction, Some(7..8));
+    assert_eq!(spans.len, 8);
+    let spans = scan::scan_float_with_options(b"1,00", &strict);
+    assert_eq!(spans, scan::FloatSpans::default());
+
+    // Streaming group separators may be followed by more digits.
+    let streaming = |bytes: &[u8], options: &scan::ParseOptions| {
+        scan::parse_float_streaming_with_options::<f64>(bytes, false, options)
+    };
+    assert_eq!(streaming(b"1,", &lenient), Incomplete);
+    assert_eq!(streaming(b"1,000,", &lenient), Incomplete);
+    assert_eq!(streaming(b"1,000.", &lenient), Incomplete);
+    assert_eq!(streaming(b"1,000.5,", &lenient), Complete(1000.5, 7));
+    assert_eq!(streaming(b"1,00", &strict), Incomplete);
+    assert_eq!(streaming(b"1,000,", &strict), Incomplete);
+    assert_eq!(streaming(b"1234,", &strict), Complete(1234.0, 4));
+    assert_eq!(streaming(b"1,0000", &strict), Invalid);
+    let last = scan::parse_float_streaming_with_options::<f64>(b"1,", true, &lenient);
+    assert_eq!(last, Complete(1.0, 1));
+}
+
+#[test]
+fn float_iter_test() {
+    use minimal_lexical::scan::{FloatIter, ParseFloatError, ParseFloatErrorKind};
+
+    let collect =
+        |bytes: &[u8], delimiter: u8| FloatIter::<f64>::new(bytes, delimiter).collect::<Vec<_>>();
+    let error = |index: usize| {
+        Err(ParseFloatError {
+            index,
+            kind: ParseFloatErrorKind::Invalid,
+        })
+    };
+
+    // Comma-separated floats, with optional whitespace.
+    assert_eq!(collect(b"1.5,-2e3,4", b','), [Ok(1.5), Ok(-2000.0), Ok(4.0)]);
+    assert_eq!(collect(b" 1.5 , 2 ,3 ", b','), [Ok(1.5), Ok(2.0), Ok(3.0)]);
+    assert_eq!(collect(b"inf,NaN", b',').len(), 2);
+    assert_eq!(collect(b"", b','), []);
+    assert_eq!(collect(b"  ", b','), []);
+
+    // Whitespace-separated floats.
+    assert_eq!(collect(b"1 2\t3\n", b' '), [Ok(1.0), Ok(2.0), Ok(3.0)]);
+    assert_eq!(collect(b"  1   2  ", b' '), [Ok(1.0), Ok(2.0)]);
+
+    // Errors are yielded once, and stop iteration.
+    assert_eq!(collect(b"1,,2", b','), [Ok(1.0), error(2)]);
+    assert_eq!(collect(b"1,2,", b','), [Ok(1.0), Ok(2.0), error(4)]);
+    assert_eq!(collect(b",1", b','), [error(0)]);
+    assert_eq!(collect(b"1.5x,2", b','), [Ok(1.5), error(3)]);
+    assert_eq!(collect(b"1 2", b','), [Ok(1.0), error(2)]);
+    assert_eq!(collect(b"1-2", b' '), [Ok(1.0), error(1)]);
+
+    // Validation rules are respected.
+    let options = scan::ParseOptions {
+        decimal_point: b',',
+        ..scan::ParseOptions::default()
+    };
+    let floats = FloatIter::<f64>::with_options(b"1,5;2,25", b';', options);
+    assert_eq!(floats.collect::<Vec<_>>(), [Ok(1.5), Ok(2.25)]);
+
+    let mut floats = FloatIter::<f32>::new(b"1,2", b',');
+    assert_eq!(floats.next(), Some(Ok(1.0)));
+    assert_eq!(floats.index(), 2);
+}
+
+#[test]
+fn parse_float_str_test() {
+    fn error<T>(index: usize) -> Result<T, scan::ParseFloatError> {
+        Err(scan::ParseFloatError {
+            index,
+            kind: scan::ParseFloatErrorKind::Invalid,
+        })
+    }
+
+    assert_eq!(scan::parse_float_str::<f64>("1.5"), Ok(1.5));
+    assert_eq!(scan::parse_float_str::<f64>("-1.5e-3"), Ok(-1.5e-3));
+    assert_eq!(scan::parse_float_str::<f32>("inf"), Ok(f32::INFINITY));
+    assert_eq!(scan::parse_float_str::<f64>("1.5,"), error(3));
+    assert_eq!(scan::parse_float_str::<f64>("1.5e"), error(3));
+    assert_eq!(scan::parse_float_str::<f64>(" 1.5"), error(0));
+    assert_eq!(scan::parse_float_str::<f64>(""), error(0));
+
+    assert_eq!(scan::parse_float_str_partial::<f64>("1.5 foo"), Ok((1.5, 3)));
+    assert_eq!(scan::parse_float_str_partial::<f64>("1.5"), Ok((1.5, 3)));
+    assert_eq!(scan::parse_float_str_partial::<f64>("foo"), error(0));
+
+    // Matches the standard library parser for valid and invalid floats.
+    let strings = [
+        "0",
+        "1.",
+        ".5",
+        "+1.5",
+        "-0.0",
+        "1e10",
+        "1E+10",
+        "1e-10",
+        "NaN",
+        "-inf",
+        "Infinity",
+        "1.7976931348623157e308",
+        "2.2250738585072014e-308",
+        "9007199254740993",
+        ".",
+        "e5",
+        "+",
+        "1e",
+        "1.5e+",
+        "1.5eq",
+        "1_000",
+        "0x10",
+        "1.5 ",
+        "in",
+    ];
+    for string in strings.iter() {
+        let expected = string.parse::<f64>().ok().map(f64::to_bits);
+        let actual = scan::parse_float_str::<f64>(string).ok().map(f64::to_bits);
+        assert_eq!(actual, expected, "{}", string);
+    }
+}
+
+#[test]
+fn parse_float_str_checked_test() {
+    use minimal_lexical::scan::{ParseFloatError, ParseFloatErrorKind};
+
+    fn error<T>(kind: ParseFloatErrorKind) -> Result<T, ParseFloatError> {
+        Err(ParseFloatError {
+            index: 0,
+            kind,
+        })
+    }
+
+    // Out of range values are distinct from literal infinities and zeros.
+    let parse64 = scan::parse_float_str_checked::<f64>;
+    let parse32 = scan::parse_float_str_checked::<f32>;
+    assert_eq!(parse64("1e400"), error(ParseFloatErrorKind::Overflow));
+    assert_eq!(parse64("-1e400"), error(ParseFloatErrorKind::Overflow));
+    assert_eq!(parse64("1e-400"), error(ParseFloatErrorKind::Underflow));
+    assert_eq!(parse32("1e39"), error(ParseFloatErrorKind::Overflow));
+    assert_eq!(parse32("1e-46"), error(ParseFloatErrorKind::Underflow));
+    assert_eq!(parse64("inf"), Ok(f64::INFINITY));
+    assert_eq!(parse64("-Infinity"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse64("0e-400"), Ok(0.0));
+    assert_eq!(parse64("0.000e400"), Ok(0.0));
+    assert_eq!(parse64("5e-324"), Ok(5e-324));
+    assert_eq!(parse64("1.7976931348623157e308"), Ok(f64::MAX));
+    assert!(parse64("NaN").unwrap().is_nan());
+
+    // Invalid floats are still reported first.
+    assert_eq!(parse64("1e400x").unwrap_err().kind, ParseFloatErrorKind::Invalid);
+    assert_eq!(parse64("1e400x").unwrap_err().index, 5);
+    assert_eq!(parse64("infx").unwrap_err().index, 3);
+    assert_eq!(parse64("1e400").unwrap_err().to_string(), "float overflow at index 0");
+}
+
+#[test]
+fn try_parse_float_parts_test() {
+    use minimal_lexical::parse::NumberParts;
+    use minimal_lexical::scan::{ParseFloatError, ParseFloatErrorKind};
+
+    fn parse(integer: &str, fraction: &str) -> Result<f64, ParseFloatError> {
+        let parts = NumberParts {
+            is_positive: false,
+            integer: integer.as_bytes(),
+            fraction: fraction.as_bytes(),
+            exponent: 1,
+        };
+        scan::try_parse_float_parts::<f64>(parts)
+    }
+
+    fn error<T>(index: usize) -> Result<T, ParseFloatError> {
+        Err(ParseFloatError {
+            index,
+            kind: ParseFloatErrorKind::InvalidDigit,
+        })
+    }
+
+    assert_eq!(parse("0012", "500"), Ok(-125.0));
+    assert_eq!(parse("", ""), Ok(-0.0));
+    assert_eq!(parse("1x2", "5"), error(1));
+    assert_eq!(parse("12", "5x"), error(3));
+    assert_eq!(parse("", "-5"), error(0));
+    assert_eq!(parse("12", "."), error(2));
+}
+
+#[test]
+fn try_parse_float_chars_test() {
+    use minimal_lexical::scan::{ParseFloatError, ParseFloatErrorKind};
+
+    fn parse(integer: &str, fraction: &str) -> Result<f64, ParseFloatError> {
+        scan::try_parse_float_chars::<f64, _, _>(integer.chars(), fraction.chars(), 2)
+    }
+
+    fn error<T>(index: usize) -> Result<T, ParseFloatError> {
+        Err(ParseFloatError {
+            index,
+            kind: ParseFloatErrorKind::InvalidDigit,
+        })
+    }
+
+    assert_eq!(parse("12", "5"), Ok(1250.0));
+    assert_eq!(parse("", ""), Ok(0.0));
+    assert_eq!(parse("1x2", "5"), error(1));
+    assert_eq!(parse("12", "5x"), error(3));
+    // Non-ASCII digits are invalid, and the index counts chars, not bytes.
+    assert_eq!(parse("١٢", "5"), error(0));
+    assert_eq!(parse("12", "5٣"), error(3));
+    assert_eq!(parse("12", "５"), error(2));
+}
+
+#[test]
+fn parse_float_error_fmt_test() {
+    use minimal_lexical::scan::{ParseFloatError, ParseFloatErrorKind};
+
+    let error = |kind: ParseFloatErrorKind| ParseFloatError {
+        index: 3,
+        kind,
+    };
+    assert_eq!(error(ParseFloatErrorKind::Invalid).to_string(), "invalid float at index 3");
+    assert_eq!(error(ParseFloatErrorKind::InvalidDigit).to_string(), "invalid digit at index 3");
+    assert_eq!(error(ParseFloatErrorKind::Overflow).to_string(), "float overflow at index 3");
+    assert_eq!(error(ParseFloatErrorKind::Underflow).to_string(), "float underflow at index 3");
+    assert_eq!(
+        format!("{:?}", error(ParseFloatErrorKind::InvalidDigit)),
+        "ParseFloatError { index: 3, kind: InvalidDigit }"
+    );
+
+    // The error integrates with `?` and boxed errors.
+    #[cfg(feature = "std")]
+    {
+        fn parse(string: &str) -> Result<f64, Box<dyn std::error::Error>> {
+            Ok(scan::parse_float_str::<f64>(string)?)
+        }
+        assert_eq!(parse("1.5").unwrap(), 1.5);
+        assert_eq!(parse("1.5x").unwrap_err().to_string(), "invalid float at index 3");
+    }
+}
+
+#[test]
+fn parse_float_rust_strict_test() {
+    let parse = scan::parse_float_rust_strict::<f64>;
+
+    // Valid float literals, from the Rust reference.
+    assert_eq!(parse(b"123.0f64"), Some((123.0, 8)));
+    assert_eq!(parse(b"0.1f64"), Some((0.1, 6)));
+    assert_eq!(parse(b"0.1f32"), Some((0.1, 6)));
+    assert_eq!(parse(b"12E+99_f64"), Some((12e99, 10)));
+    assert_eq!(parse(b"5f32"), Some((5.0, 4)));
+    assert_eq!(parse(b"2."), Some((2.0, 2)));
+    assert_eq!(parse(b"2.;"), Some((2.0, 2)));
+    assert_eq!(parse(b"1.5"), Some((1.5, 3)));
+    assert_eq!(parse(b"007.250"), Some((7.25, 7)));
+    assert_eq!(parse(b"1_000.000_1"), Some((1000.0001, 11)));
+    assert_eq!(parse(b"1__.5__"), Some((1.5, 7)));
+    assert_eq!(parse(b"1e10"), Some((1e10, 4)));
+    assert_eq!(parse(b"1e_1_0"), Some((1e10, 6)));
+    assert_eq!(parse(b"1.5E-3"), Some((1.5e-3, 6)));
+    assert_eq!(parse(b"1.5e+_3_"), Some((1.5e3, 8)));
+    assert_eq!(parse(b"1.0_f32)"), Some((1.0, 7)));
+    assert_eq!(parse(b"1e400"), Some((f64::INFINITY, 5)));
+    assert_eq!(parse(b"1.5 + 2"), Some((1.5, 3)));
+
+    // Invalid float literals, or other tokens.
+    let invalid: [&[u8]; 26] = [
+        b"",
+        b"1",
+        b"1_000",
+        b"+1.5",
+        b"-1.5",
+        b".5",
+        b"_1.5",
+        b"1..2",
+        b"1.e5",
+        b"1._5",
+        b"1.f32",
+        b"1.foo",
+        b"1.\xc3\xa9",
+        b"1e",
+        b"1e+",
+        b"1e_",
+        b"1e+_",
+        b"1.5ef32",
+        b"1.5f",
+        b"1.5f16",
+        b"1.5_f128",
+        b"1.5x",
+        b"0x1p3",
+        b"0b1f32",
+        b"inf",
+        b"NaN",
+    ];
+    for &bytes in invalid.iter() {
+        assert!(parse(bytes).is_none(), "{:?}", bytes);
+    }
+}
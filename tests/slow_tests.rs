@@ -6,6 +6,8 @@ use minimal_lexical::num::Float;
 use minimal_lexical::number::Number;
 use minimal_lexical::slow;
 use stackvec::vec_from_u32;
+use std::cmp::Ordering;
+use std::{f32, f64};
 
 fn b<F: Float>(float: F) -> (u64, i32) {
     let fp = slow::b(float);
@@ -270,7 +272,8 @@ fn parse_mantissa_test() {
     // Large number of digits.
     let integer = b"2";
     let fraction = b"4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328124999";
-    let (bigmant, count) = slow::parse_mantissa(integer.iter(), fraction.iter(), max_digits);
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
     let expected = vec_from_u32(&[
         1727738439, 330069557, 3509095598, 686205316, 156923684, 750687444, 2688855918, 28211928,
         1887482096, 3222998811, 913348873, 1652282845, 1600735541, 1664240266, 84454144,
@@ -290,7 +293,8 @@ fn parse_mantissa_test() {
     // Truncation.
     let integer = b"7";
     let fraction = b"4109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375332669816033062329967789262837";
-    let (bigmant, count) = slow::parse_mantissa(integer.iter(), fraction.iter(), max_digits);
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
     let expected = vec_from_u32(&[
         983641521, 2202462645, 4170685875, 1591772364, 529830014, 803977727, 126733331, 1695971390,
         4089590927, 1532849076, 2705586665, 4046282448, 4076195232, 3230469892, 3059053929,
@@ -310,14 +314,16 @@ fn parse_mantissa_test() {
     // No fraction digits.
     let integer = b"74109846876186981626485318930233205854758970392148714663837852375101326090531312779794975454245398856969484704316857659638998506553390969459816219401617281718945106978546710679176872575177347315553307795408549809608457500958111373034747658096871009590975442271004757307809711118935784838675653998783503015228055934046593739791790738723868299395818481660169122019456499931289798411362062484498678713572180352209017023903285791732520220528974020802906854021606612375549983402671300035812486479041385743401875520901590172592547146296175134159774938718574737870961645638908718119841271673056017045493004705269590165763776884908267986972573366521765567941072508764337560846003984904972149117463085539556354188641513168478436313080237596295773983001708984375332669816033062329967789262837";
     let fraction = b"";
-    let (bigmant, count) = slow::parse_mantissa(integer.iter(), fraction.iter(), max_digits);
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
     assert_eq!(&*bigmant.data, &*expected);
     assert_eq!(count, max_digits + 1);
 
     // Multiple of step (check we add our temporary correctly).
     let integer = b"7410984687618698162648531893023320585475897039214871466383785237510132609053131277979497545424539885696948470431685765963899850655339096945981621940161728171894510697854671067917687257517734731555330779540854980960845750095811137303474765809687100959097544227100475730780971111893578483867565399878350301522805593404659373979179073872386829939581848166016912201945649993128979841136206248449867871357218035220901702390328579173252022052897402080290685402160661237554998340267130003581248647904138574340187552090159017259254714629617513415977493871857473787096164563890871811984127167305601704549300470526959016576377688490826798697257336652176556794107250876433756084600398490497214911746308553955635418864151316847843631308023759629577398300170898437533266981";
     let fraction = b"";
-    let (bigmant, count) = slow::parse_mantissa(integer.iter(), fraction.iter(), max_digits);
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
     let expected = vec_from_u32(&[
         617018405, 396211401, 2130402383, 3812547827, 4263683770, 3918012496, 1787721490,
         2493014694, 435464626, 3720854431, 2928509507, 2677932436, 369049650, 3606588290,
@@ -335,3 +341,311 @@ fn parse_mantissa_test() {
     assert_eq!(&*bigmant.data, &*expected);
     assert_eq!(count, 760);
 }
+
+#[test]
+fn parse_mantissa_huge_test() {
+    // Only the first `MAX_DIGITS` digits are added to the big integer,
+    // the rest are a sticky bit, regardless of the number of digits.
+    let max_digits = f64::MAX_DIGITS;
+    let integer = b"1";
+    let fraction = vec![b'9'; 10_000_000];
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
+    let (expected, expected_count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction[..1000].iter(), max_digits);
+    assert_eq!(&*bigmant.data, &*expected.data);
+    assert_eq!(count, expected_count);
+    assert_eq!(count, max_digits + 1);
+    assert!(bigmant.bit_length() as usize <= f64::BIGINT_BITS);
+
+    // A sticky bit after all the digits.
+    let mut fraction = vec![b'0'; 10_000_000];
+    fraction.push(b'1');
+    let (bigmant, count) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
+    assert_eq!(count, max_digits + 1);
+    assert!(bigmant.bit_length() as usize <= f64::BIGINT_BITS);
+}
+
+/// Calculate the slow-path float with a custom maximum number of digits.
+fn slow_max_digits<F: Float>(
+    num: Number,
+    fp: ExtendedFloat,
+    integer: &[u8],
+    fraction: &[u8],
+    max_digits: usize,
+) -> ExtendedFloat {
+    let sci_exp = slow::scientific_exponent(&num);
+    let (bigmant, digits) =
+        slow::parse_mantissa::<f64, _, _>(integer.iter(), fraction.iter(), max_digits);
+    let exponent = sci_exp + 1 - digits as i32;
+    if exponent >= 0 {
+        slow::positive_digit_comp::<F>(bigmant, exponent)
+    } else {
+        slow::negative_digit_comp::<F>(bigmant, fp, exponent)
+    }
+}
+
+#[test]
+fn max_digits_test() {
+    // Digits beyond `MAX_DIGITS` only act as a sticky bit, so truncating
+    // them must give identical results to using every digit.
+    // 5e-324, halfway, in 752 significant digits.
+    let integer = b"2";
+    let halfway = "4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+    let num = Number {
+        mantissa: 2470328229206232720,
+        exponent: -342,
+        many_digits: true,
+    };
+    let fp = ExtendedFloat {
+        mant: 1 << 63,
+        exp: -63,
+    };
+
+    // Above halfway, with the non-zero digit beyond `MAX_DIGITS`: round-up.
+    let mut fraction = halfway.to_string();
+    fraction.push_str(&"0".repeat(40));
+    fraction.push('1');
+    assert!(fraction.len() + 1 > f64::MAX_DIGITS);
+    let capped = slow_max_digits::<f64>(num, fp, integer, fraction.as_bytes(), f64::MAX_DIGITS);
+    let uncapped = slow_max_digits::<f64>(num, fp, integer, fraction.as_bytes(), 1000);
+    assert_eq!(capped, uncapped);
+    assert_eq!(capped.mant, 1);
+    assert_eq!(capped.exp, 0);
+
+    // Below halfway, with non-zero digits beyond `MAX_DIGITS`: round-down.
+    let mut fraction = halfway[..halfway.len() - 1].to_string();
+    fraction.push('4');
+    fraction.push_str(&"9".repeat(40));
+    assert!(fraction.len() + 1 > f64::MAX_DIGITS);
+    let capped = slow_max_digits::<f64>(num, fp, integer, fraction.as_bytes(), f64::MAX_DIGITS);
+    let uncapped = slow_max_digits::<f64>(num, fp, integer, fraction.as_bytes(), 1000);
+    assert_eq!(capped, uncapped);
+    assert_eq!(capped.mant, 0);
+    assert_eq!(capped.exp, 0);
+}
+
+fn check_bhcomp<F: Float + core::fmt::Debug>(
+    b: F,
+    integer: &str,
+    fraction: &str,
+    exponent: i32,
+    expected: F,
+) {
+    let integer = integer.as_bytes();
+    let fraction = fraction.as_bytes();
+    let result = slow::bhcomp(b, integer.iter(), fraction.iter(), exponent);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn bhcomp_test() {
+    // Positive exponent, halfway, round-down and round-up.
+    check_bhcomp(9007199254740992_f64, "9007199254740993", "", 0, 9007199254740992_f64);
+    check_bhcomp(9007199254740994_f64, "9007199254740995", "", 0, 9007199254740996_f64);
+    check_bhcomp(16777216_f32, "16777217", "", 0, 16777216_f32);
+    check_bhcomp(
+        8.98846567431158e+307_f64,
+        "898846567431158053",
+        "",
+        290,
+        8.98846567431158e+307_f64,
+    );
+
+    // Negative exponent, above halfway.
+    check_bhcomp(16777216_f32, "16777217", "0000000000000000001", 0, 16777218_f32);
+    check_bhcomp(9007199254740992_f64, "9007199254740993", "000001", 0, 9007199254740994_f64);
+
+    // Denormal, with `b` as a literal 0.
+    let halfway = "4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+    check_bhcomp(0.0_f64, "2", halfway, -324, 0.0_f64);
+    check_bhcomp(0.0_f64, "2", &format!("{}1", halfway), -324, 5e-324_f64);
+    check_bhcomp(0.0_f64, "", "1", -400, 0.0_f64);
+    check_bhcomp(0.0_f32, "", "1", -50, 0.0_f32);
+    check_bhcomp(0.0_f32, "1", "", -45, 1e-45_f32);
+}
+
+#[test]
+#[cfg(feature = "diagnostics")]
+fn bhcomp_digits_test() {
+    // Positive exponent, exactly halfway.
+    let b = 9007199254740992_f64;
+    let (real, theor) = slow::bhcomp_digits(b, b"9007199254740993".iter(), b"".iter(), 0);
+    assert_eq!(real.to_string(), "9007199254740993");
+    assert_eq!(theor.to_string(), "9007199254740993");
+
+    // Negative exponent, `0.1` is above the halfway point below `0.1_f64`.
+    let b = f64::from_bits(0.1_f64.to_bits() - 1);
+    let (real, theor) = slow::bhcomp_digits(b, b"0".iter(), b"1".iter(), 0);
+    assert_eq!(real.to_string(), "72057594037927936");
+    assert_eq!(theor.to_string(), "72057594037927935");
+
+    // Zeros are padded between each group of 9 digits.
+    assert_eq!(Bigint::new().to_string(), "0");
+    assert_eq!(Bigint::from_u64(1_000_000_007).to_string(), "1000000007");
+    let mut x = Bigint::from_u64(1);
+    x.pow(2, 100).unwrap();
+    assert_eq!(x.to_string(), "1267650600228229401496703205376");
+}
+
+fn bigint_bits<F: Float>(integer: &str, fraction: &str, exponent: i32) -> usize {
+    let integer = integer.as_bytes();
+    let fraction = fraction.as_bytes();
+    let sci_exp = slow::scientific_exponent_digits(integer.iter(), fraction.iter(), exponent);
+    let (_, digits) =
+        slow::parse_mantissa::<F, _, _>(integer.iter(), fraction.iter(), F::MAX_DIGITS);
+    let bits = slow::bigint_bits::<F>(digits, sci_exp);
+
+    // The scaled digits must fit, so the capacity is only reserved once.
+    #[cfg(feature = "diagnostics")]
+    {
+        let float =
+            minimal_lexical::parse_float::<F, _, _>(integer.iter(), fraction.iter(), exponent);
+        let b = F::from_bits(float.to_bits().saturating_sub(1));
+        for &b in &[b, float] {
+            let (real, theor) = slow::bhcomp_digits(b, integer.iter(), fraction.iter(), exponent);
+            assert!(real.bit_length() as usize <= bits);
+            assert!(theor.bit_length() as usize <= bits);
+        }
+    }
+
+    bits
+}
+
+#[test]
+fn bigint_bits_test() {
+    // Positive exponent, limited by the digits or scaled `b+h`.
+    assert_eq!(bigint_bits::<f64>("9007199254740993", "", 0), 128);
+    assert_eq!(bigint_bits::<f64>("898846567431158053", "", 290), 1091);
+    assert_eq!(bigint_bits::<f32>("16777217", "", 0), 128);
+
+    // Negative exponent, limited by the scaled `b+h`.
+    assert_eq!(bigint_bits::<f64>("1", "", -300), 828);
+    assert_eq!(bigint_bits::<f64>("", "0001", -300), 838);
+    assert_eq!(bigint_bits::<f32>("9", "", -46), 236);
+
+    // Only the maximum number of digits are parsed.
+    let digits = "1".repeat(2000);
+    assert_eq!(bigint_bits::<f64>("2", &digits, -324), 2679);
+    assert_eq!(bigint_bits::<f32>("1", &digits, 0), 448);
+}
+
+#[test]
+fn slow_path_limb_count_test() {
+    use minimal_lexical::bigint::{bits_to_limbs, BIGINT_LIMBS, LIMB_BITS};
+
+    // Matches the capacity reserved from the bits.
+    let limbs = slow::slow_path_limb_count::<f64>(16, 15);
+    assert_eq!(limbs, bits_to_limbs(bigint_bits::<f64>("9007199254740993", "", 0)));
+    let limbs = slow::slow_path_limb_count::<f64>(2001, -324);
+    assert_eq!(limbs, bits_to_limbs(bigint_bits::<f64>("2", &"1".repeat(2000), -324)));
+    assert_eq!(limbs, slow::slow_path_limb_count::<f64>(770, -324));
+
+    // The worst case must fit in the stack-allocated big integers.
+    let digits = f64::MAX_DIGITS + 1;
+    let limbs = slow::slow_path_limb_count::<f64>(digits, f64::SMALLEST_POWER_OF_TEN);
+    assert!(limbs * LIMB_BITS <= f64::BIGINT_BITS);
+    assert!(limbs <= BIGINT_LIMBS);
+    let limbs = slow::slow_path_limb_count::<f64>(digits, f64::LARGEST_POWER_OF_TEN);
+    assert!(limbs * LIMB_BITS <= f64::BIGINT_BITS);
+
+    let digits = f32::MAX_DIGITS + 1;
+    let limbs = slow::slow_path_limb_count::<f32>(digits, f32::SMALLEST_POWER_OF_TEN);
+    assert!(limbs * LIMB_BITS <= f32::BIGINT_BITS);
+}
+
+fn check_bracketing<F: Float + core::fmt::Debug>(
+    integer: &str,
+    fraction: &str,
+    exponent: i32,
+    expected: (F, F, Ordering),
+) {
+    let integer = integer.as_bytes();
+    let fraction = fraction.as_bytes();
+    let result = slow::bracketing_floats::<F, _, _>(integer.iter(), fraction.iter(), exponent);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn bracketing_floats_test() {
+    // Exact, below, at and above halfway.
+    check_bracketing("1", "", 0, (1_f64, 1.0000000000000002_f64, Ordering::Less));
+    check_bracketing("16777216", "5", 0, (16777216_f32, 16777218_f32, Ordering::Less));
+    check_bracketing("16777217", "", 0, (16777216_f32, 16777218_f32, Ordering::Equal));
+    check_bracketing("16777217", "5", 0, (16777216_f32, 16777218_f32, Ordering::Greater));
+    let expected = (9007199254740992_f64, 9007199254740994_f64, Ordering::Equal);
+    check_bracketing("9007199254740993", "", 0, expected);
+    let expected = (9007199254740994_f64, 9007199254740996_f64, Ordering::Equal);
+    check_bracketing("9007199254740995", "", 0, expected);
+
+    // Denormal and zero.
+    let halfway = "4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125";
+    check_bracketing("2", halfway, -324, (0_f64, 5e-324_f64, Ordering::Equal));
+    check_bracketing("2", &format!("{}1", halfway), -324, (0_f64, 5e-324_f64, Ordering::Greater));
+    check_bracketing("5", "", -324, (5e-324_f64, 1e-323_f64, Ordering::Less));
+    check_bracketing("1", "", -400, (0_f64, 5e-324_f64, Ordering::Less));
+    check_bracketing("1", "", -50, (0_f32, 1e-45_f32, Ordering::Less));
+
+    // Overflow.
+    let halfway = "179769313486231580793728971405303415079934132710037826936173778980444968292764750946649017977587207096330286416692887910946555547851940402630657488671505820681908902000708383676273854845817711531764475730270069855571366959622842914819860834936475292719074168444365510704342711559699508093042880177904174497792";
+    check_bracketing(halfway, "", 0, (f64::MAX, f64::INFINITY, Ordering::Equal));
+    check_bracketing(halfway, "1", 0, (f64::MAX, f64::INFINITY, Ordering::Greater));
+    check_bracketing("1", "", 400, (f64::MAX, f64::INFINITY, Ordering::Greater));
+    check_bracketing("1", "", 40, (f32::MAX, f32::INFINITY, Ordering::Greater));
+}
+
+#[test]
+fn decimal_scaler_test() {
+    // The power is only recomputed if the exponent changes.
+    let mut scaler = slow::DecimalScaler::new();
+    assert_eq!(scaler.exponent(), None);
+    for &exp in [53, 53, 10, 300, 300, 0].iter() {
+        let mut expected = Bigint::from_u64(12345);
+        expected.pow(5, exp).unwrap();
+        let mut actual = Bigint::from_u64(12345);
+        scaler.mul_pow5(&mut actual, exp).unwrap();
+        assert!(actual == expected);
+        assert_eq!(scaler.exponent(), Some(exp));
+    }
+}
+
+#[test]
+#[cfg(not(feature = "no_slow_path"))]
+fn parse_float_with_scaler_test() {
+    use minimal_lexical::parse::parse_float_with_scaler;
+
+    // Columns of halfway floats, `h * 2^-j`, with `j` fraction digits.
+    let parse = |integer: &str, fraction: &str, exponent, scaler: &mut _| -> f64 {
+        let (integer, fraction) = (integer.as_bytes(), fraction.as_bytes());
+        parse_float_with_scaler(integer.iter(), fraction.iter(), exponent, scaler)
+    };
+    for &j in [20, 24, 27].iter() {
+        let mut scaler = slow::DecimalScaler::new();
+        for index in 0..100 {
+            let h: u64 = (1 << 53) + 2 * index * 12345 + 1;
+            let digits = (h as u128 * 5u128.pow(j)).to_string();
+            let (integer, fraction) = digits.split_at(digits.len() - j as usize);
+            let expected = h as f64 / (1u64 << j) as f64;
+            assert_eq!(parse(integer, fraction, 0, &mut scaler), expected);
+        }
+        assert_eq!(scaler.exponent(), Some(j));
+    }
+
+    // Columns of halfway floats, `h * 2^70`, with `h` divisible by `5^t`,
+    // so the last `t` digits are zeros and moved to the exponent.
+    for &t in [3, 5, 7].iter() {
+        let mut scaler = slow::DecimalScaler::new();
+        let step = 5u64.pow(t);
+        for index in 0..100 {
+            let h = ((((1u64 << 53) / step) | 1) + 2 * index) * step;
+            let digits = ((h as u128) << 70).to_string();
+            let integer = &digits[..digits.len() - t as usize];
+            let expected = h as f64 * (1u128 << 70) as f64;
+            assert_eq!(parse(integer, "", t as i32, &mut scaler), expected);
+        }
+        // Up to 38 digits with a positive exponent are exact with a wide mantissa.
+        #[cfg(not(feature = "wide_mantissa"))]
+        assert_eq!(scaler.exponent(), Some(t));
+    }
+}
@@ -0,0 +1,87 @@
+use core::{f32, f64};
+use minimal_lexical::num::Float;
+use minimal_lexical::special;
+
+/// Get every combination of upper and lowercase letters in the string.
+fn case_permutations(string: &str) -> Vec<Vec<u8>> {
+    let bytes = string.as_bytes();
+    (0..1usize << bytes.len())
+        .map(|mask| {
+            let upper = |(index, c): (usize, &u8)| match mask & (1 << index) != 0 {
+                true => c.to_ascii_uppercase(),
+                false => *c,
+            };
+            bytes.iter().enumerate().map(upper).collect()
+        })
+        .collect()
+}
+
+/// Check every case and sign of a special value, with any trailing bytes.
+fn check_special<F: Float>(string: &str, is_nan: bool) {
+    for word in case_permutations(string) {
+        for &sign in [&b""[..], b"+", b"-"].iter() {
+            for &suffix in [&b""[..], b",", b"x", b" 1"].iter() {
+                let bytes = [sign, &word, suffix].concat();
+                let (float, count) = special::parse_special::<F>(&bytes).unwrap();
+                assert_eq!(count, sign.len() + word.len());
+                assert_eq!(float.to_bits() & F::SIGN_MASK != 0, sign == b"-");
+                let bits = float.to_bits() & !F::SIGN_MASK;
+                match is_nan {
+                    true => assert!(bits == F::NAN_BITS),
+                    false => assert!(bits == F::INFINITY_BITS),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_special_test() {
+    check_special::<f32>("nan", true);
+    check_special::<f64>("nan", true);
+    check_special::<f32>("inf", false);
+    check_special::<f64>("inf", false);
+    check_special::<f32>("infinity", false);
+    check_special::<f64>("infinity", false);
+
+    assert_eq!(special::parse_special::<f64>(b"-Infinity"), Some((f64::NEG_INFINITY, 9)));
+    assert_eq!(special::parse_special::<f32>(b"+INF"), Some((f32::INFINITY, 4)));
+    assert_eq!(special::parse_special::<f64>(b"infinityinity"), Some((f64::INFINITY, 8)));
+    assert_eq!(special::parse_special::<f64>(b"infx"), Some((f64::INFINITY, 3)));
+    let (float, _) = special::parse_special::<f32>(b"-inf").unwrap();
+    assert_eq!(Float::to_bits(float), f32::NEG_INFINITY_BITS);
+    let (nan, count) = special::parse_special::<f64>(b"-NaNi").unwrap();
+    assert!(nan.is_nan() && nan.is_sign_negative());
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn parse_special_partial_test() {
+    // Partial special values are invalid, and never partially consumed.
+    for &string in ["", "i", "in", "infi", "infin", "infini", "infinit", "n", "na"].iter() {
+        for word in case_permutations(string) {
+            for &sign in [&b""[..], b"+", b"-"].iter() {
+                let bytes = [sign, &word].concat();
+                assert_eq!(special::parse_special::<f64>(&bytes), None);
+                assert!(special::is_special_prefix(&bytes));
+                let bytes = [sign, &word, b","].concat();
+                assert_eq!(special::parse_special::<f32>(&bytes), None);
+            }
+        }
+    }
+    assert_eq!(special::parse_special::<f64>(b"infinit,"), None);
+    assert_eq!(special::parse_special::<f64>(b"+-inf"), None);
+    assert_eq!(special::parse_special::<f64>(b"1inf"), None);
+    assert!(!special::is_special_prefix(b"infx"));
+    assert!(!special::is_special_prefix(b"nann"));
+}
+
+#[test]
+fn is_continued_special_test() {
+    assert_eq!(special::is_continued_special(b"inf", b""), Some(true));
+    assert_eq!(special::is_continued_special(b"InF", b"INI"), Some(true));
+    assert_eq!(special::is_continued_special(b"inf", b"x"), Some(false));
+    assert_eq!(special::is_continued_special(b"infinity", b""), Some(false));
+    assert_eq!(special::is_continued_special(b"NaN", b""), Some(false));
+    assert_eq!(special::is_continued_special(b"1.5", b""), None);
+}
@@ -6,14 +6,14 @@ pub use minimal_lexical::stackvec::StackVec as VecType;
 
 pub fn vec_from_u32(x: &[u32]) -> VecType {
     let mut vec = VecType::new();
-    #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+    #[cfg(not(limb_width_64))]
     {
         for &xi in x {
             vec.try_push(xi as bigint::Limb).unwrap();
         }
     }
 
-    #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+    #[cfg(limb_width_64)]
     {
         for xi in x.chunks(2) {
             match xi.len() {
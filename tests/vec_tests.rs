@@ -1,15 +1,17 @@
+mod rng;
 mod stackvec;
 
 use core::cmp;
 use minimal_lexical::bigint;
+use rng::xorshift;
 use stackvec::{vec_from_u32, VecType};
 
 // u64::MAX and Limb::MAX for older Rustc versions.
 const U64_MAX: u64 = 0xffff_ffff_ffff_ffff;
 // LIMB_MAX
-#[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+#[cfg(limb_width_64)]
 const LIMB_MAX: u64 = U64_MAX;
-#[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+#[cfg(not(limb_width_64))]
 const LIMB_MAX: u32 = 0xffff_ffff;
 
 #[test]
@@ -231,6 +233,49 @@ fn pow_test() {
     assert_eq!(&*x, &*expected);
 }
 
+#[test]
+fn pow10_test() {
+    // Powers of 10 are derived from the powers of 5 and a shift, so
+    // cross-validate them against repeated multiplication by 10.
+    let mut expected = bigint::Bigint::from_u64(1);
+    for exp in 0..700 {
+        let mut x = bigint::Bigint::from_u64(1);
+        x.pow(10, exp).unwrap();
+        assert!(x == expected);
+        bigint::small_mul(&mut expected.data, 10).unwrap();
+    }
+}
+
+#[test]
+fn mul_pow_test() {
+    // Scale a mantissa by powers of 2, 5 and 10, and check against `pow`.
+    let mantissa = 12345678901234567890;
+    for exp in [0, 1, 26, 27, 100, 342].iter().cloned() {
+        let mut x = bigint::Bigint::from_u64(mantissa);
+        let mut y = bigint::Bigint::from_u64(mantissa);
+        x.mul_pow5(exp).unwrap();
+        y.pow(5, exp).unwrap();
+        assert!(x == y);
+
+        x.shl(exp as usize).unwrap();
+        y.pow(2, exp).unwrap();
+        assert!(x == y);
+
+        let mut z = bigint::Bigint::from_u64(mantissa);
+        z.mul_pow10(exp).unwrap();
+        assert!(x == z);
+    }
+
+    // Overflowing the maximum number of bits fails.
+    #[cfg(not(feature = "alloc"))]
+    {
+        let mut x = bigint::Bigint::from_u64(1);
+        assert_eq!(x.shl(bigint::BIGINT_BITS), None);
+        let mut x = bigint::Bigint::from_u64(1);
+        assert_eq!(x.mul_pow10(2000), None);
+    }
+}
+
 #[test]
 fn large_add_test() {
     // Overflow, both single values
@@ -331,6 +376,85 @@ fn very_large_mul_test() {
     assert_eq!(&*x, &*expected);
 }
 
+/// Create a normalized big integer from a u128, as a reference.
+fn vec_from_u128(x: u128) -> VecType {
+    let mut vec = vec_from_u32(&[x as u32, (x >> 32) as u32, (x >> 64) as u32, (x >> 96) as u32]);
+    vec.normalize();
+    vec
+}
+
+#[test]
+fn small_div_test() {
+    let mut x = VecType::from_u64(0);
+    assert_eq!(bigint::small_div(&mut x, 7), 0);
+    assert!(x.is_empty());
+
+    let mut x = VecType::from_u64(100);
+    assert_eq!(bigint::small_div(&mut x, 7), 2);
+    assert_eq!(&*x, &*VecType::from_u64(14));
+
+    let mut x: VecType = vec_from_u32(&[0xFFFFFFFE, 0x0FFFFFFF, 1]);
+    assert_eq!(bigint::small_div(&mut x, 10), 0);
+    assert_eq!(&*x, &*vec_from_u128(0x10FFFFFFFFFFFFFFE / 10));
+
+    let mut x: VecType = vec_from_u32(&[0xFFFFFFFF, 0x0FFFFFFF, 1]);
+    assert_eq!(bigint::small_div(&mut x, 0xFFFFFFF0), 0x10F);
+    assert_eq!(&*x, &*vec_from_u128(0x10FFFFFFFFFFFFFFF / 0xFFFFFFF0));
+}
+
+#[test]
+fn large_sub_test() {
+    let mut x = VecType::from_u64(100);
+    bigint::large_sub(&mut x, &VecType::from_u64(100));
+    assert!(x.is_empty());
+
+    // Borrow across limbs.
+    let mut x: VecType = vec_from_u32(&[0, 0, 1]);
+    bigint::large_sub(&mut x, &VecType::from_u64(1));
+    assert_eq!(&*x, &*vec_from_u32(&[0xFFFFFFFF, 0xFFFFFFFF]));
+
+    let mut x: VecType = vec_from_u32(&[0xFFFFFFFE, 0x0FFFFFFF, 1]);
+    bigint::large_sub(&mut x, &vec_from_u32(&[0xFFFFFFFF, 0x0FFFFFFF]));
+    assert_eq!(&*x, &*vec_from_u128(0x10FFFFFFFFFFFFFFE - 0xFFFFFFFFFFFFFFF));
+}
+
+#[test]
+fn large_div_test() {
+    // Cross-validate against native, 128-bit division.
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = || xorshift(&mut state);
+    for _ in 0..2000 {
+        let x = ((next() as u128) << 64 | next() as u128) >> (next() % 128);
+        let y = ((next() as u128) << 64 | next() as u128) >> (next() % 128);
+        if y == 0 {
+            continue;
+        }
+        let (quotient, rem) = bigint::large_div(&vec_from_u128(x), &vec_from_u128(y)).unwrap();
+        assert_eq!(&*quotient, &*vec_from_u128(x / y));
+        assert_eq!(&*rem, &*vec_from_u128(x % y));
+    }
+
+    // Large values: check `quotient * y + rem == x`, with `rem < y`.
+    let x: VecType = vec_from_u32(&[
+        4, 13, 28, 50, 80, 119, 168, 228, 300, 385, 484, 598, 728, 875, 1040, 1224, 1340, 1435,
+        1508, 1558, 1584, 1585, 1560, 1508, 1428, 1319, 1180, 1010, 808, 573, 304,
+    ]);
+    let y: VecType = vec_from_u32(&[4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+    let (quotient, rem) = bigint::large_div(&x, &y).unwrap();
+    let expected: VecType = vec_from_u32(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    assert_eq!(&*quotient, &*expected);
+    assert!(rem.is_empty());
+
+    let mut z = x.clone();
+    bigint::small_add(&mut z, 3);
+    let (quotient, rem) = bigint::large_div(&z, &quotient).unwrap();
+    assert_eq!(&*quotient, &*y);
+    assert_eq!(&*rem, &*VecType::from_u64(3));
+    let mut w = bigint::long_mul(&quotient, &expected).unwrap();
+    bigint::large_add(&mut w, &rem);
+    assert_eq!(&*w, &*z);
+}
+
 #[test]
 fn bit_length_test() {
     let x: VecType = vec_from_u32(&[0, 0, 0, 1]);
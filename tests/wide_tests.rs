@@ -0,0 +1,63 @@
+use minimal_lexical::wide;
+
+// u64::MAX and u128::MAX for older Rustc versions.
+const U64_MAX: u64 = 0xffff_ffff_ffff_ffff;
+const U128_MAX: u128 = 0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff;
+
+#[test]
+fn mul_u64_test() {
+    assert_eq!(wide::mul_u64(0, 0), (0, 0));
+    assert_eq!(wide::mul_u64(0, U64_MAX), (0, 0));
+    assert_eq!(wide::mul_u64(1, U64_MAX), (U64_MAX, 0));
+    assert_eq!(wide::mul_u64(1 << 63, 2), (0, 1));
+    // (2^64 - 1)^2 = 2^128 - 2^65 + 1
+    assert_eq!(wide::mul_u64(U64_MAX, U64_MAX), (1, U64_MAX - 1));
+
+    // Must match the 128-bit multiplication.
+    let values: [u64; 4] = [7, 0xdead_beef_cafe_babe, 0x1234_5678_9abc_def0, 1 << 63 | 1];
+    for &x in values.iter() {
+        for &y in values.iter() {
+            let r = (x as u128) * (y as u128);
+            assert_eq!(wide::mul_u64(x, y), (r as u64, (r >> 64) as u64));
+        }
+    }
+}
+
+#[test]
+fn mul_wide_test() {
+    assert_eq!(wide::mul_wide(0, 0), (0, 0));
+    assert_eq!(wide::mul_wide(0, U128_MAX), (0, 0));
+    assert_eq!(wide::mul_wide(1, U128_MAX), (U128_MAX, 0));
+    assert_eq!(wide::mul_wide(12345, 67890), (838102050, 0));
+    assert_eq!(wide::mul_wide(1 << 64, 1 << 64), (0, 1));
+    assert_eq!(wide::mul_wide(1 << 127, 2), (0, 1));
+    assert_eq!(wide::mul_wide(1 << 127, 1 << 127), (0, 1 << 126));
+    // (2^128 - 1)^2 = 2^256 - 2^129 + 1
+    assert_eq!(wide::mul_wide(U128_MAX, U128_MAX), (1, U128_MAX - 1));
+    // 5^55 * 5^55 = 5^110
+    let pow5_55: u128 = 277555756156289135105907917022705078125;
+    let (lo, hi) = wide::mul_wide(pow5_55, pow5_55);
+    assert_eq!(lo, 0x10331d72aeaf716586ff327d536b7469);
+    assert_eq!(hi, 0xaa51823e34a7eedebd4b46f0599fd415);
+}
+
+#[test]
+fn mul_u128_u64_test() {
+    assert_eq!(wide::mul_u128_u64(0, 0), (0, 0));
+    assert_eq!(wide::mul_u128_u64(U128_MAX, 0), (0, 0));
+    assert_eq!(wide::mul_u128_u64(U128_MAX, 1), (U128_MAX, 0));
+    assert_eq!(wide::mul_u128_u64(1 << 127, 2), (0, 1));
+    // (2^128 - 1) * y = y * 2^128 - y
+    let expected = ((U64_MAX as u128).wrapping_neg(), U64_MAX - 1);
+    assert_eq!(wide::mul_u128_u64(U128_MAX, U64_MAX), expected);
+
+    // Must match the full multiplication.
+    let values: [u128; 4] = [3, 0xdead_beef_cafe_babe_1234_5678_9abc_def0, 1 << 100, U128_MAX];
+    let smalls: [u64; 4] = [7, 0x1234_5678_9abc_def0, 1 << 63, U64_MAX];
+    for &x in values.iter() {
+        for &y in smalls.iter() {
+            let (lo, hi) = wide::mul_wide(x, y as u128);
+            assert_eq!(wide::mul_u128_u64(x, y), (lo, hi as u64));
+        }
+    }
+}